@@ -1,9 +1,41 @@
-use sqlx::{postgres::PgRow, Row};
+use sqlx::{postgres::PgRow, Postgres, QueryBuilder, Row, Transaction};
 use time::Date;
 use uuid::Uuid;
 
-use crate::database::{conn::Database, ResultDB};
-use shared::structs::{author::Author, PersonName};
+use crate::database::{
+    conn::Database, push_date_range, push_fuzzy_match, set_fuzzy_threshold, OrderColumns,
+    Pagination, ResultDB,
+};
+use shared::structs::{
+    author::{Author, AuthorFilter},
+    duplicate::{AuthorMergeSummary, DuplicateGroup, Merge},
+    page::Page,
+    ConversionError, PersonName,
+};
+
+pub const AUTHOR_ORDER_COLUMNS: OrderColumns = &[("name", "name"), ("born", "born")];
+
+pub const AUTHOR_FUZZY_ORDER_COLUMNS: OrderColumns = &[
+    ("similarity", "word_similarity(search.token, name)"),
+    ("name", "name"),
+    ("born", "born"),
+];
+
+fn push_author_filters(query: &mut QueryBuilder<'_, Postgres>, token: &str, filter: &AuthorFilter) {
+    query
+        .push(" WHERE name ILIKE ")
+        .push_bind(format!("%{token}%"));
+    push_date_range(query, "born", filter.born_from, filter.born_until);
+}
+
+fn push_author_fuzzy_filters(
+    query: &mut QueryBuilder<'_, Postgres>,
+    token: &str,
+    filter: &AuthorFilter,
+) {
+    push_fuzzy_match(query, "name", token);
+    push_date_range(query, "born", filter.born_from, filter.born_until);
+}
 
 impl Database {
     pub async fn create_author(&self, author: Author) -> ResultDB<Uuid> {
@@ -36,14 +68,15 @@ impl Database {
         ",
         )
         .bind(author_uuid)
-        .map(|row: PgRow| {
+        .try_map(|row: PgRow| {
             let name_parser: String = row.get("name");
 
             let id: Uuid = row.get("id");
-            let name: PersonName = PersonName::try_from(name_parser).unwrap();
+            let name: PersonName = PersonName::try_from(name_parser)
+                .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
             let born: Date = row.get("born");
 
-            Author { id, name, born }
+            Ok(Author { id, name, born })
         })
         .fetch_optional(&self.pool)
         .await?;
@@ -70,28 +103,130 @@ impl Database {
         Ok(author_uuid)
     }
 
-    pub async fn search_authors(&self, token: String) -> ResultDB<Vec<Author>> {
-        let authors_vec: Vec<Author> = sqlx::query(
+    pub async fn list_author_duplicates(&self) -> ResultDB<Vec<DuplicateGroup<Author>>> {
+        let rows: Vec<(String, Date, Author)> = sqlx::query(
             "
-            SELECT id, name, born
+            SELECT id, name, born, person_name_key(name) as name_key
             FROM tbl_authors
-            WHERE name ILIKE $1
+            WHERE (person_name_key(name), born) IN (
+                SELECT person_name_key(name), born
+                FROM tbl_authors
+                GROUP BY person_name_key(name), born
+                HAVING count(*) > 1
+            )
+            ORDER BY name_key, born, id
         ",
         )
-        .bind(format!("%{token}%"))
-        .map(|row: PgRow| {
+        .try_map(|row: PgRow| {
             let name_parser: String = row.get("name");
+            let name_key: String = row.get("name_key");
 
             let id: Uuid = row.get("id");
-            let name: PersonName = PersonName::try_from(name_parser).unwrap();
+            let name: PersonName = PersonName::try_from(name_parser)
+                .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
             let born: Date = row.get("born");
 
-            Author { id, name, born }
+            Ok((name_key, born, Author { id, name, born }))
         })
         .fetch_all(&self.pool)
         .await?;
 
-        Ok(authors_vec)
+        Ok(DuplicateGroup::group(rows))
+    }
+
+    pub async fn search_authors(
+        &self,
+        token: String,
+        filter: AuthorFilter,
+        pagination: Pagination,
+    ) -> ResultDB<Page<Author>> {
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "
+            SELECT id, name, born
+            FROM tbl_authors
+        ",
+        );
+        push_author_filters(&mut query, &token, &filter);
+        pagination.push_order(&mut query);
+
+        let authors_vec: Vec<Author> = query
+            .build()
+            .try_map(|row: PgRow| {
+                let name_parser: String = row.get("name");
+
+                let id: Uuid = row.get("id");
+                let name: PersonName = PersonName::try_from(name_parser)
+                    .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+                let born: Date = row.get("born");
+
+                Ok(Author { id, name, born })
+            })
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut count_query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "
+            SELECT count(*) as total
+            FROM tbl_authors
+        ",
+        );
+        push_author_filters(&mut count_query, &token, &filter);
+
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(pagination.page(authors_vec, total))
+    }
+
+    pub async fn search_authors_fuzzy(
+        &self,
+        token: String,
+        threshold: f32,
+        filter: AuthorFilter,
+        pagination: Pagination,
+    ) -> ResultDB<Page<Author>> {
+        let mut tx: Transaction<'_, Postgres> = self.pool.begin().await?;
+        set_fuzzy_threshold(&mut tx, threshold).await?;
+
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "
+            SELECT id, name, born
+            FROM tbl_authors
+        ",
+        );
+        push_author_fuzzy_filters(&mut query, &token, &filter);
+        pagination.push_order(&mut query);
+
+        let authors_vec: Vec<Author> = query
+            .build()
+            .try_map(|row: PgRow| {
+                let name_parser: String = row.get("name");
+
+                let id: Uuid = row.get("id");
+                let name: PersonName = PersonName::try_from(name_parser)
+                    .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+                let born: Date = row.get("born");
+
+                Ok(Author { id, name, born })
+            })
+            .fetch_all(&mut *tx)
+            .await?;
+
+        let mut count_query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "
+            SELECT count(*) as total
+            FROM tbl_authors
+        ",
+        );
+        push_author_fuzzy_filters(&mut count_query, &token, &filter);
+
+        let total: i64 = count_query.build_query_scalar().fetch_one(&mut *tx).await?;
+
+        tx.commit().await?;
+
+        Ok(pagination.page(authors_vec, total))
     }
 
     pub async fn update_author(&self, author: Author) -> ResultDB<Uuid> {
@@ -116,6 +251,64 @@ impl Database {
         Ok(author_uuid)
     }
 
+    pub async fn merge_authors(&self, merge: Merge) -> ResultDB<AuthorMergeSummary> {
+        let mut tx: Transaction<'_, Postgres> = self.pool.begin().await?;
+
+        let books: i64 = sqlx::query_scalar(
+            "
+            SELECT count(DISTINCT book_uuid)
+            FROM tbl_book_authors
+            WHERE author_uuid = $1
+        ",
+        )
+        .bind(merge.loser_uuid)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "
+            DELETE FROM tbl_book_authors l
+            USING tbl_book_authors s
+            WHERE l.author_uuid = $2 AND s.author_uuid = $1
+            AND s.book_uuid = l.book_uuid AND s.role = l.role
+        ",
+        )
+        .bind(merge.survivor_uuid)
+        .bind(merge.loser_uuid)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "
+            UPDATE tbl_book_authors
+            SET author_uuid = $1
+            WHERE author_uuid = $2
+        ",
+        )
+        .bind(merge.survivor_uuid)
+        .bind(merge.loser_uuid)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "
+            DELETE FROM tbl_authors
+            WHERE id = $1
+        ",
+        )
+        .bind(merge.loser_uuid)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(AuthorMergeSummary {
+            survivor_uuid: merge.survivor_uuid,
+            loser_uuid: merge.loser_uuid,
+            books,
+        })
+    }
+
     pub async fn delete_author(&self, author_uuid: Uuid) -> ResultDB<Uuid> {
         let author_uuid: Uuid = sqlx::query(
             "
@@ -157,12 +350,20 @@ mod tests {
 
     use time::{error::ComponentRange, Date, Month};
 
+    use crate::database::Direction;
     use crate::handlers::QueryURL;
-    use shared::structs::author::{PayloadAuthor, PayloadUpdateAuthor};
-
-    const DEFAULT_NAME: &'static str = "Name";
+    use shared::structs::{
+        author::{PayloadAuthor, PayloadUpdateAuthor},
+        book::{Book, BookContributor, ContributorRole, PayloadBook, PayloadContributor},
+        publisher::{PayloadPublisher, Publisher},
+    };
+
+    const DEFAULT_NAME: &str = "Name";
+    const DEFAULT_EDITOR: &str = "Editor";
     const DEFAULT_BORN: Result<Date, ComponentRange> =
         Date::from_calendar_date(2000, Month::January, 1);
+    const DEFAULT_RELEASE: Result<Date, ComponentRange> =
+        Date::from_calendar_date(2000, Month::January, 1);
 
     async fn conn_db() -> Database {
         let db_url: String = var("DATABASE_URL").unwrap();
@@ -178,13 +379,45 @@ mod tests {
         Author::create(payload_author).unwrap()
     }
 
+    async fn create_book(db: &Database, author_uuid: Uuid) -> Book {
+        let payload_publisher: PayloadPublisher = PayloadPublisher {
+            name: format!("{DEFAULT_EDITOR} {}", Uuid::new_v4().simple()),
+        };
+
+        let publisher_uuid: Uuid = db
+            .create_publisher(Publisher::create(payload_publisher).unwrap())
+            .await
+            .unwrap();
+
+        let payload_book: PayloadBook = PayloadBook {
+            name: DEFAULT_NAME.to_string(),
+            isbn: None,
+            contributors: vec![PayloadContributor {
+                author_uuid,
+                role: "author".to_string(),
+            }],
+            subjects: vec![],
+            publisher_uuid,
+            release: DEFAULT_RELEASE.unwrap(),
+        };
+
+        Book::create(payload_book).unwrap()
+    }
+
+    fn random_letters() -> String {
+        Uuid::new_v4().simple().to_string()[..12]
+            .chars()
+            .map(|c: char| (b'a' + c.to_digit(16).unwrap() as u8) as char)
+            .collect()
+    }
+
     #[sqlx::test]
     async fn test_create_author() {
         let db: Database = conn_db().await;
 
         let author: Author = create_author();
 
-        let author_uuid: Uuid = author.id.clone();
+        let author_uuid: Uuid = author.id;
 
         let sql_result: Uuid = db.create_author(author).await.unwrap();
 
@@ -199,18 +432,43 @@ mod tests {
 
         let author_uuid: Uuid = db.create_author(author.clone()).await.unwrap();
 
-        let sql_result: Author = db.get_author(author_uuid.clone()).await.unwrap().unwrap();
+        let sql_result: Author = db.get_author(author_uuid).await.unwrap().unwrap();
 
         assert_eq!(sql_result, author);
     }
 
+    #[sqlx::test]
+    async fn test_get_author_blank_name() {
+        let db: Database = conn_db().await;
+
+        let author_uuid: Uuid = Uuid::new_v4();
+
+        sqlx::query(
+            "
+            INSERT INTO tbl_authors (id, name, born)
+            VALUES ($1, '   ', $2)
+        ",
+        )
+        .bind(author_uuid)
+        .bind(Date::from_calendar_date(1900, Month::January, 1).unwrap())
+        .execute(&db.pool)
+        .await
+        .unwrap();
+
+        let sql_result: ResultDB<Option<Author>> = db.get_author(author_uuid).await;
+
+        db.delete_author(author_uuid).await.unwrap();
+
+        assert!(matches!(sql_result, Err(sqlx::Error::Decode(_))));
+    }
+
     #[sqlx::test]
     async fn test_get_author_not_found() {
         let db: Database = conn_db().await;
 
         let author: Author = create_author();
 
-        let sql_result: Option<Author> = db.get_author(author.id.clone()).await.unwrap();
+        let sql_result: Option<Author> = db.get_author(author.id).await.unwrap();
 
         assert!(sql_result.is_none());
     }
@@ -223,11 +481,7 @@ mod tests {
 
         let author_uuid: Uuid = db.create_author(author.clone()).await.unwrap();
 
-        let sql_result: Uuid = db
-            .get_author_id(author_uuid.clone())
-            .await
-            .unwrap()
-            .unwrap();
+        let sql_result: Uuid = db.get_author_id(author_uuid).await.unwrap().unwrap();
 
         assert_eq!(sql_result, author_uuid);
     }
@@ -238,7 +492,7 @@ mod tests {
 
         let author: Author = create_author();
 
-        let sql_result: Option<Uuid> = db.get_author_id(author.id.clone()).await.unwrap();
+        let sql_result: Option<Uuid> = db.get_author_id(author.id).await.unwrap();
 
         assert!(sql_result.is_none());
     }
@@ -251,11 +505,20 @@ mod tests {
 
         let token: QueryURL = QueryURL {
             token: "Nam".to_string(),
+            ..Default::default()
         };
 
         db.create_author(author.clone()).await.unwrap();
 
-        let sql_result: Vec<Author> = db.search_authors(token.token).await.unwrap();
+        let sql_result: Vec<Author> = db
+            .search_authors(
+                token.token,
+                Default::default(),
+                Pagination::new(AUTHOR_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
 
         assert!(sql_result.contains(&author));
     }
@@ -268,11 +531,20 @@ mod tests {
 
         let token: QueryURL = QueryURL {
             token: "nam".to_string(),
+            ..Default::default()
         };
 
         db.create_author(author.clone()).await.unwrap();
 
-        let sql_result: Vec<Author> = db.search_authors(token.token).await.unwrap();
+        let sql_result: Vec<Author> = db
+            .search_authors(
+                token.token,
+                Default::default(),
+                Pagination::new(AUTHOR_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
 
         assert!(sql_result.contains(&author));
     }
@@ -285,15 +557,235 @@ mod tests {
 
         let token: QueryURL = QueryURL {
             token: "foo".to_string(),
+            ..Default::default()
         };
 
         db.create_author(author.clone()).await.unwrap();
 
-        let sql_result: Vec<Author> = db.search_authors(token.token).await.unwrap();
+        let sql_result: Vec<Author> = db
+            .search_authors(
+                token.token,
+                Default::default(),
+                Pagination::new(AUTHOR_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
 
         assert!(!sql_result.contains(&author));
     }
 
+    #[sqlx::test]
+    async fn test_search_authors_paginated() {
+        let db: Database = conn_db().await;
+
+        db.create_author(create_author()).await.unwrap();
+        db.create_author(create_author()).await.unwrap();
+
+        let pagination: Pagination = Pagination {
+            limit: Some(1),
+            offset: 1,
+            order_by: "born",
+            direction: Direction::Desc,
+        };
+
+        let sql_result: Page<Author> = db
+            .search_authors(DEFAULT_NAME.to_string(), Default::default(), pagination)
+            .await
+            .unwrap();
+
+        assert_eq!(sql_result.items.len(), 1);
+        assert!(sql_result.total >= 2);
+        assert_eq!(sql_result.limit, Some(1));
+        assert_eq!(sql_result.offset, 1);
+    }
+
+    #[sqlx::test]
+    async fn test_search_authors_filtered_by_born() {
+        let db: Database = conn_db().await;
+
+        db.create_author(create_author()).await.unwrap();
+
+        let filter: AuthorFilter = AuthorFilter {
+            born_from: Some(DEFAULT_BORN.unwrap()),
+            born_until: Some(DEFAULT_BORN.unwrap()),
+        };
+
+        let sql_result: Vec<Author> = db
+            .search_authors(
+                DEFAULT_NAME.to_string(),
+                filter,
+                Pagination::new(AUTHOR_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
+
+        assert!(!sql_result.is_empty());
+        assert!(sql_result
+            .iter()
+            .all(|author: &Author| author.born == DEFAULT_BORN.unwrap()));
+
+        let filter: AuthorFilter = AuthorFilter {
+            born_from: Some(DEFAULT_BORN.unwrap().next_day().unwrap()),
+            born_until: None,
+        };
+
+        let sql_result: Vec<Author> = db
+            .search_authors(
+                DEFAULT_NAME.to_string(),
+                filter,
+                Pagination::new(AUTHOR_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
+
+        assert!(sql_result
+            .iter()
+            .all(|author: &Author| author.born > DEFAULT_BORN.unwrap()));
+    }
+
+    #[sqlx::test]
+    async fn test_list_author_duplicates() {
+        let db: Database = conn_db().await;
+
+        let letters: String = random_letters();
+
+        let mut author: Author = create_author();
+        author.name = PersonName::try_from(format!("José {letters}")).unwrap();
+        db.create_author(author.clone()).await.unwrap();
+
+        let mut duplicate: Author = create_author();
+        duplicate.name = PersonName::try_from(format!("jose  {}", letters.to_uppercase())).unwrap();
+        db.create_author(duplicate.clone()).await.unwrap();
+
+        let mut other_born: Author = create_author();
+        other_born.name = author.name.clone();
+        other_born.born = Date::from_calendar_date(2001, Month::January, 1).unwrap();
+        db.create_author(other_born).await.unwrap();
+
+        let sql_result: Vec<DuplicateGroup<Author>> = db.list_author_duplicates().await.unwrap();
+
+        let groups: Vec<&DuplicateGroup<Author>> = sql_result
+            .iter()
+            .filter(|group: &&DuplicateGroup<Author>| group.key == format!("jose{letters}"))
+            .collect();
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].born, DEFAULT_BORN.unwrap());
+        assert_eq!(groups[0].items.len(), 2);
+        assert!(groups[0].items.contains(&author));
+        assert!(groups[0].items.contains(&duplicate));
+    }
+
+    #[sqlx::test]
+    async fn test_merge_authors_repoints_books() {
+        let db: Database = conn_db().await;
+
+        let loser_uuid: Uuid = db.create_author(create_author()).await.unwrap();
+        let book: Book = create_book(&db, loser_uuid).await;
+
+        let survivor: Author = create_author();
+        db.create_author(survivor.clone()).await.unwrap();
+
+        let mut shared_book: Book = create_book(&db, loser_uuid).await;
+        shared_book.contributors = vec![
+            BookContributor {
+                author_uuid: survivor.id,
+                role: ContributorRole::Author,
+            },
+            BookContributor {
+                author_uuid: loser_uuid,
+                role: ContributorRole::Author,
+            },
+        ];
+
+        db.create_book(book.clone()).await.unwrap();
+        db.create_book(shared_book.clone()).await.unwrap();
+
+        let sql_result: AuthorMergeSummary = db
+            .merge_authors(Merge {
+                survivor_uuid: survivor.id,
+                loser_uuid,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            sql_result,
+            AuthorMergeSummary {
+                survivor_uuid: survivor.id,
+                loser_uuid,
+                books: 2,
+            }
+        );
+        for book_uuid in [book.id, shared_book.id] {
+            let sql_book: Book = db.get_book_raw(book_uuid).await.unwrap().unwrap();
+            assert_eq!(
+                sql_book.contributors,
+                vec![BookContributor {
+                    author_uuid: survivor.id,
+                    role: ContributorRole::Author,
+                }]
+            );
+        }
+        assert!(db.get_author_id(loser_uuid).await.unwrap().is_none());
+    }
+
+    #[sqlx::test]
+    async fn test_search_authors_fuzzy() {
+        let db: Database = conn_db().await;
+
+        let letters: String = random_letters();
+        let misspelled: String = format!("{}x{}", &letters[..6], &letters[7..]);
+
+        let mut author: Author = create_author();
+        author.name = PersonName::try_from(format!("Machado {letters}")).unwrap();
+        db.create_author(author.clone()).await.unwrap();
+
+        let mut similar_author: Author = create_author();
+        similar_author.name = PersonName::try_from(format!("Machado {misspelled}")).unwrap();
+        db.create_author(similar_author.clone()).await.unwrap();
+
+        let sql_result: Vec<Author> = db
+            .search_authors_fuzzy(
+                letters.clone(),
+                0.4,
+                Default::default(),
+                Pagination {
+                    direction: Direction::Desc,
+                    ..Pagination::new(AUTHOR_FUZZY_ORDER_COLUMNS)
+                },
+            )
+            .await
+            .unwrap()
+            .items;
+
+        let position = |wanted: &Author| {
+            sql_result
+                .iter()
+                .position(|author: &Author| author == wanted)
+                .unwrap()
+        };
+
+        assert!(position(&author) < position(&similar_author));
+
+        let sql_result: Vec<Author> = db
+            .search_authors_fuzzy(
+                letters,
+                1.0,
+                Default::default(),
+                Pagination::new(AUTHOR_FUZZY_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
+
+        assert!(sql_result.contains(&author));
+        assert!(!sql_result.contains(&similar_author));
+    }
+
     #[sqlx::test]
     async fn test_update_author() {
         let db: Database = conn_db().await;
@@ -303,7 +795,7 @@ mod tests {
         let sql_author_uuid: Uuid = db.create_author(author.clone()).await.unwrap();
 
         let payload_update_author: PayloadUpdateAuthor = PayloadUpdateAuthor {
-            id: sql_author_uuid.clone(),
+            id: sql_author_uuid,
             name: DEFAULT_NAME.to_string(),
             born: DEFAULT_BORN.unwrap(),
         };
@@ -325,7 +817,7 @@ mod tests {
 
         db.create_author(author.clone()).await.unwrap();
 
-        let sql_result_before: Option<Uuid> = db.get_author_id(author.id.clone()).await.unwrap();
+        let sql_result_before: Option<Uuid> = db.get_author_id(author.id).await.unwrap();
 
         let sql_result_uuid: Uuid = db.delete_author(author.id).await.unwrap();
 
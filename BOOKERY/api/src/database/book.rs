@@ -1,102 +1,417 @@
-use sqlx::{postgres::PgRow, Row};
+use sqlx::{postgres::PgRow, Postgres, QueryBuilder, Row, Transaction};
 use time::Date;
 use uuid::Uuid;
 
-use crate::database::{conn::Database, ResultDB};
+use crate::database::{
+    conn::Database, push_date_range, push_fuzzy_match, push_ilike, set_fuzzy_threshold,
+    OrderColumns, Pagination, ResultDB,
+};
 use shared::structs::{
-    book::{Book, BookWithAuthor},
-    BookName, EditorName, PersonName,
+    book::{
+        Book, BookContributor, BookContributorName, BookFilter, BookWithAuthor, ContributorRole,
+    },
+    page::Page,
+    BookName, ConversionError, EditorName, Isbn, PersonName, SubjectName,
 };
 
+pub const BOOK_ORDER_COLUMNS: OrderColumns = &[
+    ("name", "book_name"),
+    ("author", "author_name"),
+    ("editor", "book_editor"),
+    ("release", "release"),
+    ("circulation", "circulation_count"),
+    ("last_borrowed", "last_borrowed_at"),
+];
+
+pub const BOOK_RAW_ORDER_COLUMNS: OrderColumns = &[("name", "name"), ("release", "release")];
+
+pub const BOOK_FUZZY_ORDER_COLUMNS: OrderColumns = &[
+    ("similarity", "word_similarity(search.token, b.name)"),
+    ("name", "book_name"),
+    ("author", "author_name"),
+    ("editor", "book_editor"),
+    ("release", "release"),
+];
+
+pub const BOOK_RANKED_ORDER_COLUMNS: OrderColumns = &[
+    ("relevance", "ts_rank(b.search_vector, search.query)"),
+    ("name", "book_name"),
+    ("author", "author_name"),
+    ("editor", "book_editor"),
+    ("release", "release"),
+];
+
+const BOOK_WITH_AUTHOR_SELECT: &str = "
+        SELECT b.id as id, b.name as book_name, b.isbn as isbn, contributors.author_name as author_name, contributors.contributor_names as contributor_names, contributors.contributor_roles as contributor_roles, subjects.subject_names as subject_names, p.name as book_editor, b.release as release, circulation.circulation_count as circulation_count, circulation.last_borrowed_at as last_borrowed_at
+        FROM tbl_books b
+        JOIN tbl_publishers p
+        ON p.id = b.publisher_uuid
+        CROSS JOIN LATERAL (
+            SELECT string_agg(a.name, ', ' ORDER BY ba.position) as author_name, coalesce(array_agg(a.name::text ORDER BY ba.position), '{}') as contributor_names, coalesce(array_agg(ba.role::text ORDER BY ba.position), '{}') as contributor_roles
+            FROM tbl_book_authors ba
+            JOIN tbl_authors a
+            ON a.id = ba.author_uuid
+            WHERE ba.book_uuid = b.id
+        ) contributors
+        CROSS JOIN LATERAL (
+            SELECT coalesce(array_agg(s.name::text ORDER BY s.name), '{}') as subject_names
+            FROM tbl_book_subjects bs
+            JOIN tbl_subjects s
+            ON s.id = bs.subject_uuid
+            WHERE bs.book_uuid = b.id
+        ) subjects
+        CROSS JOIN LATERAL (
+            SELECT count(r.id) as circulation_count, max(r.borrowed_at) as last_borrowed_at
+            FROM tbl_copies cp
+            JOIN tbl_rentals r
+            ON r.copy_uuid = cp.id
+            WHERE cp.book_uuid = b.id
+        ) circulation
+        ";
+
+const BOOK_SELECT: &str = "
+        SELECT id, name, isbn, contributors.author_uuids as author_uuids, contributors.roles as roles, subjects.subject_uuids as subject_uuids, publisher_uuid, release
+        FROM tbl_books
+        CROSS JOIN LATERAL (
+            SELECT coalesce(array_agg(ba.author_uuid ORDER BY ba.position), '{}') as author_uuids, coalesce(array_agg(ba.role::text ORDER BY ba.position), '{}') as roles
+            FROM tbl_book_authors ba
+            WHERE ba.book_uuid = tbl_books.id
+        ) contributors
+        CROSS JOIN LATERAL (
+            SELECT coalesce(array_agg(bs.subject_uuid ORDER BY bs.subject_uuid), '{}') as subject_uuids
+            FROM tbl_book_subjects bs
+            WHERE bs.book_uuid = tbl_books.id
+        ) subjects
+        ";
+
+fn push_contributor_ilike(
+    query: &mut QueryBuilder<'_, Postgres>,
+    book_column: &str,
+    token: String,
+) {
+    query
+        .push(" EXISTS (SELECT 1 FROM tbl_book_authors ba JOIN tbl_authors a ON a.id = ba.author_uuid WHERE ba.book_uuid = ")
+        .push(book_column)
+        .push(" AND a.name ILIKE ")
+        .push_bind(token)
+        .push(")");
+}
+
+fn push_publisher_ilike(query: &mut QueryBuilder<'_, Postgres>, token: String) {
+    query
+        .push(" EXISTS (SELECT 1 FROM tbl_publishers p WHERE p.id = tbl_books.publisher_uuid AND p.name ILIKE ")
+        .push_bind(token)
+        .push(")");
+}
+
+fn push_subject_ilike(query: &mut QueryBuilder<'_, Postgres>, book_column: &str, token: String) {
+    query
+        .push(" EXISTS (WITH RECURSIVE subject_tree AS (SELECT id FROM tbl_subjects WHERE name ILIKE ")
+        .push_bind(token)
+        .push(" UNION SELECT s.id FROM tbl_subjects s JOIN subject_tree st ON s.parent_uuid = st.id) SELECT 1 FROM tbl_book_subjects bs JOIN subject_tree st ON st.id = bs.subject_uuid WHERE bs.book_uuid = ")
+        .push(book_column)
+        .push(")");
+}
+
+fn push_book_filters(query: &mut QueryBuilder<'_, Postgres>, token: &str, filter: &BookFilter) {
+    let token: String = format!("%{token}%");
+
+    query
+        .push(" WHERE (b.name ILIKE ")
+        .push_bind(token.clone())
+        .push(" OR p.name ILIKE ")
+        .push_bind(token.clone())
+        .push(" OR");
+    push_contributor_ilike(query, "b.id", token);
+    query.push(")");
+    push_book_filter_fields(query, filter);
+}
+
+fn push_book_filter_fields(query: &mut QueryBuilder<'_, Postgres>, filter: &BookFilter) {
+    push_ilike(query, "b.name", &filter.name);
+    push_ilike(query, "p.name", &filter.editor);
+    push_date_range(
+        query,
+        "b.release",
+        filter.release_from,
+        filter.release_until,
+    );
+
+    if let Some(author) = &filter.author {
+        query.push(" AND");
+        push_contributor_ilike(query, "b.id", format!("%{author}%"));
+    }
+
+    if let Some(subject) = &filter.subject {
+        query.push(" AND");
+        push_subject_ilike(query, "b.id", format!("%{subject}%"));
+    }
+}
+
+fn push_book_fuzzy_filters(
+    query: &mut QueryBuilder<'_, Postgres>,
+    token: &str,
+    filter: &BookFilter,
+) {
+    push_fuzzy_match(query, "b.name", token);
+    push_book_filter_fields(query, filter);
+}
+
+fn push_book_ranked_filters(
+    query: &mut QueryBuilder<'_, Postgres>,
+    tsquery: &str,
+    filter: &BookFilter,
+) {
+    query
+        .push(" CROSS JOIN (SELECT to_tsquery('simple', immutable_unaccent(")
+        .push_bind(tsquery.to_string())
+        .push(")) as query) search WHERE b.search_vector @@ search.query");
+    push_book_filter_fields(query, filter);
+}
+
+pub fn prefix_tsquery(token: &str) -> Option<String> {
+    let terms: Vec<String> = token
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term: &&str| !term.is_empty())
+        .map(|term: &str| format!("{}:*", term.to_lowercase()))
+        .collect();
+
+    if terms.is_empty() {
+        return None;
+    }
+
+    Some(terms.join(" & "))
+}
+
+fn push_book_raw_filters(query: &mut QueryBuilder<'_, Postgres>, token: &str, filter: &BookFilter) {
+    let token: String = format!("%{token}%");
+
+    query
+        .push(" WHERE (name ILIKE ")
+        .push_bind(token.clone())
+        .push(" OR");
+    push_publisher_ilike(query, token);
+    query.push(")");
+    push_ilike(query, "name", &filter.name);
+    push_date_range(query, "release", filter.release_from, filter.release_until);
+
+    if let Some(editor) = &filter.editor {
+        query.push(" AND");
+        push_publisher_ilike(query, format!("%{editor}%"));
+    }
+
+    if let Some(author) = &filter.author {
+        query.push(" AND");
+        push_contributor_ilike(query, "tbl_books.id", format!("%{author}%"));
+    }
+
+    if let Some(subject) = &filter.subject {
+        query.push(" AND");
+        push_subject_ilike(query, "tbl_books.id", format!("%{subject}%"));
+    }
+}
+
+fn book_from_row(row: PgRow) -> Result<Book, sqlx::Error> {
+    let name_parser: String = row.get("name");
+    let author_uuids: Vec<Uuid> = row.get("author_uuids");
+    let roles_parser: Vec<String> = row.get("roles");
+
+    let isbn_parser: Option<String> = row.get("isbn");
+
+    let id: Uuid = row.get("id");
+    let name: BookName = BookName::try_from(name_parser)
+        .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+    let isbn: Option<Isbn> = isbn_parser
+        .map(Isbn::try_from)
+        .transpose()
+        .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+    let contributors: Vec<BookContributor> = author_uuids
+        .into_iter()
+        .zip(roles_parser)
+        .map(|(author_uuid, role): (Uuid, String)| {
+            Ok(BookContributor {
+                author_uuid,
+                role: ContributorRole::try_from(role)?,
+            })
+        })
+        .collect::<Result<Vec<BookContributor>, ConversionError>>()
+        .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+    let subjects: Vec<Uuid> = row.get("subject_uuids");
+    let publisher_uuid: Uuid = row.get("publisher_uuid");
+    let release: Date = row.get("release");
+
+    Ok(Book {
+        id,
+        name,
+        isbn,
+        contributors,
+        subjects,
+        publisher_uuid,
+        release,
+    })
+}
+
+fn book_with_author_from_row(row: PgRow) -> Result<BookWithAuthor, sqlx::Error> {
+    let name_parser: String = row.get("book_name");
+    let editor_parser: String = row.get("book_editor");
+    let contributor_names_parser: Vec<String> = row.get("contributor_names");
+    let contributor_roles_parser: Vec<String> = row.get("contributor_roles");
+    let subject_names_parser: Vec<String> = row.get("subject_names");
+
+    let isbn_parser: Option<String> = row.get("isbn");
+
+    let id: Uuid = row.get("id");
+    let name: BookName = BookName::try_from(name_parser)
+        .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+    let isbn: Option<Isbn> = isbn_parser
+        .map(Isbn::try_from)
+        .transpose()
+        .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+    let contributors: Vec<BookContributorName> = contributor_names_parser
+        .into_iter()
+        .zip(contributor_roles_parser)
+        .map(|(name, role): (String, String)| {
+            Ok(BookContributorName {
+                name: PersonName::try_from(name)?,
+                role: ContributorRole::try_from(role)?,
+            })
+        })
+        .collect::<Result<Vec<BookContributorName>, ConversionError>>()
+        .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+    let subjects: Vec<SubjectName> = subject_names_parser
+        .into_iter()
+        .map(SubjectName::try_from)
+        .collect::<Result<Vec<SubjectName>, ConversionError>>()
+        .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+    let editor: EditorName = EditorName::try_from(editor_parser)
+        .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+    let release: Date = row.get("release");
+    let circulation_count: i64 = row.get("circulation_count");
+    let last_borrowed_at: Option<Date> = row.get("last_borrowed_at");
+
+    Ok(BookWithAuthor {
+        id,
+        name,
+        isbn,
+        contributors,
+        subjects,
+        editor,
+        release,
+        circulation_count,
+        last_borrowed_at,
+    })
+}
+
+async fn insert_contributors(
+    tx: &mut Transaction<'_, Postgres>,
+    book_uuid: Uuid,
+    contributors: &[BookContributor],
+) -> ResultDB<()> {
+    for (position, contributor) in contributors.iter().enumerate() {
+        sqlx::query(
+            "
+        INSERT INTO tbl_book_authors (book_uuid, author_uuid, role, position)
+        VALUES ($1, $2, $3, $4)
+        ",
+        )
+        .bind(book_uuid)
+        .bind(contributor.author_uuid)
+        .bind(contributor.role.as_str())
+        .bind(position as i32)
+        .execute(&mut **tx)
+        .await?;
+    }
+
+    Ok(())
+}
+
+async fn insert_subjects(
+    tx: &mut Transaction<'_, Postgres>,
+    book_uuid: Uuid,
+    subjects: &[Uuid],
+) -> ResultDB<()> {
+    for subject_uuid in subjects {
+        sqlx::query(
+            "
+        INSERT INTO tbl_book_subjects (book_uuid, subject_uuid)
+        VALUES ($1, $2)
+        ",
+        )
+        .bind(book_uuid)
+        .bind(subject_uuid)
+        .execute(&mut **tx)
+        .await?;
+    }
+
+    Ok(())
+}
+
 impl Database {
     pub async fn create_book(&self, book: Book) -> ResultDB<Uuid> {
+        let mut tx: Transaction<'_, Postgres> = self.pool.begin().await?;
+
         let book_uuid: Uuid = sqlx::query(
             "
-        INSERT INTO tbl_books (id, name, author_uuid, editor, release)
+        INSERT INTO tbl_books (id, name, isbn, publisher_uuid, release)
         VALUES ($1, $2, $3, $4, $5)
         RETURNING id
         ",
         )
         .bind(book.id)
         .bind(book.name.as_str())
-        .bind(book.author_uuid)
-        .bind(book.editor.as_str())
+        .bind(book.isbn.map(|isbn: Isbn| isbn.as_str()))
+        .bind(book.publisher_uuid)
         .bind(book.release)
         .map(|row: PgRow| {
             let uuid: Uuid = row.get("id");
             uuid
         })
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *tx)
         .await?;
 
+        insert_contributors(&mut tx, book_uuid, &book.contributors).await?;
+        insert_subjects(&mut tx, book_uuid, &book.subjects).await?;
+
+        tx.commit().await?;
+
         Ok(book_uuid)
     }
 
     pub async fn get_book(&self, book_uuid: Uuid) -> ResultDB<Option<BookWithAuthor>> {
-        let book: Option<BookWithAuthor> = sqlx::query(
-            "
-        SELECT b.id as id, b.name as book_name, a.name as author_name, b.editor as book_editor, b.release as release
-        FROM tbl_books b
-        JOIN tbl_authors a
-        ON b.author_uuid = a.id
-        WHERE b.id = $1
-        ",
-        )
-        .bind(book_uuid)
-        .map(|row: PgRow| {
-            let name_parser: String = row.get("book_name");
-            let editor_parser: String = row.get("book_editor");
-            let author_name_parser: String = row.get("author_name");
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(BOOK_WITH_AUTHOR_SELECT);
+        query.push(" WHERE b.id = ").push_bind(book_uuid);
 
-            let id: Uuid = row.get("id");
-            let name: BookName = BookName::try_from(name_parser).unwrap();
-            let author_name: PersonName = PersonName::try_from(author_name_parser).unwrap();
-            let editor: EditorName = EditorName::try_from(editor_parser).unwrap();
-            let release: Date = row.get("release");
+        let book: Option<BookWithAuthor> = query
+            .build()
+            .try_map(book_with_author_from_row)
+            .fetch_optional(&self.pool)
+            .await?;
 
-            BookWithAuthor {
-                id,
-                name,
-                author_name,
-                editor,
-                release,
-            }
-        })
-        .fetch_optional(&self.pool)
-        .await?;
+        Ok(book)
+    }
+
+    pub async fn get_book_by_isbn(&self, isbn: Isbn) -> ResultDB<Option<BookWithAuthor>> {
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(BOOK_WITH_AUTHOR_SELECT);
+        query.push(" WHERE b.isbn = ").push_bind(isbn.as_str());
+
+        let book: Option<BookWithAuthor> = query
+            .build()
+            .try_map(book_with_author_from_row)
+            .fetch_optional(&self.pool)
+            .await?;
 
         Ok(book)
     }
 
     pub async fn get_book_raw(&self, book_uuid: Uuid) -> ResultDB<Option<Book>> {
-        let book: Option<Book> = sqlx::query(
-            "
-        SELECT id, name, author_uuid, editor, release
-        FROM tbl_books
-        WHERE id = $1
-        ",
-        )
-        .bind(book_uuid)
-        .map(|row: PgRow| {
-            let name_parser: String = row.get("name");
-            let editor_parser: String = row.get("editor");
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(BOOK_SELECT);
+        query.push(" WHERE id = ").push_bind(book_uuid);
 
-            let id: Uuid = row.get("id");
-            let name: BookName = BookName::try_from(name_parser).unwrap();
-            let author_uuid: Uuid = row.get("author_uuid");
-            let editor: EditorName = EditorName::try_from(editor_parser).unwrap();
-            let release: Date = row.get("release");
-
-            Book {
-                id,
-                name,
-                author_uuid,
-                editor,
-                release,
-            }
-        })
-        .fetch_optional(&self.pool)
-        .await?;
+        let book: Option<Book> = query
+            .build()
+            .try_map(book_from_row)
+            .fetch_optional(&self.pool)
+            .await?;
 
         Ok(book)
     }
@@ -120,99 +435,192 @@ impl Database {
         Ok(book_uuid)
     }
 
-    pub async fn search_books(&self, token: String) -> ResultDB<Vec<BookWithAuthor>> {
-        let book_vec: Vec<BookWithAuthor> = sqlx::query(
+    pub async fn search_books(
+        &self,
+        token: String,
+        filter: BookFilter,
+        pagination: Pagination,
+    ) -> ResultDB<Page<BookWithAuthor>> {
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(BOOK_WITH_AUTHOR_SELECT);
+        push_book_filters(&mut query, &token, &filter);
+        pagination.push_order(&mut query);
+
+        let book_vec: Vec<BookWithAuthor> = query
+            .build()
+            .try_map(book_with_author_from_row)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut count_query: QueryBuilder<Postgres> = QueryBuilder::new(
             "
-        SELECT b.id as id, b.name as book_name, a.name as author_name, b.editor as book_editor, b.release as release
+        SELECT count(*) as total
         FROM tbl_books b
-        JOIN tbl_authors a
-        ON b.author_uuid = a.id
-        WHERE a.name ILIKE $1
-        OR b.name ILIKE $1
-        OR b.editor ILIKE $1
+        JOIN tbl_publishers p
+        ON p.id = b.publisher_uuid
         ",
-        )
-        .bind(format!("%{token}%"))
-        .map(|row: PgRow| {
-            let name_parser: String = row.get("book_name");
-            let editor_parser: String = row.get("book_editor");
-            let author_name_parser: String = row.get("author_name");
+        );
+        push_book_filters(&mut count_query, &token, &filter);
 
-            let id: Uuid = row.get("id");
-            let name: BookName = BookName::try_from(name_parser).unwrap();
-            let author_name: PersonName = PersonName::try_from(author_name_parser).unwrap();
-            let editor: EditorName = EditorName::try_from(editor_parser).unwrap();
-            let release: Date = row.get("release");
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
 
-            BookWithAuthor {
-                id,
-                name,
-                author_name,
-                editor,
-                release,
-            }
-        })
-        .fetch_all(&self.pool)
-        .await?;
+        Ok(pagination.page(book_vec, total))
+    }
+
+    pub async fn search_books_fuzzy(
+        &self,
+        token: String,
+        threshold: f32,
+        filter: BookFilter,
+        pagination: Pagination,
+    ) -> ResultDB<Page<BookWithAuthor>> {
+        let mut tx: Transaction<'_, Postgres> = self.pool.begin().await?;
+        set_fuzzy_threshold(&mut tx, threshold).await?;
+
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(BOOK_WITH_AUTHOR_SELECT);
+        push_book_fuzzy_filters(&mut query, &token, &filter);
+        pagination.push_order(&mut query);
+
+        let book_vec: Vec<BookWithAuthor> = query
+            .build()
+            .try_map(book_with_author_from_row)
+            .fetch_all(&mut *tx)
+            .await?;
+
+        let mut count_query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "
+        SELECT count(*) as total
+        FROM tbl_books b
+        JOIN tbl_publishers p
+        ON p.id = b.publisher_uuid
+        ",
+        );
+        push_book_fuzzy_filters(&mut count_query, &token, &filter);
+
+        let total: i64 = count_query.build_query_scalar().fetch_one(&mut *tx).await?;
 
-        Ok(book_vec)
+        tx.commit().await?;
+
+        Ok(pagination.page(book_vec, total))
     }
 
-    pub async fn search_books_raw(&self, token: String) -> ResultDB<Vec<Book>> {
-        let book_vec: Vec<Book> = sqlx::query(
+    pub async fn search_books_ranked(
+        &self,
+        tsquery: String,
+        filter: BookFilter,
+        pagination: Pagination,
+    ) -> ResultDB<Page<BookWithAuthor>> {
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(BOOK_WITH_AUTHOR_SELECT);
+        push_book_ranked_filters(&mut query, &tsquery, &filter);
+        pagination.push_order(&mut query);
+
+        let book_vec: Vec<BookWithAuthor> = query
+            .build()
+            .try_map(book_with_author_from_row)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut count_query: QueryBuilder<Postgres> = QueryBuilder::new(
             "
-        SELECT id, name, author_uuid, editor, release
+        SELECT count(*) as total
+        FROM tbl_books b
+        JOIN tbl_publishers p
+        ON p.id = b.publisher_uuid
+        ",
+        );
+        push_book_ranked_filters(&mut count_query, &tsquery, &filter);
+
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(pagination.page(book_vec, total))
+    }
+
+    pub async fn search_books_raw(
+        &self,
+        token: String,
+        filter: BookFilter,
+        pagination: Pagination,
+    ) -> ResultDB<Page<Book>> {
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(BOOK_SELECT);
+        push_book_raw_filters(&mut query, &token, &filter);
+        pagination.push_order(&mut query);
+
+        let book_vec: Vec<Book> = query
+            .build()
+            .try_map(book_from_row)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut count_query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "
+        SELECT count(*) as total
         FROM tbl_books
-        WHERE name ILIKE $1
-        OR editor ILIKE $1
         ",
-        )
-        .bind(format!("%{token}%"))
-        .map(|row: PgRow| {
-            let name_parser: String = row.get("name");
-            let editor_parser: String = row.get("editor");
+        );
+        push_book_raw_filters(&mut count_query, &token, &filter);
 
-            let id: Uuid = row.get("id");
-            let name: BookName = BookName::try_from(name_parser).unwrap();
-            let author_uuid: Uuid = row.get("author_uuid");
-            let editor: EditorName = EditorName::try_from(editor_parser).unwrap();
-            let release: Date = row.get("release");
-
-            Book {
-                id,
-                name,
-                author_uuid,
-                editor,
-                release,
-            }
-        })
-        .fetch_all(&self.pool)
-        .await?;
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
 
-        Ok(book_vec)
+        Ok(pagination.page(book_vec, total))
     }
 
     pub async fn update_book(&self, book: Book) -> ResultDB<Uuid> {
+        let mut tx: Transaction<'_, Postgres> = self.pool.begin().await?;
+
         let book_uuid: Uuid = sqlx::query(
             "
         UPDATE tbl_books
-        SET name = $1, author_uuid = $2, editor = $3, release = $4
+        SET name = $1, isbn = $2, publisher_uuid = $3, release = $4
         WHERE id = $5
         RETURNING id
         ",
         )
         .bind(book.name.as_str())
-        .bind(book.author_uuid)
-        .bind(book.editor.as_str())
+        .bind(book.isbn.map(|isbn: Isbn| isbn.as_str()))
+        .bind(book.publisher_uuid)
         .bind(book.release)
         .bind(book.id)
         .map(|row: PgRow| {
             let uuid: Uuid = row.get("id");
             uuid
         })
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *tx)
         .await?;
 
+        sqlx::query(
+            "
+        DELETE FROM tbl_book_authors
+        WHERE book_uuid = $1
+        ",
+        )
+        .bind(book_uuid)
+        .execute(&mut *tx)
+        .await?;
+
+        insert_contributors(&mut tx, book_uuid, &book.contributors).await?;
+
+        sqlx::query(
+            "
+        DELETE FROM tbl_book_subjects
+        WHERE book_uuid = $1
+        ",
+        )
+        .bind(book_uuid)
+        .execute(&mut *tx)
+        .await?;
+
+        insert_subjects(&mut tx, book_uuid, &book.subjects).await?;
+
+        tx.commit().await?;
+
         Ok(book_uuid)
     }
 
@@ -257,14 +665,17 @@ mod tests {
 
     use time::{error::ComponentRange, Date, Month};
 
+    use crate::database::Direction;
     use crate::handlers::QueryURL;
     use shared::structs::{
         author::{Author, PayloadAuthor},
-        book::{PayloadBook, PayloadUpdateBook},
+        book::{PayloadBook, PayloadContributor, PayloadUpdateBook},
+        publisher::{PayloadPublisher, Publisher},
+        subject::{PayloadSubject, Subject},
     };
 
-    const DEFAULT_NAME: &'static str = "Name";
-    const DEFAULT_EDITOR: &'static str = "Editor";
+    const DEFAULT_NAME: &str = "Name";
+    const DEFAULT_EDITOR: &str = "Editor";
     const DEFAULT_RELEASE: Result<Date, ComponentRange> =
         Date::from_calendar_date(2000, Month::January, 1);
 
@@ -288,23 +699,54 @@ mod tests {
 
         let author_uuid: Uuid = db.create_author(author).await.unwrap();
 
+        let payload_publisher: PayloadPublisher = PayloadPublisher {
+            name: format!("{DEFAULT_EDITOR} {}", Uuid::new_v4().simple()),
+        };
+
+        let publisher_uuid: Uuid = db
+            .create_publisher(Publisher::create(payload_publisher).unwrap())
+            .await
+            .unwrap();
+
         let payload_book: PayloadBook = PayloadBook {
             name: DEFAULT_NAME.to_string(),
-            author_uuid,
-            editor: DEFAULT_EDITOR.to_string(),
+            isbn: None,
+            contributors: vec![PayloadContributor {
+                author_uuid,
+                role: "author".to_string(),
+            }],
+            subjects: vec![],
+            publisher_uuid,
             release: DEFAULT_RELEASE.unwrap(),
         };
 
         Book::create(payload_book).unwrap()
     }
 
+    fn random_isbn() -> String {
+        let prefix: String = format!("978{:09}", Uuid::new_v4().as_u128() % 1_000_000_000);
+
+        (0..10)
+            .map(|check: u32| format!("{prefix}{check}"))
+            .find(|isbn: &String| Isbn::try_from(isbn.clone()).is_ok())
+            .unwrap()
+    }
+
+    async fn publisher_name(db: &Database, book: &Book) -> EditorName {
+        db.get_publisher(book.publisher_uuid)
+            .await
+            .unwrap()
+            .unwrap()
+            .name
+    }
+
     #[sqlx::test]
     async fn test_create_book() {
         let db: Database = conn_db().await;
 
         let book: Book = create_book().await;
 
-        let book_uuid: Uuid = book.id.clone();
+        let book_uuid: Uuid = book.id;
 
         let sql_result: Uuid = db.create_book(book).await.unwrap();
 
@@ -316,6 +758,7 @@ mod tests {
         let db: Database = conn_db().await;
 
         let book: Book = create_book().await;
+        let editor: EditorName = publisher_name(&db, &book).await;
 
         let created_book_uuid: Uuid = db.create_book(book.clone()).await.unwrap();
 
@@ -326,9 +769,16 @@ mod tests {
             BookWithAuthor {
                 id: book.id,
                 name: book.name,
-                author_name: PersonName::try_from(DEFAULT_NAME.to_string()).unwrap(),
-                editor: book.editor,
-                release: book.release
+                isbn: None,
+                contributors: vec![BookContributorName {
+                    name: PersonName::try_from(DEFAULT_NAME.to_string()).unwrap(),
+                    role: ContributorRole::Author,
+                }],
+                subjects: vec![],
+                editor,
+                release: book.release,
+                circulation_count: 0,
+                last_borrowed_at: None,
             }
         );
     }
@@ -339,11 +789,58 @@ mod tests {
 
         let book: Book = create_book().await;
 
-        let sql_result: Option<BookWithAuthor> = db.get_book(book.id.clone()).await.unwrap();
+        let sql_result: Option<BookWithAuthor> = db.get_book(book.id).await.unwrap();
 
         assert!(sql_result.is_none());
     }
 
+    #[sqlx::test]
+    async fn test_get_book_by_isbn() {
+        let db: Database = conn_db().await;
+
+        let mut book: Book = create_book().await;
+        book.isbn = Some(Isbn::try_from(random_isbn()).unwrap());
+
+        let book_uuid: Uuid = db.create_book(book.clone()).await.unwrap();
+
+        let sql_result: BookWithAuthor = db
+            .get_book_by_isbn(book.isbn.clone().unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(sql_result.id, book_uuid);
+        assert_eq!(sql_result.isbn, book.isbn);
+
+        let sql_result: Option<BookWithAuthor> = db
+            .get_book_by_isbn(Isbn::try_from(random_isbn()).unwrap())
+            .await
+            .unwrap();
+
+        assert!(sql_result.is_none());
+    }
+
+    #[sqlx::test]
+    async fn test_create_book_duplicated_isbn() {
+        let db: Database = conn_db().await;
+
+        let isbn: Isbn = Isbn::try_from(random_isbn()).unwrap();
+
+        let mut book: Book = create_book().await;
+        book.isbn = Some(isbn.clone());
+        db.create_book(book).await.unwrap();
+
+        let mut book: Book = create_book().await;
+        book.isbn = Some(isbn);
+
+        let sql_result: sqlx::Error = db.create_book(book).await.unwrap_err();
+
+        assert_eq!(
+            sql_result.as_database_error().unwrap().constraint(),
+            Some("idx_books_isbn")
+        );
+    }
+
     #[sqlx::test]
     async fn test_get_book_raw_found() {
         let db: Database = conn_db().await;
@@ -352,7 +849,7 @@ mod tests {
 
         let book_uuid: Uuid = db.create_book(book.clone()).await.unwrap();
 
-        let sql_result: Book = db.get_book_raw(book_uuid.clone()).await.unwrap().unwrap();
+        let sql_result: Book = db.get_book_raw(book_uuid).await.unwrap().unwrap();
 
         assert_eq!(sql_result, book);
     }
@@ -363,7 +860,7 @@ mod tests {
 
         let book: Book = create_book().await;
 
-        let sql_result: Option<Book> = db.get_book_raw(book.id.clone()).await.unwrap();
+        let sql_result: Option<Book> = db.get_book_raw(book.id).await.unwrap();
 
         assert!(sql_result.is_none());
     }
@@ -376,7 +873,7 @@ mod tests {
 
         let book_uuid: Uuid = db.create_book(book.clone()).await.unwrap();
 
-        let sql_result: Uuid = db.get_book_id(book_uuid.clone()).await.unwrap().unwrap();
+        let sql_result: Uuid = db.get_book_id(book_uuid).await.unwrap().unwrap();
 
         assert_eq!(sql_result, book_uuid);
     }
@@ -387,7 +884,7 @@ mod tests {
 
         let book: Book = create_book().await;
 
-        let sql_result: Option<Uuid> = db.get_book_id(book.id.clone()).await.unwrap();
+        let sql_result: Option<Uuid> = db.get_book_id(book.id).await.unwrap();
 
         assert!(sql_result.is_none());
     }
@@ -397,21 +894,38 @@ mod tests {
         let db: Database = conn_db().await;
 
         let book: Book = create_book().await;
+        let editor: EditorName = publisher_name(&db, &book).await;
 
         let token: QueryURL = QueryURL {
             token: "Nam".to_string(),
+            ..Default::default()
         };
 
         let book_uuid: Uuid = db.create_book(book).await.unwrap();
 
-        let sql_result: Vec<BookWithAuthor> = db.search_books(token.token).await.unwrap();
+        let sql_result: Vec<BookWithAuthor> = db
+            .search_books(
+                token.token,
+                Default::default(),
+                Pagination::new(BOOK_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
 
         assert!(sql_result.contains(&BookWithAuthor {
             id: book_uuid,
             name: BookName::try_from(DEFAULT_NAME.to_string()).unwrap(),
-            author_name: PersonName::try_from(DEFAULT_NAME.to_string()).unwrap(),
-            editor: EditorName::try_from(DEFAULT_EDITOR.to_string()).unwrap(),
-            release: DEFAULT_RELEASE.unwrap()
+            isbn: None,
+            contributors: vec![BookContributorName {
+                name: PersonName::try_from(DEFAULT_NAME.to_string()).unwrap(),
+                role: ContributorRole::Author,
+            }],
+            subjects: vec![],
+            editor,
+            release: DEFAULT_RELEASE.unwrap(),
+            circulation_count: 0,
+            last_borrowed_at: None,
         }));
     }
 
@@ -420,21 +934,38 @@ mod tests {
         let db: Database = conn_db().await;
 
         let book: Book = create_book().await;
+        let editor: EditorName = publisher_name(&db, &book).await;
 
         let token: QueryURL = QueryURL {
             token: "nam".to_string(),
+            ..Default::default()
         };
 
         let book_uuid: Uuid = db.create_book(book).await.unwrap();
 
-        let sql_result: Vec<BookWithAuthor> = db.search_books(token.token).await.unwrap();
+        let sql_result: Vec<BookWithAuthor> = db
+            .search_books(
+                token.token,
+                Default::default(),
+                Pagination::new(BOOK_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
 
         assert!(sql_result.contains(&BookWithAuthor {
             id: book_uuid,
             name: BookName::try_from(DEFAULT_NAME.to_string()).unwrap(),
-            author_name: PersonName::try_from(DEFAULT_NAME.to_string()).unwrap(),
-            editor: EditorName::try_from(DEFAULT_EDITOR.to_string()).unwrap(),
-            release: DEFAULT_RELEASE.unwrap()
+            isbn: None,
+            contributors: vec![BookContributorName {
+                name: PersonName::try_from(DEFAULT_NAME.to_string()).unwrap(),
+                role: ContributorRole::Author,
+            }],
+            subjects: vec![],
+            editor,
+            release: DEFAULT_RELEASE.unwrap(),
+            circulation_count: 0,
+            last_borrowed_at: None,
         }));
     }
 
@@ -443,24 +974,298 @@ mod tests {
         let db: Database = conn_db().await;
 
         let book: Book = create_book().await;
+        let editor: EditorName = publisher_name(&db, &book).await;
 
         let token: QueryURL = QueryURL {
             token: "foo".to_string(),
+            ..Default::default()
         };
 
         let book_uuid: Uuid = db.create_book(book).await.unwrap();
 
-        let sql_result: Vec<BookWithAuthor> = db.search_books(token.token).await.unwrap();
+        let sql_result: Vec<BookWithAuthor> = db
+            .search_books(
+                token.token,
+                Default::default(),
+                Pagination::new(BOOK_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
 
         assert!(!sql_result.contains(&BookWithAuthor {
             id: book_uuid,
             name: BookName::try_from(DEFAULT_NAME.to_string()).unwrap(),
-            author_name: PersonName::try_from(DEFAULT_NAME.to_string()).unwrap(),
-            editor: EditorName::try_from(DEFAULT_EDITOR.to_string()).unwrap(),
-            release: DEFAULT_RELEASE.unwrap()
+            isbn: None,
+            contributors: vec![BookContributorName {
+                name: PersonName::try_from(DEFAULT_NAME.to_string()).unwrap(),
+                role: ContributorRole::Author,
+            }],
+            subjects: vec![],
+            editor,
+            release: DEFAULT_RELEASE.unwrap(),
+            circulation_count: 0,
+            last_borrowed_at: None,
         }));
     }
 
+    #[sqlx::test]
+    async fn test_search_books_filtered() {
+        let db: Database = conn_db().await;
+
+        let book_uuid: Uuid = db.create_book(create_book().await).await.unwrap();
+
+        let filter: BookFilter = BookFilter {
+            name: Some("nam".to_string()),
+            author: Some("nam".to_string()),
+            editor: Some(DEFAULT_EDITOR.to_string()),
+            subject: None,
+            release_from: Some(DEFAULT_RELEASE.unwrap()),
+            release_until: Some(DEFAULT_RELEASE.unwrap()),
+        };
+
+        let sql_result: Vec<BookWithAuthor> = db
+            .search_books(
+                String::new(),
+                filter.clone(),
+                Pagination::new(BOOK_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
+
+        assert!(sql_result
+            .iter()
+            .any(|book: &BookWithAuthor| book.id == book_uuid));
+
+        let sql_result: Vec<Book> = db
+            .search_books_raw(
+                String::new(),
+                filter,
+                Pagination::new(BOOK_RAW_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
+
+        assert!(sql_result.iter().any(|book: &Book| book.id == book_uuid));
+
+        let filter: BookFilter = BookFilter {
+            author: Some("Nobody".to_string()),
+            ..Default::default()
+        };
+
+        let sql_result: Vec<BookWithAuthor> = db
+            .search_books(String::new(), filter, Pagination::new(BOOK_ORDER_COLUMNS))
+            .await
+            .unwrap()
+            .items;
+
+        assert!(!sql_result
+            .iter()
+            .any(|book: &BookWithAuthor| book.id == book_uuid));
+    }
+
+    #[sqlx::test]
+    async fn test_search_books_matches_any_contributor() {
+        let db: Database = conn_db().await;
+
+        let payload_translator: PayloadAuthor = PayloadAuthor {
+            name: "Quenby Translator".to_string(),
+            born: DEFAULT_BORN.unwrap(),
+        };
+        let translator_uuid: Uuid = db
+            .create_author(Author::create(payload_translator).unwrap())
+            .await
+            .unwrap();
+
+        let mut book: Book = create_book().await;
+        book.contributors.push(BookContributor {
+            author_uuid: translator_uuid,
+            role: ContributorRole::Translator,
+        });
+
+        let book_uuid: Uuid = db.create_book(book.clone()).await.unwrap();
+
+        let sql_result: BookWithAuthor = db.get_book(book_uuid).await.unwrap().unwrap();
+
+        assert_eq!(
+            sql_result.contributors,
+            vec![
+                BookContributorName {
+                    name: PersonName::try_from(DEFAULT_NAME.to_string()).unwrap(),
+                    role: ContributorRole::Author,
+                },
+                BookContributorName {
+                    name: PersonName::try_from("Quenby Translator".to_string()).unwrap(),
+                    role: ContributorRole::Translator,
+                },
+            ]
+        );
+
+        let sql_result: Vec<BookWithAuthor> = db
+            .search_books(
+                "quenby".to_string(),
+                Default::default(),
+                Pagination::new(BOOK_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
+
+        assert!(sql_result
+            .iter()
+            .any(|book: &BookWithAuthor| book.id == book_uuid));
+
+        let filter: BookFilter = BookFilter {
+            author: Some("quenby".to_string()),
+            ..Default::default()
+        };
+
+        let sql_result: Vec<Book> = db
+            .search_books_raw(
+                String::new(),
+                filter,
+                Pagination::new(BOOK_RAW_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
+
+        assert!(sql_result.contains(&book));
+    }
+
+    #[sqlx::test]
+    async fn test_search_books_by_subject_includes_descendants() {
+        let db: Database = conn_db().await;
+
+        let parent: Subject = Subject::create(PayloadSubject {
+            name: format!("Subject {}", Uuid::new_v4().simple()),
+            parent_uuid: None,
+        })
+        .unwrap();
+        db.create_subject(parent.clone()).await.unwrap();
+
+        let child: Subject = Subject::create(PayloadSubject {
+            name: format!("Subject {}", Uuid::new_v4().simple()),
+            parent_uuid: Some(parent.id),
+        })
+        .unwrap();
+        db.create_subject(child.clone()).await.unwrap();
+
+        let mut book: Book = create_book().await;
+        book.subjects = vec![child.id];
+
+        let book_uuid: Uuid = db.create_book(book.clone()).await.unwrap();
+
+        let sql_result: BookWithAuthor = db.get_book(book_uuid).await.unwrap().unwrap();
+
+        assert_eq!(sql_result.subjects, vec![child.name.clone()]);
+
+        for (subject, found) in [
+            (parent.name.as_str().to_lowercase(), true),
+            (child.name.as_str(), true),
+            ("Nothing".to_string(), false),
+        ] {
+            let filter: BookFilter = BookFilter {
+                subject: Some(subject),
+                ..Default::default()
+            };
+
+            let sql_result: Vec<BookWithAuthor> = db
+                .search_books(
+                    String::new(),
+                    filter.clone(),
+                    Pagination::new(BOOK_ORDER_COLUMNS),
+                )
+                .await
+                .unwrap()
+                .items;
+
+            assert_eq!(
+                sql_result
+                    .iter()
+                    .any(|book: &BookWithAuthor| book.id == book_uuid),
+                found
+            );
+
+            let sql_result: Vec<Book> = db
+                .search_books_raw(
+                    String::new(),
+                    filter,
+                    Pagination::new(BOOK_RAW_ORDER_COLUMNS),
+                )
+                .await
+                .unwrap()
+                .items;
+
+            assert_eq!(sql_result.contains(&book), found);
+        }
+    }
+
+    #[test]
+    fn test_prefix_tsquery() {
+        assert_eq!(
+            prefix_tsquery("Dom  Casmurro!"),
+            Some("dom:* & casmurro:*".to_string())
+        );
+        assert_eq!(
+            prefix_tsquery("São-Paulo"),
+            Some("são:* & paulo:*".to_string())
+        );
+        assert_eq!(prefix_tsquery(" & | ! "), None);
+    }
+
+    #[sqlx::test]
+    async fn test_search_books_ranked() {
+        let db: Database = conn_db().await;
+
+        let word: String = format!("q{}", Uuid::new_v4().simple());
+
+        let mut named_book: Book = create_book().await;
+        named_book.name = BookName::try_from(format!("Ação {word}")).unwrap();
+        let named_book_uuid: Uuid = db.create_book(named_book).await.unwrap();
+
+        let published_book: Book = create_book().await;
+        let published_book_uuid: Uuid = db.create_book(published_book.clone()).await.unwrap();
+        db.update_publisher(Publisher {
+            id: published_book.publisher_uuid,
+            name: EditorName::try_from(format!("{DEFAULT_EDITOR} {word}")).unwrap(),
+        })
+        .await
+        .unwrap();
+
+        let sql_result: Vec<Uuid> = db
+            .search_books_ranked(
+                prefix_tsquery(&word[..12]).unwrap(),
+                Default::default(),
+                Pagination {
+                    direction: Direction::Desc,
+                    ..Pagination::new(BOOK_RANKED_ORDER_COLUMNS)
+                },
+            )
+            .await
+            .unwrap()
+            .items
+            .iter()
+            .map(|book: &BookWithAuthor| book.id)
+            .collect();
+
+        assert_eq!(sql_result, vec![named_book_uuid, published_book_uuid]);
+
+        let sql_result: Page<BookWithAuthor> = db
+            .search_books_ranked(
+                prefix_tsquery(&format!("acao {word}")).unwrap(),
+                Default::default(),
+                Pagination::new(BOOK_RANKED_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(sql_result.total, 1);
+        assert_eq!(sql_result.items[0].id, named_book_uuid);
+    }
+
     #[sqlx::test]
     async fn test_search_books_raw_case_sensitive_found() {
         let db: Database = conn_db().await;
@@ -469,11 +1274,20 @@ mod tests {
 
         let token: QueryURL = QueryURL {
             token: "Nam".to_string(),
+            ..Default::default()
         };
 
         db.create_book(book.clone()).await.unwrap();
 
-        let sql_result: Vec<Book> = db.search_books_raw(token.token).await.unwrap();
+        let sql_result: Vec<Book> = db
+            .search_books_raw(
+                token.token,
+                Default::default(),
+                Pagination::new(BOOK_RAW_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
 
         assert!(sql_result.contains(&book));
     }
@@ -486,11 +1300,20 @@ mod tests {
 
         let token: QueryURL = QueryURL {
             token: "nam".to_string(),
+            ..Default::default()
         };
 
         db.create_book(book.clone()).await.unwrap();
 
-        let sql_result: Vec<Book> = db.search_books_raw(token.token).await.unwrap();
+        let sql_result: Vec<Book> = db
+            .search_books_raw(
+                token.token,
+                Default::default(),
+                Pagination::new(BOOK_RAW_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
 
         assert!(sql_result.contains(&book));
     }
@@ -503,11 +1326,20 @@ mod tests {
 
         let token: QueryURL = QueryURL {
             token: "foo".to_string(),
+            ..Default::default()
         };
 
         db.create_book(book.clone()).await.unwrap();
 
-        let sql_result: Vec<Book> = db.search_books_raw(token.token).await.unwrap();
+        let sql_result: Vec<Book> = db
+            .search_books_raw(
+                token.token,
+                Default::default(),
+                Pagination::new(BOOK_RAW_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
 
         assert!(!sql_result.contains(&book));
     }
@@ -521,10 +1353,15 @@ mod tests {
         let sql_book_uuid: Uuid = db.create_book(book.clone()).await.unwrap();
 
         let payload_update_book: PayloadUpdateBook = PayloadUpdateBook {
-            id: sql_book_uuid.clone(),
+            id: sql_book_uuid,
             name: DEFAULT_NAME.to_string(),
-            author_uuid: book.author_uuid.clone(),
-            editor: DEFAULT_EDITOR.to_string(),
+            isbn: None,
+            contributors: vec![PayloadContributor {
+                author_uuid: book.contributors[0].author_uuid,
+                role: "author".to_string(),
+            }],
+            subjects: vec![],
+            publisher_uuid: book.publisher_uuid,
             release: DEFAULT_RELEASE.unwrap(),
         };
 
@@ -545,7 +1382,7 @@ mod tests {
 
         db.create_book(book.clone()).await.unwrap();
 
-        let sql_result_before: Option<Uuid> = db.get_book_id(book.id.clone()).await.unwrap();
+        let sql_result_before: Option<Uuid> = db.get_book_id(book.id).await.unwrap();
 
         let sql_result_uuid: Uuid = db.delete_book(book.id).await.unwrap();
 
@@ -1,7 +1,10 @@
 use sqlx::{postgres::PgPoolOptions, PgPool, Pool, Postgres};
 
+use crate::policy::Policy;
+
 pub struct Database {
     pub pool: PgPool,
+    pub policy: Policy,
 }
 
 impl Database {
@@ -14,6 +17,9 @@ impl Database {
 
         sqlx::migrate!("./src/migrations").run(&pool).await.unwrap();
 
-        Self { pool }
+        Self {
+            pool,
+            policy: Policy::from_env(),
+        }
     }
 }
@@ -0,0 +1,454 @@
+use sqlx::{postgres::PgRow, Row};
+use uuid::Uuid;
+
+use crate::database::{conn::Database, ResultDB};
+use shared::structs::{
+    copy::{BookCopies, BookCopy, BookCopyWithLoan, CopyCondition, CopyStatus},
+    Barcode, ConversionError, ShelfLocation,
+};
+
+impl Database {
+    pub async fn create_copy(&self, copy: BookCopy) -> ResultDB<Uuid> {
+        let copy_uuid: Uuid = sqlx::query(
+            "
+            INSERT INTO tbl_copies (id, book_uuid, barcode, condition, shelf_location, status)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id
+        ",
+        )
+        .bind(copy.id)
+        .bind(copy.book_uuid)
+        .bind(copy.barcode.as_str())
+        .bind(copy.condition.as_str())
+        .bind(copy.shelf_location.as_str())
+        .bind(copy.status.as_str())
+        .map(|row: PgRow| {
+            let uuid: Uuid = row.get("id");
+            uuid
+        })
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(copy_uuid)
+    }
+
+    pub async fn get_copy(&self, copy_uuid: Uuid) -> ResultDB<Option<BookCopy>> {
+        let copy: Option<BookCopy> = sqlx::query(
+            "
+            SELECT id, book_uuid, barcode, condition, shelf_location, status
+            FROM tbl_copies
+            WHERE id = $1
+        ",
+        )
+        .bind(copy_uuid)
+        .try_map(|row: PgRow| {
+            let barcode_parser: String = row.get("barcode");
+            let condition_parser: String = row.get("condition");
+            let shelf_location_parser: String = row.get("shelf_location");
+            let status_parser: String = row.get("status");
+
+            let id: Uuid = row.get("id");
+            let book_uuid: Uuid = row.get("book_uuid");
+            let barcode: Barcode = Barcode::try_from(barcode_parser)
+                .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+            let condition: CopyCondition = CopyCondition::try_from(condition_parser)
+                .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+            let shelf_location: ShelfLocation = ShelfLocation::try_from(shelf_location_parser)
+                .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+            let status: CopyStatus = CopyStatus::try_from(status_parser)
+                .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+
+            Ok(BookCopy {
+                id,
+                book_uuid,
+                barcode,
+                condition,
+                shelf_location,
+                status,
+            })
+        })
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(copy)
+    }
+
+    pub async fn get_copy_id(&self, copy_uuid: Uuid) -> ResultDB<Option<Uuid>> {
+        let copy_uuid: Option<Uuid> = sqlx::query(
+            "
+            SELECT id
+            FROM tbl_copies
+            WHERE id = $1
+        ",
+        )
+        .bind(copy_uuid)
+        .map(|row: PgRow| {
+            let id: Uuid = row.get("id");
+            id
+        })
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(copy_uuid)
+    }
+
+    pub async fn list_book_copies(&self, book_uuid: Uuid) -> ResultDB<BookCopies> {
+        let copies_vec: Vec<BookCopyWithLoan> = sqlx::query(
+            "
+            SELECT cp.id, cp.barcode, cp.condition, cp.shelf_location, cp.status,
+            EXISTS (
+                SELECT 1
+                FROM tbl_rentals r
+                WHERE r.copy_uuid = cp.id AND r.returned_at IS NULL
+            ) as on_loan
+            FROM tbl_copies cp
+            WHERE cp.book_uuid = $1
+            ORDER BY cp.barcode
+        ",
+        )
+        .bind(book_uuid)
+        .try_map(|row: PgRow| {
+            let barcode_parser: String = row.get("barcode");
+            let condition_parser: String = row.get("condition");
+            let shelf_location_parser: String = row.get("shelf_location");
+            let status_parser: String = row.get("status");
+
+            let id: Uuid = row.get("id");
+            let barcode: Barcode = Barcode::try_from(barcode_parser)
+                .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+            let condition: CopyCondition = CopyCondition::try_from(condition_parser)
+                .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+            let shelf_location: ShelfLocation = ShelfLocation::try_from(shelf_location_parser)
+                .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+            let status: CopyStatus = CopyStatus::try_from(status_parser)
+                .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+            let on_loan: bool = row.get("on_loan");
+
+            Ok(BookCopyWithLoan {
+                id,
+                barcode,
+                condition,
+                shelf_location,
+                status,
+                on_loan,
+            })
+        })
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(BookCopies::new(book_uuid, copies_vec))
+    }
+
+    pub async fn update_copy(&self, copy: BookCopy) -> ResultDB<Uuid> {
+        let copy_uuid: Uuid = sqlx::query(
+            "
+            UPDATE tbl_copies
+            SET book_uuid = $1, barcode = $2, condition = $3, shelf_location = $4, status = $5
+            WHERE id = $6
+            RETURNING id
+        ",
+        )
+        .bind(copy.book_uuid)
+        .bind(copy.barcode.as_str())
+        .bind(copy.condition.as_str())
+        .bind(copy.shelf_location.as_str())
+        .bind(copy.status.as_str())
+        .bind(copy.id)
+        .map(|row: PgRow| {
+            let uuid: Uuid = row.get("id");
+            uuid
+        })
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(copy_uuid)
+    }
+
+    pub async fn delete_copy(&self, copy_uuid: Uuid) -> ResultDB<Uuid> {
+        let copy_uuid: Uuid = sqlx::query(
+            "
+            DELETE FROM tbl_copies
+            WHERE id = $1
+            RETURNING id
+        ",
+        )
+        .bind(copy_uuid)
+        .map(|row: PgRow| {
+            let uuid: Uuid = row.get("id");
+            uuid
+        })
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(copy_uuid)
+    }
+
+    pub async fn count_copies(&self) -> ResultDB<i64> {
+        let total: i64 = sqlx::query_scalar(
+            "
+            SELECT count(*) as total
+            FROM tbl_copies
+        ",
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::env::var;
+
+    use time::{error::ComponentRange, Date, Month};
+
+    use shared::structs::{
+        author::{Author, PayloadAuthor},
+        book::{Book, PayloadBook, PayloadContributor},
+        copy::{PayloadBookCopy, PayloadUpdateBookCopy},
+        costumer::{Costumer, PayloadCostumer, PayloadDocument},
+        publisher::{PayloadPublisher, Publisher},
+    };
+
+    const DEFAULT_NAME: &str = "Name";
+    const DEFAULT_EDITOR: &str = "Editor";
+    const DEFAULT_SHELF_LOCATION: &str = "A-01";
+    const DEFAULT_DATE: Result<Date, ComponentRange> =
+        Date::from_calendar_date(2000, Month::January, 1);
+
+    fn random_document() -> PayloadDocument {
+        PayloadDocument {
+            kind: "rg".to_string(),
+            value: format!("{:014}", Uuid::new_v4().as_u128() % 10u128.pow(14)),
+        }
+    }
+
+    async fn conn_db() -> Database {
+        let db_url: String = var("DATABASE_URL").unwrap();
+        Database::conn(&db_url).await
+    }
+
+    async fn create_book() -> Uuid {
+        let db: Database = conn_db().await;
+
+        let payload_author: PayloadAuthor = PayloadAuthor {
+            name: DEFAULT_NAME.to_string(),
+            born: DEFAULT_DATE.unwrap(),
+        };
+
+        let author_uuid: Uuid = db
+            .create_author(Author::create(payload_author).unwrap())
+            .await
+            .unwrap();
+
+        let payload_publisher: PayloadPublisher = PayloadPublisher {
+            name: format!("{DEFAULT_EDITOR} {}", Uuid::new_v4().simple()),
+        };
+
+        let publisher_uuid: Uuid = db
+            .create_publisher(Publisher::create(payload_publisher).unwrap())
+            .await
+            .unwrap();
+
+        let payload_book: PayloadBook = PayloadBook {
+            name: DEFAULT_NAME.to_string(),
+            isbn: None,
+            contributors: vec![PayloadContributor {
+                author_uuid,
+                role: "author".to_string(),
+            }],
+            subjects: vec![],
+            publisher_uuid,
+            release: DEFAULT_DATE.unwrap(),
+        };
+
+        db.create_book(Book::create(payload_book).unwrap())
+            .await
+            .unwrap()
+    }
+
+    fn create_copy(book_uuid: Uuid, status: &str) -> BookCopy {
+        let payload_copy: PayloadBookCopy = PayloadBookCopy {
+            book_uuid,
+            barcode: Uuid::new_v4().simple().to_string(),
+            condition: "good".to_string(),
+            shelf_location: DEFAULT_SHELF_LOCATION.to_string(),
+            status: status.to_string(),
+        };
+
+        BookCopy::create(payload_copy).unwrap()
+    }
+
+    #[sqlx::test]
+    async fn test_create_copy() {
+        let db: Database = conn_db().await;
+
+        let copy: BookCopy = create_copy(create_book().await, "available");
+
+        let copy_uuid: Uuid = copy.id;
+
+        let sql_result: Uuid = db.create_copy(copy).await.unwrap();
+
+        assert_eq!(sql_result, copy_uuid);
+    }
+
+    #[sqlx::test]
+    async fn test_create_copy_duplicated_barcode() {
+        let db: Database = conn_db().await;
+
+        let copy: BookCopy = create_copy(create_book().await, "available");
+        let mut second_copy: BookCopy = copy.clone();
+        second_copy.id = Uuid::new_v4();
+
+        db.create_copy(copy).await.unwrap();
+
+        assert!(db.create_copy(second_copy).await.is_err());
+    }
+
+    #[sqlx::test]
+    async fn test_get_copy_found() {
+        let db: Database = conn_db().await;
+
+        let copy: BookCopy = create_copy(create_book().await, "available");
+
+        let copy_uuid: Uuid = db.create_copy(copy.clone()).await.unwrap();
+
+        let sql_result: BookCopy = db.get_copy(copy_uuid).await.unwrap().unwrap();
+
+        assert_eq!(sql_result, copy);
+    }
+
+    #[sqlx::test]
+    async fn test_get_copy_not_found() {
+        let db: Database = conn_db().await;
+
+        let sql_result: Option<BookCopy> = db.get_copy(Uuid::new_v4()).await.unwrap();
+
+        assert!(sql_result.is_none());
+    }
+
+    #[sqlx::test]
+    async fn test_get_copy_invalid_barcode() {
+        let db: Database = conn_db().await;
+
+        let copy: BookCopy = create_copy(create_book().await, "available");
+
+        sqlx::query(
+            "
+            INSERT INTO tbl_copies (id, book_uuid, barcode, condition, shelf_location, status)
+            VALUES ($1, $2, $3, 'good', '', 'available')
+        ",
+        )
+        .bind(copy.id)
+        .bind(copy.book_uuid)
+        .bind(format!("BK {}", &Uuid::new_v4().simple().to_string()[..12]))
+        .execute(&db.pool)
+        .await
+        .unwrap();
+
+        let sql_result: ResultDB<Option<BookCopy>> = db.get_copy(copy.id).await;
+
+        assert!(matches!(sql_result, Err(sqlx::Error::Decode(_))));
+    }
+
+    #[sqlx::test]
+    async fn test_list_book_copies() {
+        let db: Database = conn_db().await;
+
+        let book_uuid: Uuid = create_book().await;
+
+        let loaned_copy_uuid: Uuid = db
+            .create_copy(create_copy(book_uuid, "available"))
+            .await
+            .unwrap();
+        db.create_copy(create_copy(book_uuid, "available"))
+            .await
+            .unwrap();
+        db.create_copy(create_copy(book_uuid, "maintenance"))
+            .await
+            .unwrap();
+
+        let payload_costumer: PayloadCostumer = PayloadCostumer {
+            name: DEFAULT_NAME.to_string(),
+            document: random_document(),
+            born: DEFAULT_DATE.unwrap(),
+        };
+
+        let costumer_uuid: Uuid = db
+            .create_costumer(Costumer::create(payload_costumer).unwrap())
+            .await
+            .unwrap();
+
+        db.checkout_rental(costumer_uuid, loaned_copy_uuid)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let sql_result: BookCopies = db.list_book_copies(book_uuid).await.unwrap();
+
+        assert_eq!(sql_result.book_uuid, book_uuid);
+        assert_eq!(sql_result.total, 3);
+        assert_eq!(sql_result.available, 1);
+        assert!(sql_result
+            .copies
+            .iter()
+            .any(|copy: &BookCopyWithLoan| copy.id == loaned_copy_uuid && copy.on_loan));
+    }
+
+    #[sqlx::test]
+    async fn test_update_copy() {
+        let db: Database = conn_db().await;
+
+        let copy: BookCopy = create_copy(create_book().await, "available");
+
+        let copy_uuid: Uuid = db.create_copy(copy.clone()).await.unwrap();
+
+        let payload_update_copy: PayloadUpdateBookCopy = PayloadUpdateBookCopy {
+            id: copy_uuid,
+            book_uuid: copy.book_uuid,
+            barcode: copy.barcode.as_str(),
+            condition: "worn".to_string(),
+            shelf_location: DEFAULT_SHELF_LOCATION.to_string(),
+            status: "lost".to_string(),
+        };
+
+        db.update_copy(BookCopy::parse(payload_update_copy).unwrap())
+            .await
+            .unwrap();
+
+        let sql_result: BookCopy = db.get_copy(copy_uuid).await.unwrap().unwrap();
+
+        assert_eq!(sql_result.condition, CopyCondition::Worn);
+        assert_eq!(sql_result.status, CopyStatus::Lost);
+    }
+
+    #[sqlx::test]
+    async fn test_delete_copy() {
+        let db: Database = conn_db().await;
+
+        let copy: BookCopy = create_copy(create_book().await, "available");
+
+        db.create_copy(copy.clone()).await.unwrap();
+
+        let sql_result_before: Option<Uuid> = db.get_copy_id(copy.id).await.unwrap();
+
+        let sql_result_uuid: Uuid = db.delete_copy(copy.id).await.unwrap();
+
+        let sql_result_after: Option<Uuid> = db.get_copy_id(sql_result_uuid).await.unwrap();
+
+        assert!(sql_result_before.is_some());
+        assert!(sql_result_after.is_none());
+    }
+
+    #[sqlx::test]
+    async fn test_count_copies() {
+        let db: Database = conn_db().await;
+
+        let sql_result: i64 = db.count_copies().await.unwrap();
+
+        assert!(sql_result >= 0);
+    }
+}
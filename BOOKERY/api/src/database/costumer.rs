@@ -1,21 +1,95 @@
-use sqlx::{postgres::PgRow, Row};
+use sqlx::{postgres::PgRow, Postgres, QueryBuilder, Row, Transaction};
 use time::Date;
 use uuid::Uuid;
 
-use crate::database::{conn::Database, ResultDB};
-use shared::structs::{costumer::Costumer, PersonDocument, PersonName};
+use crate::database::{
+    conn::Database, push_date_range, push_fuzzy_match, push_ilike, set_fuzzy_threshold,
+    OrderColumns, Pagination, ResultDB,
+};
+use crate::policy::BorrowingStanding;
+use shared::structs::{
+    costumer::{Costumer, CostumerBlock, CostumerFilter, CostumerSummary, Document},
+    duplicate::{CostumerMergeSummary, DuplicateGroup, Merge},
+    page::Page,
+    BlockReason, ConversionError, PersonName,
+};
+
+pub const COSTUMER_ORDER_COLUMNS: OrderColumns =
+    &[("name", "name"), ("document", "document"), ("born", "born")];
+
+pub const COSTUMER_FUZZY_ORDER_COLUMNS: OrderColumns = &[
+    ("similarity", "word_similarity(search.token, name)"),
+    ("name", "name"),
+    ("document", "document"),
+    ("born", "born"),
+];
+
+fn push_costumer_filters(
+    query: &mut QueryBuilder<'_, Postgres>,
+    token: &str,
+    filter: &CostumerFilter,
+) {
+    query
+        .push(" WHERE name ILIKE ")
+        .push_bind(format!("%{token}%"));
+    push_costumer_document(query, filter);
+    push_date_range(query, "born", filter.born_from, filter.born_until);
+}
+
+fn push_costumer_fuzzy_filters(
+    query: &mut QueryBuilder<'_, Postgres>,
+    token: &str,
+    filter: &CostumerFilter,
+) {
+    push_fuzzy_match(query, "name", token);
+    push_costumer_document(query, filter);
+    push_date_range(query, "born", filter.born_from, filter.born_until);
+}
+
+fn push_costumer_document(query: &mut QueryBuilder<'_, Postgres>, filter: &CostumerFilter) {
+    let document: Option<String> = filter.document.as_deref().map(Document::normalize);
+
+    push_ilike(query, "document", &document);
+
+    if let Some(document_kind) = filter.document_kind {
+        query
+            .push(" AND document_kind = ")
+            .push_bind(document_kind.as_str());
+    }
+}
+
+fn costumer_from_row(row: PgRow) -> Result<Costumer, sqlx::Error> {
+    let name_parser: String = row.get("name");
+    let document_kind_parser: String = row.get("document_kind");
+    let document_parser: String = row.get("document");
+
+    let id: Uuid = row.get("id");
+    let name: PersonName = PersonName::try_from(name_parser)
+        .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+    let document: Document = Document::parse(document_kind_parser, document_parser)
+        .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+    let born: Date = row.get("born");
+
+    Ok(Costumer {
+        id,
+        name,
+        document,
+        born,
+    })
+}
 
 impl Database {
     pub async fn create_costumer(&self, costumer: Costumer) -> ResultDB<Uuid> {
         let costumer_uuid: Uuid = sqlx::query(
             "
-            INSERT INTO tbl_costumers (id, name, document, born)
-            VALUES ($1, $2, $3, $4)
+            INSERT INTO tbl_costumers (id, name, document_kind, document, born)
+            VALUES ($1, $2, $3, $4, $5)
             RETURNING id
         ",
         )
         .bind(costumer.id)
         .bind(costumer.name.as_str())
+        .bind(costumer.document.kind().as_str())
         .bind(costumer.document.as_str())
         .bind(costumer.born)
         .map(|row: PgRow| {
@@ -31,28 +105,13 @@ impl Database {
     pub async fn get_costumer(&self, costumer_uuid: Uuid) -> ResultDB<Option<Costumer>> {
         let costumer: Option<Costumer> = sqlx::query(
             "
-            SELECT id, name, document, born
+            SELECT id, name, document_kind, document, born
             FROM tbl_costumers
             WHERE id = $1
         ",
         )
         .bind(costumer_uuid)
-        .map(|row: PgRow| {
-            let name_parser: String = row.get("name");
-            let document_parser: String = row.get("document");
-
-            let id: Uuid = row.get("id");
-            let name: PersonName = PersonName::try_from(name_parser).unwrap();
-            let document: PersonDocument = PersonDocument::try_from(document_parser).unwrap();
-            let born: Date = row.get("born");
-
-            Costumer {
-                id,
-                name,
-                document,
-                born,
-            }
-        })
+        .try_map(costumer_from_row)
         .fetch_optional(&self.pool)
         .await?;
 
@@ -78,47 +137,140 @@ impl Database {
         Ok(costumer_uuid)
     }
 
-    pub async fn search_costumers(&self, token: String) -> ResultDB<Vec<Costumer>> {
-        let costumers_vec: Vec<Costumer> = sqlx::query(
+    pub async fn get_costumer_id_by_document(&self, document: &Document) -> ResultDB<Option<Uuid>> {
+        let costumer_uuid: Option<Uuid> = sqlx::query(
             "
-            SELECT id, name, document, born
+            SELECT id
             FROM tbl_costumers
-            WHERE name ILIKE $1
+            WHERE document_kind = $1 AND document = $2
         ",
         )
-        .bind(format!("%{token}%"))
+        .bind(document.kind().as_str())
+        .bind(document.as_str())
         .map(|row: PgRow| {
-            let name_parser: String = row.get("name");
-            let document_parser: String = row.get("document");
-
             let id: Uuid = row.get("id");
-            let name: PersonName = PersonName::try_from(name_parser).unwrap();
-            let document: PersonDocument = PersonDocument::try_from(document_parser).unwrap();
+            id
+        })
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(costumer_uuid)
+    }
+
+    pub async fn list_costumer_duplicates(&self) -> ResultDB<Vec<DuplicateGroup<Costumer>>> {
+        let rows: Vec<(String, Date, Costumer)> = sqlx::query(
+            "
+            SELECT id, name, document_kind, document, born, person_name_key(name) as name_key
+            FROM tbl_costumers
+            WHERE (person_name_key(name), born) IN (
+                SELECT person_name_key(name), born
+                FROM tbl_costumers
+                GROUP BY person_name_key(name), born
+                HAVING count(*) > 1
+            )
+            ORDER BY name_key, born, id
+        ",
+        )
+        .try_map(|row: PgRow| {
+            let name_key: String = row.get("name_key");
             let born: Date = row.get("born");
 
-            Costumer {
-                id,
-                name,
-                document,
-                born,
-            }
+            Ok((name_key, born, costumer_from_row(row)?))
         })
         .fetch_all(&self.pool)
         .await?;
 
-        Ok(costumers_vec)
+        Ok(DuplicateGroup::group(rows))
+    }
+
+    pub async fn search_costumers(
+        &self,
+        token: String,
+        filter: CostumerFilter,
+        pagination: Pagination,
+    ) -> ResultDB<Page<Costumer>> {
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "
+            SELECT id, name, document_kind, document, born
+            FROM tbl_costumers
+        ",
+        );
+        push_costumer_filters(&mut query, &token, &filter);
+        pagination.push_order(&mut query);
+
+        let costumers_vec: Vec<Costumer> = query
+            .build()
+            .try_map(costumer_from_row)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut count_query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "
+            SELECT count(*) as total
+            FROM tbl_costumers
+        ",
+        );
+        push_costumer_filters(&mut count_query, &token, &filter);
+
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(pagination.page(costumers_vec, total))
+    }
+
+    pub async fn search_costumers_fuzzy(
+        &self,
+        token: String,
+        threshold: f32,
+        filter: CostumerFilter,
+        pagination: Pagination,
+    ) -> ResultDB<Page<Costumer>> {
+        let mut tx: Transaction<'_, Postgres> = self.pool.begin().await?;
+        set_fuzzy_threshold(&mut tx, threshold).await?;
+
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "
+            SELECT id, name, document_kind, document, born
+            FROM tbl_costumers
+        ",
+        );
+        push_costumer_fuzzy_filters(&mut query, &token, &filter);
+        pagination.push_order(&mut query);
+
+        let costumers_vec: Vec<Costumer> = query
+            .build()
+            .try_map(costumer_from_row)
+            .fetch_all(&mut *tx)
+            .await?;
+
+        let mut count_query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "
+            SELECT count(*) as total
+            FROM tbl_costumers
+        ",
+        );
+        push_costumer_fuzzy_filters(&mut count_query, &token, &filter);
+
+        let total: i64 = count_query.build_query_scalar().fetch_one(&mut *tx).await?;
+
+        tx.commit().await?;
+
+        Ok(pagination.page(costumers_vec, total))
     }
 
     pub async fn update_costumer(&self, costumer: Costumer) -> ResultDB<Uuid> {
         let costumer_uuid: Uuid = sqlx::query(
             "
             UPDATE tbl_costumers
-            SET name = $1, document = $2, born = $3
-            WHERE id = $4
+            SET name = $1, document_kind = $2, document = $3, born = $4
+            WHERE id = $5
             RETURNING id
         ",
         )
         .bind(costumer.name.as_str())
+        .bind(costumer.document.kind().as_str())
         .bind(costumer.document.as_str())
         .bind(costumer.born)
         .bind(costumer.id)
@@ -132,6 +284,100 @@ impl Database {
         Ok(costumer_uuid)
     }
 
+    pub async fn merge_costumers(&self, merge: Merge) -> ResultDB<CostumerMergeSummary> {
+        let mut tx: Transaction<'_, Postgres> = self.pool.begin().await?;
+
+        sqlx::query(
+            "
+            UPDATE tbl_reservations
+            SET status = 'cancelled'
+            WHERE costumer_uuid = $2 AND status IN ('waiting', 'ready')
+            AND book_uuid IN (
+                SELECT book_uuid
+                FROM tbl_reservations
+                WHERE costumer_uuid = $1 AND status IN ('waiting', 'ready')
+            )
+        ",
+        )
+        .bind(merge.survivor_uuid)
+        .bind(merge.loser_uuid)
+        .execute(&mut *tx)
+        .await?;
+
+        let rentals: u64 = sqlx::query(
+            "
+            UPDATE tbl_rentals
+            SET costumer_uuid = $1
+            WHERE costumer_uuid = $2
+        ",
+        )
+        .bind(merge.survivor_uuid)
+        .bind(merge.loser_uuid)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        let fees: u64 = sqlx::query(
+            "
+            UPDATE tbl_fees
+            SET costumer_uuid = $1
+            WHERE costumer_uuid = $2
+        ",
+        )
+        .bind(merge.survivor_uuid)
+        .bind(merge.loser_uuid)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        let reservations: u64 = sqlx::query(
+            "
+            UPDATE tbl_reservations
+            SET costumer_uuid = $1
+            WHERE costumer_uuid = $2
+        ",
+        )
+        .bind(merge.survivor_uuid)
+        .bind(merge.loser_uuid)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        sqlx::query(
+            "
+            UPDATE tbl_costumers s
+            SET blocked_reason = l.blocked_reason, blocked_until = l.blocked_until
+            FROM tbl_costumers l
+            WHERE s.id = $1 AND l.id = $2
+            AND s.blocked_reason IS NULL AND l.blocked_reason IS NOT NULL
+        ",
+        )
+        .bind(merge.survivor_uuid)
+        .bind(merge.loser_uuid)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "
+            DELETE FROM tbl_costumers
+            WHERE id = $1
+        ",
+        )
+        .bind(merge.loser_uuid)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(CostumerMergeSummary {
+            survivor_uuid: merge.survivor_uuid,
+            loser_uuid: merge.loser_uuid,
+            rentals: rentals as i64,
+            fees: fees as i64,
+            reservations: reservations as i64,
+        })
+    }
+
     pub async fn delete_costumer(&self, costumer_uuid: Uuid) -> ResultDB<Uuid> {
         let costumer_uuid: Uuid = sqlx::query(
             "
@@ -151,6 +397,177 @@ impl Database {
         Ok(costumer_uuid)
     }
 
+    pub async fn block_costumer(&self, block: CostumerBlock) -> ResultDB<Uuid> {
+        let costumer_uuid: Uuid = sqlx::query(
+            "
+            UPDATE tbl_costumers
+            SET blocked_reason = $1, blocked_until = $2
+            WHERE id = $3
+            RETURNING id
+        ",
+        )
+        .bind(block.reason.as_str())
+        .bind(block.until)
+        .bind(block.id)
+        .map(|row: PgRow| {
+            let uuid: Uuid = row.get("id");
+            uuid
+        })
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(costumer_uuid)
+    }
+
+    pub async fn unblock_costumer(&self, costumer_uuid: Uuid) -> ResultDB<Uuid> {
+        let costumer_uuid: Uuid = sqlx::query(
+            "
+            UPDATE tbl_costumers
+            SET blocked_reason = NULL, blocked_until = NULL
+            WHERE id = $1
+            RETURNING id
+        ",
+        )
+        .bind(costumer_uuid)
+        .map(|row: PgRow| {
+            let uuid: Uuid = row.get("id");
+            uuid
+        })
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(costumer_uuid)
+    }
+
+    pub async fn get_costumer_block(&self, costumer_uuid: Uuid) -> ResultDB<Option<CostumerBlock>> {
+        let block: Option<CostumerBlock> = sqlx::query(
+            "
+            SELECT id, blocked_reason, blocked_until
+            FROM tbl_costumers
+            WHERE id = $1 AND blocked_reason IS NOT NULL
+            AND (blocked_until IS NULL OR blocked_until >= CURRENT_DATE)
+        ",
+        )
+        .bind(costumer_uuid)
+        .try_map(|row: PgRow| {
+            let reason_parser: String = row.get("blocked_reason");
+
+            let id: Uuid = row.get("id");
+            let reason: BlockReason = BlockReason::try_from(reason_parser)
+                .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+            let until: Option<Date> = row.get("blocked_until");
+
+            Ok(CostumerBlock { id, reason, until })
+        })
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(block)
+    }
+
+    pub async fn borrowing_standing(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        costumer_uuid: Uuid,
+    ) -> ResultDB<Option<BorrowingStanding>> {
+        sqlx::query(
+            "
+            SELECT id
+            FROM tbl_costumers
+            WHERE id = $1
+            FOR UPDATE
+        ",
+        )
+        .bind(costumer_uuid)
+        .execute(&mut **tx)
+        .await?;
+
+        let standing: Option<BorrowingStanding> = sqlx::query(
+            "
+            SELECT
+            (c.blocked_reason IS NOT NULL AND (c.blocked_until IS NULL OR c.blocked_until >= CURRENT_DATE)) as blocked,
+            (
+                SELECT count(*)
+                FROM tbl_rentals r
+                WHERE r.costumer_uuid = c.id AND r.returned_at IS NULL
+            ) as open_rentals,
+            (
+                SELECT COALESCE(sum(f.amount), 0)::bigint
+                FROM tbl_fees f
+                WHERE f.costumer_uuid = c.id AND f.paid_at IS NULL
+            ) as outstanding_fees
+            FROM tbl_costumers c
+            WHERE c.id = $1
+        ",
+        )
+        .bind(costumer_uuid)
+        .map(|row: PgRow| {
+            let blocked: bool = row.get("blocked");
+            let open_rentals: i64 = row.get("open_rentals");
+            let outstanding_fees: i64 = row.get("outstanding_fees");
+
+            BorrowingStanding {
+                blocked,
+                open_rentals,
+                outstanding_fees,
+            }
+        })
+        .fetch_optional(&mut **tx)
+        .await?;
+
+        Ok(standing)
+    }
+
+    pub async fn costumer_summary(&self, costumer_uuid: Uuid) -> ResultDB<Option<CostumerSummary>> {
+        let summary: Option<CostumerSummary> = sqlx::query(
+            "
+            SELECT c.id,
+            (
+                SELECT count(*)
+                FROM tbl_rentals r
+                WHERE r.costumer_uuid = c.id
+            ) as total_borrowed,
+            (
+                SELECT count(*)
+                FROM tbl_rentals r
+                WHERE r.costumer_uuid = c.id AND r.returned_at IS NULL
+            ) as current_loans,
+            (
+                SELECT count(*)
+                FROM tbl_rentals r
+                WHERE r.costumer_uuid = c.id AND r.returned_at IS NULL AND r.due_date < CURRENT_DATE
+            ) as overdue_count,
+            (
+                SELECT COALESCE(sum(f.amount), 0)::bigint
+                FROM tbl_fees f
+                WHERE f.costumer_uuid = c.id AND f.paid_at IS NULL
+            ) as fees_owed
+            FROM tbl_costumers c
+            WHERE c.id = $1
+        ",
+        )
+        .bind(costumer_uuid)
+        .map(|row: PgRow| {
+            let id: Uuid = row.get("id");
+            let total_borrowed: i64 = row.get("total_borrowed");
+            let current_loans: i64 = row.get("current_loans");
+            let overdue_count: i64 = row.get("overdue_count");
+            let fees_owed: i64 = row.get("fees_owed");
+
+            CostumerSummary {
+                id,
+                total_borrowed,
+                current_loans,
+                overdue_count,
+                fees_owed,
+            }
+        })
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(summary)
+    }
+
     pub async fn count_costumers(&self) -> ResultDB<i64> {
         let total: i64 = sqlx::query_scalar(
             "
@@ -171,16 +588,43 @@ mod tests {
 
     use std::env::var;
 
-    use time::{error::ComponentRange, Date, Month};
+    use time::{error::ComponentRange, Date, Duration, Month};
 
     use crate::handlers::QueryURL;
-    use shared::structs::costumer::{PayloadCostumer, PayloadUpdateCostumer};
-
-    const DEFAULT_NAME: &'static str = "Name";
-    const DEFAULT_DOCUMENT: &'static str = "12345678901";
+    use shared::structs::{
+        author::{Author, PayloadAuthor},
+        book::{Book, PayloadBook, PayloadContributor},
+        copy::{BookCopy, PayloadBookCopy},
+        costumer::{
+            DocumentKind, PayloadBlockCostumer, PayloadCostumer, PayloadDocument,
+            PayloadUpdateCostumer,
+        },
+        publisher::{PayloadPublisher, Publisher},
+        rental::{PayloadRental, Rental},
+        PersonDocument,
+    };
+
+    const DEFAULT_NAME: &str = "Name";
+    const DEFAULT_EDITOR: &str = "Editor";
+    const DEFAULT_SHELF_LOCATION: &str = "A-01";
     const DEFAULT_BORN: Result<Date, ComponentRange> =
         Date::from_calendar_date(2000, Month::January, 1);
 
+    const DEFAULT_RELEASE: Result<Date, ComponentRange> =
+        Date::from_calendar_date(2000, Month::January, 1);
+
+    const DEFAULT_BORROWED_DATE: Result<Date, ComponentRange> =
+        Date::from_calendar_date(2000, Month::January, 1);
+    const DEFAULT_DUE_DATE: Result<Date, ComponentRange> =
+        Date::from_calendar_date(2000, Month::January, 1);
+
+    fn random_document() -> PayloadDocument {
+        PayloadDocument {
+            kind: "rg".to_string(),
+            value: format!("{:014}", Uuid::new_v4().as_u128() % 10u128.pow(14)),
+        }
+    }
+
     async fn conn_db() -> Database {
         let db_url: String = var("DATABASE_URL").unwrap();
         Database::conn(&db_url).await
@@ -189,20 +633,88 @@ mod tests {
     fn create_costumer() -> Costumer {
         let payload_costumer: PayloadCostumer = PayloadCostumer {
             name: DEFAULT_NAME.to_string(),
-            document: DEFAULT_DOCUMENT.to_string(),
+            document: random_document(),
             born: DEFAULT_BORN.unwrap(),
         };
 
         Costumer::create(payload_costumer).unwrap()
     }
 
+    async fn create_copy(db: &Database) -> Uuid {
+        let payload_author: PayloadAuthor = PayloadAuthor {
+            name: DEFAULT_NAME.to_string(),
+            born: DEFAULT_BORN.unwrap(),
+        };
+
+        let author_uuid: Uuid = db
+            .create_author(Author::create(payload_author).unwrap())
+            .await
+            .unwrap();
+
+        let payload_publisher: PayloadPublisher = PayloadPublisher {
+            name: format!("{DEFAULT_EDITOR} {}", Uuid::new_v4().simple()),
+        };
+
+        let publisher_uuid: Uuid = db
+            .create_publisher(Publisher::create(payload_publisher).unwrap())
+            .await
+            .unwrap();
+
+        let payload_book: PayloadBook = PayloadBook {
+            name: DEFAULT_NAME.to_string(),
+            isbn: None,
+            publisher_uuid,
+            contributors: vec![PayloadContributor {
+                author_uuid,
+                role: "author".to_string(),
+            }],
+            subjects: vec![],
+            release: DEFAULT_RELEASE.unwrap(),
+        };
+
+        let book_uuid: Uuid = db
+            .create_book(Book::create(payload_book).unwrap())
+            .await
+            .unwrap();
+
+        let payload_copy: PayloadBookCopy = PayloadBookCopy {
+            book_uuid,
+            barcode: Uuid::new_v4().simple().to_string(),
+            condition: "good".to_string(),
+            shelf_location: DEFAULT_SHELF_LOCATION.to_string(),
+            status: "available".to_string(),
+        };
+
+        db.create_copy(BookCopy::create(payload_copy).unwrap())
+            .await
+            .unwrap()
+    }
+
+    async fn create_rental(db: &Database, costumer_uuid: Uuid) -> Rental {
+        let payload_rental: PayloadRental = PayloadRental {
+            copy_uuid: create_copy(db).await,
+            costumer_uuid,
+            borrowed_at: DEFAULT_BORROWED_DATE.unwrap(),
+            due_date: DEFAULT_DUE_DATE.unwrap(),
+        };
+
+        Rental::create(payload_rental).unwrap()
+    }
+
+    fn random_letters() -> String {
+        Uuid::new_v4().simple().to_string()[..12]
+            .chars()
+            .map(|c: char| (b'a' + c.to_digit(16).unwrap() as u8) as char)
+            .collect()
+    }
+
     #[sqlx::test]
     async fn test_create_costumer() {
         let db: Database = conn_db().await;
 
         let costumer: Costumer = create_costumer();
 
-        let costumer_uuid: Uuid = costumer.id.clone();
+        let costumer_uuid: Uuid = costumer.id;
 
         let sql_result: Uuid = db.create_costumer(costumer).await.unwrap();
 
@@ -217,22 +729,123 @@ mod tests {
 
         let costumer_uuid: Uuid = db.create_costumer(costumer.clone()).await.unwrap();
 
-        let sql_result: Costumer = db
-            .get_costumer(costumer_uuid.clone())
-            .await
-            .unwrap()
-            .unwrap();
+        let sql_result: Costumer = db.get_costumer(costumer_uuid).await.unwrap().unwrap();
 
         assert_eq!(sql_result, costumer);
     }
 
+    #[sqlx::test]
+    async fn test_get_costumer_company_document() {
+        let db: Database = conn_db().await;
+
+        let mut digits: Vec<u32> = format!("{:012}", Uuid::new_v4().as_u128() % 10u128.pow(12))
+            .chars()
+            .map(|c: char| c.to_digit(10).unwrap())
+            .collect();
+        for _ in 0..2 {
+            let sum: u32 = digits
+                .iter()
+                .rev()
+                .enumerate()
+                .map(|(i, digit): (usize, &u32)| digit * (i as u32 % 8 + 2))
+                .sum();
+            digits.push(match sum % 11 {
+                0 | 1 => 0,
+                rest => 11 - rest,
+            });
+        }
+        let cnpj: String = digits.iter().map(|digit: &u32| digit.to_string()).collect();
+
+        let payload_costumer: PayloadCostumer = PayloadCostumer {
+            name: DEFAULT_NAME.to_string(),
+            document: PayloadDocument {
+                kind: "cnpj".to_string(),
+                value: format!(
+                    "{}.{}.{}/{}-{}",
+                    &cnpj[..2],
+                    &cnpj[2..5],
+                    &cnpj[5..8],
+                    &cnpj[8..12],
+                    &cnpj[12..]
+                ),
+            },
+            born: DEFAULT_BORN.unwrap(),
+        };
+        let costumer: Costumer = Costumer::create(payload_costumer).unwrap();
+
+        let costumer_uuid: Uuid = db.create_costumer(costumer.clone()).await.unwrap();
+
+        let sql_result: Costumer = db.get_costumer(costumer_uuid).await.unwrap().unwrap();
+
+        assert_eq!(sql_result, costumer);
+        assert_eq!(sql_result.document.kind(), DocumentKind::Cnpj);
+        assert_eq!(sql_result.document.as_str(), cnpj);
+    }
+
+    #[sqlx::test]
+    async fn test_get_costumer_legacy_document() {
+        let db: Database = conn_db().await;
+
+        let costumer_uuid: Uuid = Uuid::new_v4();
+        let document: String = format!("{:011}", Uuid::new_v4().as_u128() % 10u128.pow(11));
+
+        sqlx::query(
+            "
+            INSERT INTO tbl_costumers (id, name, document_kind, document, born)
+            VALUES ($1, $2, 'legacy', $3, $4)
+        ",
+        )
+        .bind(costumer_uuid)
+        .bind(DEFAULT_NAME)
+        .bind(&document)
+        .bind(DEFAULT_BORN.unwrap())
+        .execute(&db.pool)
+        .await
+        .unwrap();
+
+        let sql_result: Costumer = db.get_costumer(costumer_uuid).await.unwrap().unwrap();
+
+        assert_eq!(sql_result.document.kind(), DocumentKind::Legacy);
+        assert_eq!(sql_result.document.as_str(), document);
+    }
+
+    #[sqlx::test]
+    async fn test_get_costumer_invalid_document() {
+        let db: Database = conn_db().await;
+
+        let costumer_uuid: Uuid = Uuid::new_v4();
+
+        sqlx::query(
+            "
+            INSERT INTO tbl_costumers (id, name, document_kind, document, born)
+            VALUES ($1, $2, 'cpf', $3, $4)
+        ",
+        )
+        .bind(costumer_uuid)
+        .bind(random_letters())
+        .bind(format!(
+            "x{:010}",
+            Uuid::new_v4().as_u128() % 10u128.pow(10)
+        ))
+        .bind(Date::from_calendar_date(1900, Month::January, 1).unwrap())
+        .execute(&db.pool)
+        .await
+        .unwrap();
+
+        let sql_result: ResultDB<Option<Costumer>> = db.get_costumer(costumer_uuid).await;
+
+        db.delete_costumer(costumer_uuid).await.unwrap();
+
+        assert!(matches!(sql_result, Err(sqlx::Error::Decode(_))));
+    }
+
     #[sqlx::test]
     async fn test_get_costumer_not_found() {
         let db: Database = conn_db().await;
 
         let costumer: Costumer = create_costumer();
 
-        let sql_result: Option<Costumer> = db.get_costumer(costumer.id.clone()).await.unwrap();
+        let sql_result: Option<Costumer> = db.get_costumer(costumer.id).await.unwrap();
 
         assert!(sql_result.is_none());
     }
@@ -245,11 +858,7 @@ mod tests {
 
         let costumer_uuid: Uuid = db.create_costumer(costumer.clone()).await.unwrap();
 
-        let sql_result: Uuid = db
-            .get_costumer_id(costumer_uuid.clone())
-            .await
-            .unwrap()
-            .unwrap();
+        let sql_result: Uuid = db.get_costumer_id(costumer_uuid).await.unwrap().unwrap();
 
         assert_eq!(sql_result, costumer_uuid);
     }
@@ -260,11 +869,136 @@ mod tests {
 
         let costumer: Costumer = create_costumer();
 
-        let sql_result: Option<Uuid> = db.get_costumer_id(costumer.id.clone()).await.unwrap();
+        let sql_result: Option<Uuid> = db.get_costumer_id(costumer.id).await.unwrap();
 
         assert!(sql_result.is_none());
     }
 
+    #[sqlx::test]
+    async fn test_create_costumer_duplicated_document() {
+        let db: Database = conn_db().await;
+
+        let costumer: Costumer = create_costumer();
+        db.create_costumer(costumer.clone()).await.unwrap();
+
+        let mut duplicate: Costumer = create_costumer();
+        duplicate.document = costumer.document.clone();
+
+        let sql_result: sqlx::Error = db.create_costumer(duplicate).await.unwrap_err();
+
+        assert_eq!(
+            sql_result.as_database_error().unwrap().constraint(),
+            Some("idx_costumers_document")
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_get_costumer_id_by_document() {
+        let db: Database = conn_db().await;
+
+        let costumer: Costumer = create_costumer();
+        db.create_costumer(costumer.clone()).await.unwrap();
+
+        let sql_result: Option<Uuid> = db
+            .get_costumer_id_by_document(&costumer.document)
+            .await
+            .unwrap();
+
+        assert_eq!(sql_result, Some(costumer.id));
+        assert!(db
+            .get_costumer_id_by_document(&create_costumer().document)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[sqlx::test]
+    async fn test_list_costumer_duplicates() {
+        let db: Database = conn_db().await;
+
+        let letters: String = random_letters();
+
+        let mut costumer: Costumer = create_costumer();
+        costumer.name = PersonName::try_from(format!("Zoë {letters}")).unwrap();
+        db.create_costumer(costumer.clone()).await.unwrap();
+
+        let mut duplicate: Costumer = create_costumer();
+        duplicate.name = PersonName::try_from(format!("ZOE-{letters}")).unwrap();
+        db.create_costumer(duplicate.clone()).await.unwrap();
+
+        let sql_result: Vec<DuplicateGroup<Costumer>> =
+            db.list_costumer_duplicates().await.unwrap();
+
+        let group: &DuplicateGroup<Costumer> = sql_result
+            .iter()
+            .find(|group: &&DuplicateGroup<Costumer>| group.key == format!("zoe{letters}"))
+            .unwrap();
+
+        assert_eq!(group.items.len(), 2);
+        assert!(group.items.contains(&costumer));
+        assert!(group.items.contains(&duplicate));
+    }
+
+    #[sqlx::test]
+    async fn test_merge_costumers_repoints_rentals() {
+        let db: Database = conn_db().await;
+
+        let loser_uuid: Uuid = db.create_costumer(create_costumer()).await.unwrap();
+
+        let returned_rental: Rental = create_rental(&db, loser_uuid).await;
+        db.create_rental(returned_rental.clone())
+            .await
+            .unwrap()
+            .unwrap();
+
+        let open_rental: Rental = create_rental(&db, loser_uuid).await;
+        db.create_rental(open_rental.clone())
+            .await
+            .unwrap()
+            .unwrap();
+
+        db.return_rental(returned_rental.id).await.unwrap();
+
+        let block: CostumerBlock = CostumerBlock::create(PayloadBlockCostumer {
+            id: loser_uuid,
+            reason: "Damaged a book".to_string(),
+            until: None,
+        })
+        .unwrap();
+        db.block_costumer(block).await.unwrap();
+
+        let survivor_uuid: Uuid = db.create_costumer(create_costumer()).await.unwrap();
+
+        let sql_result: CostumerMergeSummary = db
+            .merge_costumers(Merge {
+                survivor_uuid,
+                loser_uuid,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            sql_result,
+            CostumerMergeSummary {
+                survivor_uuid,
+                loser_uuid,
+                rentals: 2,
+                fees: 1,
+                reservations: 0,
+            }
+        );
+        for rental_uuid in [returned_rental.id, open_rental.id] {
+            let rental: Rental = db.get_rental_raw(rental_uuid).await.unwrap().unwrap();
+            assert_eq!(rental.costumer_uuid, survivor_uuid);
+        }
+        assert!(db.get_costumer_id(loser_uuid).await.unwrap().is_none());
+        assert!(db
+            .get_costumer_block(survivor_uuid)
+            .await
+            .unwrap()
+            .is_some());
+    }
+
     #[sqlx::test]
     async fn test_search_costumers_case_sensitive_found() {
         let db: Database = conn_db().await;
@@ -273,11 +1007,20 @@ mod tests {
 
         let token: QueryURL = QueryURL {
             token: "Nam".to_string(),
+            ..Default::default()
         };
 
         db.create_costumer(costumer.clone()).await.unwrap();
 
-        let sql_result: Vec<Costumer> = db.search_costumers(token.token).await.unwrap();
+        let sql_result: Vec<Costumer> = db
+            .search_costumers(
+                token.token,
+                Default::default(),
+                Pagination::new(COSTUMER_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
 
         assert!(sql_result.contains(&costumer));
     }
@@ -290,11 +1033,20 @@ mod tests {
 
         let token: QueryURL = QueryURL {
             token: "nam".to_string(),
+            ..Default::default()
         };
 
         db.create_costumer(costumer.clone()).await.unwrap();
 
-        let sql_result: Vec<Costumer> = db.search_costumers(token.token).await.unwrap();
+        let sql_result: Vec<Costumer> = db
+            .search_costumers(
+                token.token,
+                Default::default(),
+                Pagination::new(COSTUMER_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
 
         assert!(sql_result.contains(&costumer));
     }
@@ -307,11 +1059,20 @@ mod tests {
 
         let token: QueryURL = QueryURL {
             token: "foo".to_string(),
+            ..Default::default()
         };
 
         db.create_costumer(costumer.clone()).await.unwrap();
 
-        let sql_result: Vec<Costumer> = db.search_costumers(token.token).await.unwrap();
+        let sql_result: Vec<Costumer> = db
+            .search_costumers(
+                token.token,
+                Default::default(),
+                Pagination::new(COSTUMER_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
 
         assert!(!sql_result.contains(&costumer));
     }
@@ -325,9 +1086,12 @@ mod tests {
         let sql_costumer_uuid: Uuid = db.create_costumer(costumer.clone()).await.unwrap();
 
         let payload_update_costumer: PayloadUpdateCostumer = PayloadUpdateCostumer {
-            id: sql_costumer_uuid.clone(),
+            id: sql_costumer_uuid,
             name: DEFAULT_NAME.to_string(),
-            document: DEFAULT_DOCUMENT.to_string(),
+            document: PayloadDocument {
+                kind: costumer.document.kind().as_str().to_string(),
+                value: costumer.document.as_str(),
+            },
             born: DEFAULT_BORN.unwrap(),
         };
 
@@ -348,8 +1112,7 @@ mod tests {
 
         db.create_costumer(costumer.clone()).await.unwrap();
 
-        let sql_result_before: Option<Uuid> =
-            db.get_costumer_id(costumer.id.clone()).await.unwrap();
+        let sql_result_before: Option<Uuid> = db.get_costumer_id(costumer.id).await.unwrap();
 
         let sql_result_uuid: Uuid = db.delete_costumer(costumer.id).await.unwrap();
 
@@ -367,4 +1130,158 @@ mod tests {
 
         assert!(sql_result >= 0);
     }
+
+    #[sqlx::test]
+    async fn test_block_costumer() {
+        let db: Database = conn_db().await;
+
+        let costumer_uuid: Uuid = db.create_costumer(create_costumer()).await.unwrap();
+
+        let block: CostumerBlock = CostumerBlock::create(PayloadBlockCostumer {
+            id: costumer_uuid,
+            reason: "Lost a book".to_string(),
+            until: None,
+        })
+        .unwrap();
+
+        db.block_costumer(block.clone()).await.unwrap();
+
+        assert_eq!(
+            db.get_costumer_block(costumer_uuid).await.unwrap(),
+            Some(block)
+        );
+        let mut tx: Transaction<'_, Postgres> = db.pool.begin().await.unwrap();
+        assert!(
+            db.borrowing_standing(&mut tx, costumer_uuid)
+                .await
+                .unwrap()
+                .unwrap()
+                .blocked
+        );
+        tx.rollback().await.unwrap();
+
+        db.unblock_costumer(costumer_uuid).await.unwrap();
+
+        assert_eq!(db.get_costumer_block(costumer_uuid).await.unwrap(), None);
+    }
+
+    #[sqlx::test]
+    async fn test_get_costumer_block_invalid_reason() {
+        let db: Database = conn_db().await;
+
+        let costumer_uuid: Uuid = db.create_costumer(create_costumer()).await.unwrap();
+
+        sqlx::query(
+            "
+            UPDATE tbl_costumers
+            SET blocked_reason = '   '
+            WHERE id = $1
+        ",
+        )
+        .bind(costumer_uuid)
+        .execute(&db.pool)
+        .await
+        .unwrap();
+
+        let sql_result: ResultDB<Option<CostumerBlock>> =
+            db.get_costumer_block(costumer_uuid).await;
+
+        assert!(matches!(sql_result, Err(sqlx::Error::Decode(_))));
+    }
+
+    #[sqlx::test]
+    async fn test_block_costumer_expired() {
+        let db: Database = conn_db().await;
+
+        let costumer_uuid: Uuid = db.create_costumer(create_costumer()).await.unwrap();
+
+        let block: CostumerBlock = CostumerBlock::create(PayloadBlockCostumer {
+            id: costumer_uuid,
+            reason: "Late returns".to_string(),
+            until: Some(DEFAULT_BORN.unwrap() + Duration::days(1)),
+        })
+        .unwrap();
+
+        db.block_costumer(block).await.unwrap();
+
+        assert_eq!(db.get_costumer_block(costumer_uuid).await.unwrap(), None);
+        let mut tx: Transaction<'_, Postgres> = db.pool.begin().await.unwrap();
+        assert!(
+            !db.borrowing_standing(&mut tx, costumer_uuid)
+                .await
+                .unwrap()
+                .unwrap()
+                .blocked
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_borrowing_standing() {
+        let db: Database = conn_db().await;
+
+        let costumer_uuid: Uuid = db.create_costumer(create_costumer()).await.unwrap();
+
+        let mut tx: Transaction<'_, Postgres> = db.pool.begin().await.unwrap();
+
+        assert_eq!(
+            db.borrowing_standing(&mut tx, costumer_uuid).await.unwrap(),
+            Some(BorrowingStanding {
+                blocked: false,
+                open_rentals: 0,
+                outstanding_fees: 0,
+            })
+        );
+        assert_eq!(
+            db.borrowing_standing(&mut tx, Uuid::new_v4())
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_costumer_summary() {
+        let db: Database = conn_db().await;
+
+        let costumer_uuid: Uuid = db.create_costumer(create_costumer()).await.unwrap();
+
+        assert_eq!(
+            db.costumer_summary(costumer_uuid).await.unwrap(),
+            Some(CostumerSummary {
+                id: costumer_uuid,
+                total_borrowed: 0,
+                current_loans: 0,
+                overdue_count: 0,
+                fees_owed: 0,
+            })
+        );
+        assert_eq!(db.costumer_summary(Uuid::new_v4()).await.unwrap(), None);
+    }
+
+    #[sqlx::test]
+    async fn test_cpf_is_valid_matches_person_document() {
+        let db: Database = conn_db().await;
+
+        for document in [
+            "12345678909",
+            "529.982.247-25",
+            "12345678901",
+            "52998224724",
+            "00000000000",
+            "1234567890",
+            "1234567890a",
+        ] {
+            let sql_result: bool = sqlx::query_scalar("SELECT cpf_is_valid($1)")
+                .bind(document)
+                .fetch_one(&db.pool)
+                .await
+                .unwrap();
+
+            assert_eq!(
+                sql_result,
+                PersonDocument::try_from(document.to_string()).is_ok(),
+                "{document}"
+            );
+        }
+    }
 }
@@ -1,9 +1,113 @@
-use sqlx::error::Error;
+use serde::Deserialize;
+use sqlx::{error::Error, Postgres, QueryBuilder, Transaction};
+use time::Date;
+
+use shared::structs::page::Page;
 
 type ResultDB<T> = Result<T, Error>;
 
+pub type OrderColumns = &'static [(&'static str, &'static str)];
+
+#[derive(Deserialize, Debug, PartialEq, Clone, Copy, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    #[default]
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Pagination {
+    pub limit: Option<i64>,
+    pub offset: i64,
+    pub order_by: &'static str,
+    pub direction: Direction,
+}
+
+impl Pagination {
+    pub fn new(order_columns: OrderColumns) -> Self {
+        Self {
+            limit: None,
+            offset: 0,
+            order_by: order_columns[0].1,
+            direction: Direction::Asc,
+        }
+    }
+
+    fn push_order(&self, query: &mut QueryBuilder<'_, Postgres>) {
+        let direction: &str = match self.direction {
+            Direction::Asc => "ASC",
+            Direction::Desc => "DESC",
+        };
+
+        query
+            .push(format!(
+                " ORDER BY {} {direction}, id {direction}",
+                self.order_by
+            ))
+            .push(" LIMIT ")
+            .push_bind(self.limit)
+            .push(" OFFSET ")
+            .push_bind(self.offset);
+    }
+
+    fn page<T>(&self, items: Vec<T>, total: i64) -> Page<T> {
+        Page {
+            items,
+            total,
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+fn push_ilike(query: &mut QueryBuilder<'_, Postgres>, column: &str, token: &Option<String>) {
+    if let Some(token) = token {
+        query
+            .push(format!(" AND {column} ILIKE "))
+            .push_bind(format!("%{token}%"));
+    }
+}
+
+fn push_date_range(
+    query: &mut QueryBuilder<'_, Postgres>,
+    column: &str,
+    from: Option<Date>,
+    until: Option<Date>,
+) {
+    if let Some(from) = from {
+        query.push(format!(" AND {column} >= ")).push_bind(from);
+    }
+
+    if let Some(until) = until {
+        query.push(format!(" AND {column} <= ")).push_bind(until);
+    }
+}
+
+fn push_fuzzy_match(query: &mut QueryBuilder<'_, Postgres>, column: &str, token: &str) {
+    query
+        .push(" CROSS JOIN (SELECT ")
+        .push_bind(token.to_string())
+        .push(format!(
+            "::TEXT as token) search WHERE search.token <% {column}"
+        ));
+}
+
+async fn set_fuzzy_threshold(tx: &mut Transaction<'_, Postgres>, threshold: f32) -> ResultDB<()> {
+    sqlx::query("SELECT set_config('pg_trgm.word_similarity_threshold', $1, true)")
+        .bind(threshold.to_string())
+        .execute(&mut **tx)
+        .await?;
+
+    Ok(())
+}
+
 pub mod author;
 pub mod book;
 pub mod conn;
+pub mod copy;
 pub mod costumer;
+pub mod publisher;
 pub mod rental;
+pub mod reservation;
+pub mod subject;
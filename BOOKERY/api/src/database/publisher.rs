@@ -0,0 +1,356 @@
+use sqlx::{postgres::PgRow, Postgres, QueryBuilder, Row};
+use uuid::Uuid;
+
+use crate::database::{conn::Database, OrderColumns, Pagination, ResultDB};
+use shared::structs::{page::Page, publisher::Publisher, EditorName};
+
+pub const PUBLISHER_ORDER_COLUMNS: OrderColumns = &[("name", "name")];
+
+fn push_publisher_filters(query: &mut QueryBuilder<'_, Postgres>, token: &str) {
+    query
+        .push(" WHERE name ILIKE ")
+        .push_bind(format!("%{token}%"));
+}
+
+impl Database {
+    pub async fn create_publisher(&self, publisher: Publisher) -> ResultDB<Uuid> {
+        let publisher_uuid: Uuid = sqlx::query(
+            "
+            INSERT INTO tbl_publishers (id, name)
+            VALUES ($1, $2)
+            RETURNING id
+        ",
+        )
+        .bind(publisher.id)
+        .bind(publisher.name.as_str())
+        .map(|row: PgRow| {
+            let uuid: Uuid = row.get("id");
+            uuid
+        })
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(publisher_uuid)
+    }
+
+    pub async fn get_publisher(&self, publisher_uuid: Uuid) -> ResultDB<Option<Publisher>> {
+        let publisher: Option<Publisher> = sqlx::query(
+            "
+            SELECT id, name
+            FROM tbl_publishers
+            WHERE id = $1
+        ",
+        )
+        .bind(publisher_uuid)
+        .map(|row: PgRow| {
+            let name_parser: String = row.get("name");
+
+            let id: Uuid = row.get("id");
+            let name: EditorName = EditorName::try_from(name_parser).unwrap();
+
+            Publisher { id, name }
+        })
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(publisher)
+    }
+
+    pub async fn get_publisher_id(&self, publisher_uuid: Uuid) -> ResultDB<Option<Uuid>> {
+        let publisher_uuid: Option<Uuid> = sqlx::query(
+            "
+            SELECT id
+            FROM tbl_publishers
+            WHERE id = $1
+        ",
+        )
+        .bind(publisher_uuid)
+        .map(|row: PgRow| {
+            let id: Uuid = row.get("id");
+            id
+        })
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(publisher_uuid)
+    }
+
+    pub async fn search_publishers(
+        &self,
+        token: String,
+        pagination: Pagination,
+    ) -> ResultDB<Page<Publisher>> {
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "
+            SELECT id, name
+            FROM tbl_publishers
+        ",
+        );
+        push_publisher_filters(&mut query, &token);
+        pagination.push_order(&mut query);
+
+        let publishers_vec: Vec<Publisher> = query
+            .build()
+            .map(|row: PgRow| {
+                let name_parser: String = row.get("name");
+
+                let id: Uuid = row.get("id");
+                let name: EditorName = EditorName::try_from(name_parser).unwrap();
+
+                Publisher { id, name }
+            })
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut count_query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "
+            SELECT count(*) as total
+            FROM tbl_publishers
+        ",
+        );
+        push_publisher_filters(&mut count_query, &token);
+
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(pagination.page(publishers_vec, total))
+    }
+
+    pub async fn update_publisher(&self, publisher: Publisher) -> ResultDB<Uuid> {
+        let publisher_uuid: Uuid = sqlx::query(
+            "
+            UPDATE tbl_publishers
+            SET name = $1
+            WHERE id = $2
+            RETURNING id
+        ",
+        )
+        .bind(publisher.name.as_str())
+        .bind(publisher.id)
+        .map(|row: PgRow| {
+            let uuid: Uuid = row.get("id");
+            uuid
+        })
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(publisher_uuid)
+    }
+
+    pub async fn delete_publisher(&self, publisher_uuid: Uuid) -> ResultDB<Uuid> {
+        let publisher_uuid: Uuid = sqlx::query(
+            "
+            DELETE FROM tbl_publishers
+            WHERE id = $1
+            RETURNING id
+        ",
+        )
+        .bind(publisher_uuid)
+        .map(|row: PgRow| {
+            let uuid: Uuid = row.get("id");
+            uuid
+        })
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(publisher_uuid)
+    }
+
+    pub async fn count_publishers(&self) -> ResultDB<i64> {
+        let total: i64 = sqlx::query_scalar(
+            "
+            SELECT count(*) as total
+            FROM tbl_publishers
+        ",
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::env::var;
+
+    use shared::structs::publisher::{PayloadPublisher, PayloadUpdatePublisher};
+
+    async fn conn_db() -> Database {
+        let db_url: String = var("DATABASE_URL").unwrap();
+        Database::conn(&db_url).await
+    }
+
+    fn create_publisher() -> Publisher {
+        let payload_publisher: PayloadPublisher = PayloadPublisher {
+            name: format!("Publisher {}", Uuid::new_v4().simple()),
+        };
+
+        Publisher::create(payload_publisher).unwrap()
+    }
+
+    #[sqlx::test]
+    async fn test_create_publisher() {
+        let db: Database = conn_db().await;
+
+        let publisher: Publisher = create_publisher();
+
+        let publisher_uuid: Uuid = publisher.id;
+
+        let sql_result: Uuid = db.create_publisher(publisher).await.unwrap();
+
+        assert_eq!(sql_result, publisher_uuid);
+    }
+
+    #[sqlx::test]
+    async fn test_create_publisher_duplicated_name() {
+        let db: Database = conn_db().await;
+
+        let publisher: Publisher = create_publisher();
+
+        db.create_publisher(publisher.clone()).await.unwrap();
+
+        let payload_publisher: PayloadPublisher = PayloadPublisher {
+            name: format!("  {}  ", publisher.name.as_str().to_uppercase()),
+        };
+
+        let sql_result: sqlx::Error = db
+            .create_publisher(Publisher::create(payload_publisher).unwrap())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            sql_result.as_database_error().unwrap().constraint(),
+            Some("idx_publishers_name")
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_get_publisher_found() {
+        let db: Database = conn_db().await;
+
+        let publisher: Publisher = create_publisher();
+
+        let publisher_uuid: Uuid = db.create_publisher(publisher.clone()).await.unwrap();
+
+        let sql_result: Publisher = db.get_publisher(publisher_uuid).await.unwrap().unwrap();
+
+        assert_eq!(sql_result, publisher);
+    }
+
+    #[sqlx::test]
+    async fn test_get_publisher_not_found() {
+        let db: Database = conn_db().await;
+
+        let publisher: Publisher = create_publisher();
+
+        let sql_result: Option<Publisher> = db.get_publisher(publisher.id).await.unwrap();
+
+        assert!(sql_result.is_none());
+    }
+
+    #[sqlx::test]
+    async fn test_get_publisher_id_found() {
+        let db: Database = conn_db().await;
+
+        let publisher: Publisher = create_publisher();
+
+        let publisher_uuid: Uuid = db.create_publisher(publisher.clone()).await.unwrap();
+
+        let sql_result: Uuid = db.get_publisher_id(publisher_uuid).await.unwrap().unwrap();
+
+        assert_eq!(sql_result, publisher_uuid);
+    }
+
+    #[sqlx::test]
+    async fn test_search_publishers_case_insensitive_found() {
+        let db: Database = conn_db().await;
+
+        let publisher: Publisher = create_publisher();
+
+        db.create_publisher(publisher.clone()).await.unwrap();
+
+        let sql_result: Vec<Publisher> = db
+            .search_publishers(
+                publisher.name.as_str().to_lowercase(),
+                Pagination::new(PUBLISHER_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
+
+        assert_eq!(sql_result, vec![publisher]);
+    }
+
+    #[sqlx::test]
+    async fn test_search_publishers_not_found() {
+        let db: Database = conn_db().await;
+
+        let publisher: Publisher = create_publisher();
+
+        db.create_publisher(publisher.clone()).await.unwrap();
+
+        let sql_result: Vec<Publisher> = db
+            .search_publishers("foo".to_string(), Pagination::new(PUBLISHER_ORDER_COLUMNS))
+            .await
+            .unwrap()
+            .items;
+
+        assert!(!sql_result.contains(&publisher));
+    }
+
+    #[sqlx::test]
+    async fn test_update_publisher() {
+        let db: Database = conn_db().await;
+
+        let publisher: Publisher = create_publisher();
+
+        let sql_publisher_uuid: Uuid = db.create_publisher(publisher.clone()).await.unwrap();
+
+        let payload_update_publisher: PayloadUpdatePublisher = PayloadUpdatePublisher {
+            id: sql_publisher_uuid,
+            name: format!("Publisher {}", Uuid::new_v4().simple()),
+        };
+
+        let updated_publisher: Publisher = Publisher::parse(payload_update_publisher).unwrap();
+
+        db.update_publisher(updated_publisher.clone())
+            .await
+            .unwrap();
+
+        let sql_result: Publisher = db.get_publisher(sql_publisher_uuid).await.unwrap().unwrap();
+
+        assert_eq!(sql_result, updated_publisher);
+    }
+
+    #[sqlx::test]
+    async fn test_delete_publisher() {
+        let db: Database = conn_db().await;
+
+        let publisher: Publisher = create_publisher();
+
+        db.create_publisher(publisher.clone()).await.unwrap();
+
+        let sql_result_before: Option<Uuid> = db.get_publisher_id(publisher.id).await.unwrap();
+
+        let sql_result_uuid: Uuid = db.delete_publisher(publisher.id).await.unwrap();
+
+        let sql_result_after: Option<Uuid> = db.get_publisher_id(sql_result_uuid).await.unwrap();
+
+        assert!(sql_result_before.is_some());
+        assert!(sql_result_after.is_none());
+    }
+
+    #[sqlx::test]
+    async fn test_count_publishers() {
+        let db: Database = conn_db().await;
+
+        let sql_result: i64 = db.count_publishers().await.unwrap();
+
+        assert!(sql_result >= 0);
+    }
+}
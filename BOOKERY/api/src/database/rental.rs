@@ -1,105 +1,600 @@
-use sqlx::{postgres::PgRow, Row};
+use sqlx::{postgres::PgRow, Postgres, QueryBuilder, Row, Transaction};
 use time::Date;
 use uuid::Uuid;
 
-use crate::database::{conn::Database, ResultDB};
+use crate::database::{
+    conn::Database, push_date_range, push_ilike, OrderColumns, Pagination, ResultDB,
+};
+use crate::policy::{BorrowingRefusal, BorrowingStanding};
 use shared::structs::{
-    rental::{Rental, RentalWithCostumerAndBook},
-    BookName, PersonName,
+    copy::CopyStatus,
+    fee::Fee,
+    page::Page,
+    rental::{OverdueRental, Rental, RentalFilter, RentalWithCostumerAndBook, ReturnedRental},
+    BookName, ConversionError, PersonName,
 };
 
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CheckoutRefusal {
+    CostumerNotFound,
+    Borrowing(BorrowingRefusal),
+    CopyNotFound,
+    CopyNotLendable,
+    Reserved,
+}
+
+pub const RENTAL_ORDER_COLUMNS: OrderColumns = &[
+    ("borrowed_at", "borrowed_at"),
+    ("due_date", "due_date"),
+    ("returned_at", "returned_at"),
+    ("costumer", "costumer_name"),
+    ("book", "book_name"),
+];
+
+pub const RENTAL_RAW_ORDER_COLUMNS: OrderColumns = &[
+    ("borrowed_at", "borrowed_at"),
+    ("due_date", "due_date"),
+    ("returned_at", "returned_at"),
+];
+
+fn push_rental_dates(query: &mut QueryBuilder<'_, Postgres>, prefix: &str, filter: &RentalFilter) {
+    push_date_range(
+        query,
+        &format!("{prefix}borrowed_at"),
+        filter.borrowed_at_from,
+        filter.borrowed_at_until,
+    );
+    push_date_range(
+        query,
+        &format!("{prefix}due_date"),
+        filter.due_date_from,
+        filter.due_date_until,
+    );
+    push_date_range(
+        query,
+        &format!("{prefix}returned_at"),
+        filter.returned_at_from,
+        filter.returned_at_until,
+    );
+}
+
+fn push_rental_filters(query: &mut QueryBuilder<'_, Postgres>, token: &str, filter: &RentalFilter) {
+    let token: String = format!("%{token}%");
+
+    query
+        .push(" WHERE (c.name ILIKE ")
+        .push_bind(token.clone())
+        .push(" OR b.name ILIKE ")
+        .push_bind(token)
+        .push(")");
+    push_ilike(query, "c.name", &filter.costumer);
+    push_ilike(query, "b.name", &filter.book);
+    push_rental_dates(query, "r.", filter);
+}
+
+fn push_rental_raw_filters(
+    query: &mut QueryBuilder<'_, Postgres>,
+    token: &str,
+    filter: &RentalFilter,
+) {
+    let token: String = format!("%{token}%");
+
+    query
+        .push(" WHERE (costumer_uuid::text ILIKE ")
+        .push_bind(token.clone())
+        .push(" OR copy_uuid::text ILIKE ")
+        .push_bind(token)
+        .push(")");
+    push_rental_dates(query, "", filter);
+
+    if let Some(costumer) = &filter.costumer {
+        query
+            .push(" AND EXISTS (SELECT 1 FROM tbl_costumers c WHERE c.id = tbl_rentals.costumer_uuid AND c.name ILIKE ")
+            .push_bind(format!("%{costumer}%"))
+            .push(")");
+    }
+
+    if let Some(book) = &filter.book {
+        query
+            .push(" AND EXISTS (SELECT 1 FROM tbl_copies cp JOIN tbl_books b ON cp.book_uuid = b.id WHERE cp.id = tbl_rentals.copy_uuid AND b.name ILIKE ")
+            .push_bind(format!("%{book}%"))
+            .push(")");
+    }
+}
+
+fn rental_from_row(row: PgRow) -> Rental {
+    let renewals_parser: i32 = row.get("renewals");
+
+    let id: Uuid = row.get("id");
+    let costumer_uuid: Uuid = row.get("costumer_uuid");
+    let copy_uuid: Uuid = row.get("copy_uuid");
+    let borrowed_at: Date = row.get("borrowed_at");
+    let due_date: Date = row.get("due_date");
+    let returned_at: Option<Date> = row.get("returned_at");
+    let renewals: i64 = i64::from(renewals_parser);
+
+    Rental {
+        id,
+        costumer_uuid,
+        copy_uuid,
+        borrowed_at,
+        due_date,
+        returned_at,
+        renewals,
+    }
+}
+
+async fn copy_book_uuid(tx: &mut Transaction<'_, Postgres>, copy_uuid: Uuid) -> ResultDB<Uuid> {
+    let book_uuid: Uuid = sqlx::query(
+        "
+        SELECT book_uuid
+        FROM tbl_copies
+        WHERE id = $1
+    ",
+    )
+    .bind(copy_uuid)
+    .map(|row: PgRow| {
+        let uuid: Uuid = row.get("book_uuid");
+        uuid
+    })
+    .fetch_one(&mut **tx)
+    .await?;
+
+    Ok(book_uuid)
+}
+
+fn rental_with_costumer_and_book_from_row(
+    row: PgRow,
+) -> Result<RentalWithCostumerAndBook, sqlx::Error> {
+    let rental_costumer_name_parser: String = row.get("costumer_name");
+    let rental_book_name_parser: String = row.get("book_name");
+
+    let id: Uuid = row.get("id");
+    let costumer_name: PersonName = PersonName::try_from(rental_costumer_name_parser)
+        .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+    let book_name: BookName = BookName::try_from(rental_book_name_parser)
+        .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+    let borrowed_at: Date = row.get("borrowed_at");
+    let due_date: Date = row.get("due_date");
+    let returned_at: Option<Date> = row.get("returned_at");
+
+    Ok(RentalWithCostumerAndBook {
+        id,
+        costumer_name,
+        book_name,
+        borrowed_at,
+        due_date,
+        returned_at,
+    })
+}
+
 impl Database {
-    pub async fn create_rental(&self, rental: Rental) -> ResultDB<Uuid> {
+    async fn prepare_checkout(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        costumer_uuid: Uuid,
+        copy_uuid: Uuid,
+    ) -> ResultDB<Result<Uuid, CheckoutRefusal>> {
+        let standing: BorrowingStanding = match self.borrowing_standing(tx, costumer_uuid).await? {
+            Some(standing) => standing,
+            None => return Ok(Err(CheckoutRefusal::CostumerNotFound)),
+        };
+
+        if let Err(refusal) = self.policy.borrowing.check(&standing) {
+            return Ok(Err(CheckoutRefusal::Borrowing(refusal)));
+        }
+
+        let copy: Option<(Uuid, String)> = sqlx::query(
+            "
+            SELECT book_uuid, status
+            FROM tbl_copies
+            WHERE id = $1
+            FOR UPDATE
+        ",
+        )
+        .bind(copy_uuid)
+        .map(|row: PgRow| {
+            let book_uuid: Uuid = row.get("book_uuid");
+            let status: String = row.get("status");
+
+            (book_uuid, status)
+        })
+        .fetch_optional(&mut **tx)
+        .await?;
+
+        let book_uuid: Uuid = match copy {
+            None => return Ok(Err(CheckoutRefusal::CopyNotFound)),
+            Some((_, status)) if status != CopyStatus::Available.as_str() => {
+                return Ok(Err(CheckoutRefusal::CopyNotLendable))
+            }
+            Some((book_uuid, _)) => book_uuid,
+        };
+
+        self.sync_reservations(tx, book_uuid).await?;
+
+        Ok(Ok(book_uuid))
+    }
+
+    pub async fn create_rental(&self, rental: Rental) -> ResultDB<Result<Uuid, CheckoutRefusal>> {
+        let mut tx: Transaction<'_, Postgres> = self.pool.begin().await?;
+
+        let book_uuid: Uuid = match self
+            .prepare_checkout(&mut tx, rental.costumer_uuid, rental.copy_uuid)
+            .await?
+        {
+            Ok(book_uuid) => book_uuid,
+            Err(refusal) => return Ok(Err(refusal)),
+        };
+
         let rental_uuid: Uuid = sqlx::query(
             "
-            INSERT INTO tbl_rentals (id, costumer_uuid, book_uuid, borrowed_at, due_date)
+            INSERT INTO tbl_rentals (id, costumer_uuid, copy_uuid, borrowed_at, due_date)
             VALUES ($1, $2, $3, $4, $5)
             RETURNING id
         ",
         )
         .bind(rental.id)
         .bind(rental.costumer_uuid)
-        .bind(rental.book_uuid)
+        .bind(rental.copy_uuid)
         .bind(rental.borrowed_at)
         .bind(rental.due_date)
         .map(|row: PgRow| {
             let uuid: Uuid = row.get("id");
             uuid
         })
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *tx)
         .await?;
 
-        Ok(rental_uuid)
+        if !self
+            .claim_reservation(&mut tx, book_uuid, rental.costumer_uuid)
+            .await?
+        {
+            return Ok(Err(CheckoutRefusal::Reserved));
+        }
+
+        tx.commit().await?;
+
+        Ok(Ok(rental_uuid))
     }
 
-    pub async fn get_rental(
+    pub async fn checkout_rental(
         &self,
-        rental_uuid: Uuid,
-    ) -> ResultDB<Option<RentalWithCostumerAndBook>> {
-        let rental: Option<RentalWithCostumerAndBook> = sqlx::query(
+        costumer_uuid: Uuid,
+        copy_uuid: Uuid,
+    ) -> ResultDB<Result<Rental, CheckoutRefusal>> {
+        let mut tx: Transaction<'_, Postgres> = self.pool.begin().await?;
+
+        let book_uuid: Uuid = match self
+            .prepare_checkout(&mut tx, costumer_uuid, copy_uuid)
+            .await?
+        {
+            Ok(book_uuid) => book_uuid,
+            Err(refusal) => return Ok(Err(refusal)),
+        };
+
+        let rental: Rental = sqlx::query(
             "
-            SELECT r.id as id, c.name as costumer_name, b.name as book_name, r.borrowed_at as borrowed_at, r.due_date as due_date, r.returned_at as returned_at
+            INSERT INTO tbl_rentals (id, costumer_uuid, copy_uuid, borrowed_at)
+            VALUES ($1, $2, $3, CURRENT_DATE)
+            RETURNING id, costumer_uuid, copy_uuid, borrowed_at, due_date, returned_at, renewals
+        ",
+        )
+        .bind(Uuid::new_v4())
+        .bind(costumer_uuid)
+        .bind(copy_uuid)
+        .map(rental_from_row)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if !self
+            .claim_reservation(&mut tx, book_uuid, costumer_uuid)
+            .await?
+        {
+            return Ok(Err(CheckoutRefusal::Reserved));
+        }
+
+        tx.commit().await?;
+
+        Ok(Ok(rental))
+    }
+
+    pub async fn return_rental(&self, rental_uuid: Uuid) -> ResultDB<Option<ReturnedRental>> {
+        let mut tx: Transaction<'_, Postgres> = self.pool.begin().await?;
+
+        let rental: Option<Rental> = sqlx::query(
+            "
+            UPDATE tbl_rentals
+            SET returned_at = CURRENT_DATE
+            WHERE id = $1 AND returned_at IS NULL
+            RETURNING id, costumer_uuid, copy_uuid, borrowed_at, due_date, returned_at, renewals
+        ",
+        )
+        .bind(rental_uuid)
+        .map(rental_from_row)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let rental: Rental = match rental {
+            Some(rental) => rental,
+            None => return Ok(None),
+        };
+
+        let book_uuid: Uuid = copy_book_uuid(&mut tx, rental.copy_uuid).await?;
+
+        self.sync_reservations(&mut tx, book_uuid).await?;
+
+        let returned_at: Date = rental.returned_at.unwrap_or(rental.due_date);
+        let days_overdue: i64 = (returned_at - rental.due_date).whole_days();
+        let amount: i64 = self.policy.fee.fee(days_overdue);
+
+        let fee: Option<Fee> = if amount > 0 {
+            let fee: Fee = sqlx::query(
+                "
+                INSERT INTO tbl_fees (id, costumer_uuid, rental_uuid, amount, days_overdue)
+                VALUES ($1, $2, $3, $4, $5)
+                RETURNING id, costumer_uuid, rental_uuid, amount, days_overdue, charged_at, paid_at
+            ",
+            )
+            .bind(Uuid::new_v4())
+            .bind(rental.costumer_uuid)
+            .bind(rental.id)
+            .bind(amount)
+            .bind(days_overdue as i32)
+            .map(|row: PgRow| {
+                let days_overdue_parser: i32 = row.get("days_overdue");
+
+                let id: Uuid = row.get("id");
+                let costumer_uuid: Uuid = row.get("costumer_uuid");
+                let rental_uuid: Uuid = row.get("rental_uuid");
+                let amount: i64 = row.get("amount");
+                let days_overdue: i64 = i64::from(days_overdue_parser);
+                let charged_at: Date = row.get("charged_at");
+                let paid_at: Option<Date> = row.get("paid_at");
+
+                Fee {
+                    id,
+                    costumer_uuid,
+                    rental_uuid,
+                    amount,
+                    days_overdue,
+                    charged_at,
+                    paid_at,
+                }
+            })
+            .fetch_one(&mut *tx)
+            .await?;
+
+            Some(fee)
+        } else {
+            None
+        };
+
+        tx.commit().await?;
+
+        Ok(Some(ReturnedRental { rental, fee }))
+    }
+
+    pub async fn renew_rental(&self, rental_uuid: Uuid) -> ResultDB<Option<Rental>> {
+        let rental: Option<Rental> = sqlx::query(
+            "
+            UPDATE tbl_rentals
+            SET due_date = due_date + $2, renewals = renewals + 1
+            WHERE id = $1 AND returned_at IS NULL AND due_date >= CURRENT_DATE AND renewals < $3
+            AND NOT EXISTS (
+                SELECT 1
+                FROM tbl_reservations rs
+                JOIN tbl_copies cp
+                ON rs.book_uuid = cp.book_uuid
+                WHERE cp.id = tbl_rentals.copy_uuid
+                AND rs.costumer_uuid <> tbl_rentals.costumer_uuid
+                AND rs.status IN ('waiting', 'ready')
+            )
+            RETURNING id, costumer_uuid, copy_uuid, borrowed_at, due_date, returned_at, renewals
+        ",
+        )
+        .bind(rental_uuid)
+        .bind(self.policy.renewal.period_days as i32)
+        .bind(self.policy.renewal.max_renewals as i32)
+        .map(rental_from_row)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(rental)
+    }
+
+    pub async fn overdue_rentals(&self) -> ResultDB<Vec<OverdueRental>> {
+        let rentals_vec: Vec<OverdueRental> = sqlx::query(
+            "
+            SELECT r.id as id, r.costumer_uuid as costumer_uuid, c.name as costumer_name, r.copy_uuid as copy_uuid, b.name as book_name, r.borrowed_at as borrowed_at, r.due_date as due_date, (CURRENT_DATE - r.due_date)::bigint as days_overdue
             FROM tbl_rentals r
             JOIN tbl_costumers c
             ON r.costumer_uuid = c.id
+            JOIN tbl_copies cp
+            ON r.copy_uuid = cp.id
             JOIN tbl_books b
-            ON r.book_uuid = b.id
-            WHERE r.id = $1
+            ON cp.book_uuid = b.id
+            WHERE r.returned_at IS NULL AND r.due_date < CURRENT_DATE
+            ORDER BY r.due_date, r.id
         ",
         )
-        .bind(rental_uuid)
-        .map(|row: PgRow| {
+        .try_map(|row: PgRow| {
             let rental_costumer_name_parser: String = row.get("costumer_name");
             let rental_book_name_parser: String = row.get("book_name");
 
             let id: Uuid = row.get("id");
-            let costumer_name: PersonName = PersonName::try_from(rental_costumer_name_parser).unwrap();
-            let book_name: BookName = BookName::try_from(rental_book_name_parser).unwrap();
+            let costumer_uuid: Uuid = row.get("costumer_uuid");
+            let costumer_name: PersonName = PersonName::try_from(rental_costumer_name_parser)
+                .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
+            let copy_uuid: Uuid = row.get("copy_uuid");
+            let book_name: BookName = BookName::try_from(rental_book_name_parser)
+                .map_err(|e: ConversionError| sqlx::Error::Decode(Box::new(e)))?;
             let borrowed_at: Date = row.get("borrowed_at");
             let due_date: Date = row.get("due_date");
-            let returned_at: Option<Date> = row.get("returned_at");
+            let days_overdue: i64 = row.get("days_overdue");
+            let fee: i64 = self.policy.fee.fee(days_overdue);
 
-            RentalWithCostumerAndBook {
+            Ok(OverdueRental {
                 id,
+                costumer_uuid,
                 costumer_name,
+                copy_uuid,
                 book_name,
                 borrowed_at,
                 due_date,
-                returned_at,
-            }
+                days_overdue,
+                fee,
+            })
         })
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rentals_vec)
+    }
+
+    pub async fn get_rental(
+        &self,
+        rental_uuid: Uuid,
+    ) -> ResultDB<Option<RentalWithCostumerAndBook>> {
+        let rental: Option<RentalWithCostumerAndBook> = sqlx::query(
+            "
+            SELECT r.id as id, c.name as costumer_name, b.name as book_name, r.borrowed_at as borrowed_at, r.due_date as due_date, r.returned_at as returned_at
+            FROM tbl_rentals r
+            JOIN tbl_costumers c
+            ON r.costumer_uuid = c.id
+            JOIN tbl_copies cp
+            ON r.copy_uuid = cp.id
+            JOIN tbl_books b
+            ON cp.book_uuid = b.id
+            WHERE r.id = $1
+        ",
+        )
+        .bind(rental_uuid)
+        .try_map(rental_with_costumer_and_book_from_row)
         .fetch_optional(&self.pool)
         .await?;
 
         Ok(rental)
     }
 
+    async fn open_rentals_by(
+        &self,
+        owner_column: &str,
+        owner_uuid: Uuid,
+    ) -> ResultDB<Vec<RentalWithCostumerAndBook>> {
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "
+            SELECT r.id as id, c.name as costumer_name, b.name as book_name, r.borrowed_at as borrowed_at, r.due_date as due_date, r.returned_at as returned_at
+            FROM tbl_rentals r
+            JOIN tbl_costumers c
+            ON r.costumer_uuid = c.id
+            JOIN tbl_copies cp
+            ON r.copy_uuid = cp.id
+            JOIN tbl_books b
+            ON cp.book_uuid = b.id
+        ",
+        );
+        query
+            .push(format!(" WHERE {owner_column} = "))
+            .push_bind(owner_uuid)
+            .push(" AND r.returned_at IS NULL ORDER BY r.due_date, r.id");
+
+        let rentals_vec: Vec<RentalWithCostumerAndBook> = query
+            .build()
+            .try_map(rental_with_costumer_and_book_from_row)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rentals_vec)
+    }
+
+    async fn rental_history_by(
+        &self,
+        owner_column: &str,
+        owner_uuid: Uuid,
+        pagination: Pagination,
+    ) -> ResultDB<Page<RentalWithCostumerAndBook>> {
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "
+            SELECT r.id as id, c.name as costumer_name, b.name as book_name, r.borrowed_at as borrowed_at, r.due_date as due_date, r.returned_at as returned_at
+            FROM tbl_rentals r
+            JOIN tbl_costumers c
+            ON r.costumer_uuid = c.id
+            JOIN tbl_copies cp
+            ON r.copy_uuid = cp.id
+            JOIN tbl_books b
+            ON cp.book_uuid = b.id
+        ",
+        );
+        query
+            .push(format!(" WHERE {owner_column} = "))
+            .push_bind(owner_uuid);
+        pagination.push_order(&mut query);
+
+        let rentals_vec: Vec<RentalWithCostumerAndBook> = query
+            .build()
+            .try_map(rental_with_costumer_and_book_from_row)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut count_query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "
+            SELECT count(*) as total
+            FROM tbl_rentals r
+            JOIN tbl_copies cp
+            ON r.copy_uuid = cp.id
+        ",
+        );
+        count_query
+            .push(format!(" WHERE {owner_column} = "))
+            .push_bind(owner_uuid);
+
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(pagination.page(rentals_vec, total))
+    }
+
+    pub async fn costumer_loans(
+        &self,
+        costumer_uuid: Uuid,
+    ) -> ResultDB<Vec<RentalWithCostumerAndBook>> {
+        self.open_rentals_by("r.costumer_uuid", costumer_uuid).await
+    }
+
+    pub async fn costumer_rentals(
+        &self,
+        costumer_uuid: Uuid,
+        pagination: Pagination,
+    ) -> ResultDB<Page<RentalWithCostumerAndBook>> {
+        self.rental_history_by("r.costumer_uuid", costumer_uuid, pagination)
+            .await
+    }
+
+    pub async fn book_loans(&self, book_uuid: Uuid) -> ResultDB<Vec<RentalWithCostumerAndBook>> {
+        self.open_rentals_by("cp.book_uuid", book_uuid).await
+    }
+
+    pub async fn book_rentals(
+        &self,
+        book_uuid: Uuid,
+        pagination: Pagination,
+    ) -> ResultDB<Page<RentalWithCostumerAndBook>> {
+        self.rental_history_by("cp.book_uuid", book_uuid, pagination)
+            .await
+    }
+
     pub async fn get_rental_raw(&self, rental_uuid: Uuid) -> ResultDB<Option<Rental>> {
         let rental: Option<Rental> = sqlx::query(
             "
-            SELECT id, costumer_uuid, book_uuid, borrowed_at, due_date, returned_at
+            SELECT id, costumer_uuid, copy_uuid, borrowed_at, due_date, returned_at, renewals
             FROM tbl_rentals
             WHERE id = $1
         ",
         )
         .bind(rental_uuid)
-        .map(|row: PgRow| {
-            let id: Uuid = row.get("id");
-            let costumer_uuid: Uuid = row.get("costumer_uuid");
-            let book_uuid: Uuid = row.get("book_uuid");
-            let borrowed_at: Date = row.get("borrowed_at");
-            let due_date: Date = row.get("due_date");
-            let returned_at: Option<Date> = row.get("returned_at");
-
-            Rental {
-                id,
-                costumer_uuid,
-                book_uuid,
-                borrowed_at,
-                due_date,
-                returned_at,
-            }
-        })
+        .map(rental_from_row)
         .fetch_optional(&self.pool)
         .await?;
 
@@ -125,89 +620,103 @@ impl Database {
         Ok(rental_uuid)
     }
 
-    pub async fn search_rentals(&self, token: String) -> ResultDB<Vec<RentalWithCostumerAndBook>> {
-        let costumers_vec: Vec<RentalWithCostumerAndBook> = sqlx::query(
+    pub async fn search_rentals(
+        &self,
+        token: String,
+        filter: RentalFilter,
+        pagination: Pagination,
+    ) -> ResultDB<Page<RentalWithCostumerAndBook>> {
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
             "
             SELECT r.id as id, c.name as costumer_name, b.name as book_name, r.borrowed_at as borrowed_at, r.due_date as due_date, r.returned_at as returned_at
             FROM tbl_rentals r
             JOIN tbl_costumers c
             ON r.costumer_uuid = c.id
+            JOIN tbl_copies cp
+            ON r.copy_uuid = cp.id
             JOIN tbl_books b
-            ON r.book_uuid = b.id
-            WHERE c.name ILIKE $1 OR b.name ILIKE $1
+            ON cp.book_uuid = b.id
         ",
-        )
-        .bind(format!("%{token}%"))
-        .map(|row: PgRow| {
-            let rental_costumer_name_parser: String = row.get("costumer_name");
-            let rental_book_name_parser: String = row.get("book_name");
+        );
+        push_rental_filters(&mut query, &token, &filter);
+        pagination.push_order(&mut query);
 
-            let id: Uuid = row.get("id");
-            let costumer_name: PersonName =
-                PersonName::try_from(rental_costumer_name_parser).unwrap();
-            let book_name: BookName = BookName::try_from(rental_book_name_parser).unwrap();
-            let borrowed_at: Date = row.get("borrowed_at");
-            let due_date: Date = row.get("due_date");
-            let returned_at: Option<Date> = row.get("returned_at");
+        let rentals_vec: Vec<RentalWithCostumerAndBook> = query
+            .build()
+            .try_map(rental_with_costumer_and_book_from_row)
+            .fetch_all(&self.pool)
+            .await?;
 
-            RentalWithCostumerAndBook {
-                id,
-                costumer_name,
-                book_name,
-                borrowed_at,
-                due_date,
-                returned_at,
-            }
-        })
-        .fetch_all(&self.pool)
-        .await?;
+        let mut count_query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "
+            SELECT count(*) as total
+            FROM tbl_rentals r
+            JOIN tbl_costumers c
+            ON r.costumer_uuid = c.id
+            JOIN tbl_copies cp
+            ON r.copy_uuid = cp.id
+            JOIN tbl_books b
+            ON cp.book_uuid = b.id
+        ",
+        );
+        push_rental_filters(&mut count_query, &token, &filter);
 
-        Ok(costumers_vec)
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(pagination.page(rentals_vec, total))
     }
 
-    pub async fn search_rentals_raw(&self, token: String) -> ResultDB<Vec<Rental>> {
-        let costumers_vec: Vec<Rental> = sqlx::query(
+    pub async fn search_rentals_raw(
+        &self,
+        token: String,
+        filter: RentalFilter,
+        pagination: Pagination,
+    ) -> ResultDB<Page<Rental>> {
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
             "
-            SELECT id, costumer_uuid, book_uuid, borrowed_at, due_date, returned_at
+            SELECT id, costumer_uuid, copy_uuid, borrowed_at, due_date, returned_at, renewals
             FROM tbl_rentals
-            WHERE costumer_uuid::text ILIKE $1 OR book_uuid::text ILIKE $1
         ",
-        )
-        .bind(format!("%{token}%"))
-        .map(|row: PgRow| {
-            let id: Uuid = row.get("id");
-            let costumer_uuid: Uuid = row.get("costumer_uuid");
-            let book_uuid: Uuid = row.get("book_uuid");
-            let borrowed_at: Date = row.get("borrowed_at");
-            let due_date: Date = row.get("due_date");
-            let returned_at: Option<Date> = row.get("returned_at");
+        );
+        push_rental_raw_filters(&mut query, &token, &filter);
+        pagination.push_order(&mut query);
 
-            Rental {
-                id,
-                costumer_uuid,
-                book_uuid,
-                borrowed_at,
-                due_date,
-                returned_at,
-            }
-        })
-        .fetch_all(&self.pool)
-        .await?;
+        let rentals_vec: Vec<Rental> = query
+            .build()
+            .map(rental_from_row)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut count_query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "
+            SELECT count(*) as total
+            FROM tbl_rentals
+        ",
+        );
+        push_rental_raw_filters(&mut count_query, &token, &filter);
+
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
 
-        Ok(costumers_vec)
+        Ok(pagination.page(rentals_vec, total))
     }
 
     pub async fn update_rental(&self, rental: Rental) -> ResultDB<Uuid> {
         let rental_uuid: Uuid = sqlx::query(
             "
             UPDATE tbl_rentals
-            SET costumer_uuid = $1, book_uuid = $2, borrowed_at = $3, due_date = $4, returned_at = $5
+            SET costumer_uuid = $1, copy_uuid = $2, borrowed_at = $3, due_date = $4, returned_at = $5
             WHERE id = $6
             RETURNING id
         ",
         )
         .bind(rental.costumer_uuid)
-        .bind(rental.book_uuid)
+        .bind(rental.copy_uuid)
         .bind(rental.borrowed_at)
         .bind(rental.due_date)
         .bind(rental.returned_at)
@@ -261,19 +770,24 @@ mod tests {
 
     use std::env::var;
 
-    use time::{error::ComponentRange, Date, Month};
+    use sqlx::Error;
+    use time::{error::ComponentRange, Date, Duration, Month};
 
     use crate::handlers::QueryURL;
     use shared::structs::{
         author::{Author, PayloadAuthor},
-        book::{Book, PayloadBook},
-        costumer::{Costumer, PayloadCostumer},
+        book::{Book, BookWithAuthor, PayloadBook, PayloadContributor},
+        copy::{BookCopy, PayloadBookCopy},
+        costumer::{
+            Costumer, CostumerBlock, PayloadBlockCostumer, PayloadCostumer, PayloadDocument,
+        },
+        publisher::{PayloadPublisher, Publisher},
         rental::{PayloadRental, PayloadUpdateRental},
     };
 
-    const DEFAULT_NAME: &'static str = "Name";
-    const DEFAULT_EDITOR: &'static str = "Editor";
-    const DEFAULT_DOCUMENT: &'static str = "12345678901";
+    const DEFAULT_NAME: &str = "Name";
+    const DEFAULT_EDITOR: &str = "Editor";
+    const DEFAULT_SHELF_LOCATION: &str = "A-01";
     const DEFAULT_BORN: Result<Date, ComponentRange> =
         Date::from_calendar_date(2000, Month::January, 1);
 
@@ -287,6 +801,13 @@ mod tests {
     const DEFAULT_RETURNED_DATE: Result<Date, ComponentRange> =
         Date::from_calendar_date(2000, Month::January, 1);
 
+    fn random_document() -> PayloadDocument {
+        PayloadDocument {
+            kind: "rg".to_string(),
+            value: format!("{:014}", Uuid::new_v4().as_u128() % 10u128.pow(14)),
+        }
+    }
+
     async fn conn_db() -> Database {
         let db_url: String = var("DATABASE_URL").unwrap();
         Database::conn(&db_url).await
@@ -304,10 +825,24 @@ mod tests {
 
         let author_uuid: Uuid = db.create_author(author).await.unwrap();
 
+        let payload_publisher: PayloadPublisher = PayloadPublisher {
+            name: format!("{DEFAULT_EDITOR} {}", Uuid::new_v4().simple()),
+        };
+
+        let publisher_uuid: Uuid = db
+            .create_publisher(Publisher::create(payload_publisher).unwrap())
+            .await
+            .unwrap();
+
         let payload_book: PayloadBook = PayloadBook {
             name: DEFAULT_NAME.to_string(),
-            editor: DEFAULT_EDITOR.to_string(),
-            author_uuid,
+            isbn: None,
+            publisher_uuid,
+            contributors: vec![PayloadContributor {
+                author_uuid,
+                role: "author".to_string(),
+            }],
+            subjects: vec![],
             release: DEFAULT_RELEASE.unwrap(),
         };
 
@@ -318,12 +853,30 @@ mod tests {
         book
     }
 
+    async fn create_copy() -> BookCopy {
+        let db: Database = conn_db().await;
+
+        let payload_copy: PayloadBookCopy = PayloadBookCopy {
+            book_uuid: create_book().await.id,
+            barcode: Uuid::new_v4().simple().to_string(),
+            condition: "good".to_string(),
+            shelf_location: DEFAULT_SHELF_LOCATION.to_string(),
+            status: "available".to_string(),
+        };
+
+        let copy: BookCopy = BookCopy::create(payload_copy).unwrap();
+
+        db.create_copy(copy.clone()).await.unwrap();
+
+        copy
+    }
+
     async fn create_costumer() -> Costumer {
         let db: Database = conn_db().await;
 
         let payload_costumer: PayloadCostumer = PayloadCostumer {
             name: DEFAULT_NAME.to_string(),
-            document: DEFAULT_DOCUMENT.to_string(),
+            document: random_document(),
             born: DEFAULT_BORN.unwrap(),
         };
 
@@ -335,11 +888,11 @@ mod tests {
     }
 
     async fn create_rental() -> Rental {
-        let book_uuid: Uuid = create_book().await.id;
+        let copy_uuid: Uuid = create_copy().await.id;
         let costumer_uuid: Uuid = create_costumer().await.id;
 
         let payload_rental: PayloadRental = PayloadRental {
-            book_uuid,
+            copy_uuid,
             costumer_uuid,
             borrowed_at: DEFAULT_BORROWED_DATE.unwrap(),
             due_date: DEFAULT_DUE_DATE.unwrap(),
@@ -354,23 +907,349 @@ mod tests {
 
         let rental: Rental = create_rental().await;
 
-        let rental_uuid: Uuid = rental.id.clone();
+        let rental_uuid: Uuid = rental.id;
 
-        let sql_result: Uuid = db.create_rental(rental).await.unwrap();
+        let sql_result: Uuid = db.create_rental(rental).await.unwrap().unwrap();
 
         assert_eq!(sql_result, rental_uuid);
     }
 
+    #[sqlx::test]
+    async fn test_create_rental_book_unavailable() {
+        let db: Database = conn_db().await;
+
+        let rental: Rental = create_rental().await;
+        let mut second_rental: Rental = rental.clone();
+        second_rental.id = Uuid::new_v4();
+
+        db.create_rental(rental).await.unwrap().unwrap();
+
+        let sql_result: Error = db.create_rental(second_rental).await.unwrap_err();
+
+        assert_eq!(
+            sql_result
+                .as_database_error()
+                .and_then(|e: &dyn sqlx::error::DatabaseError| e.constraint()),
+            Some("idx_rentals_open_copy")
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_create_rental_copy_refused() {
+        let db: Database = conn_db().await;
+
+        let mut rental: Rental = create_rental().await;
+        rental.copy_uuid = Uuid::new_v4();
+
+        assert_eq!(
+            db.create_rental(rental).await.unwrap(),
+            Err(CheckoutRefusal::CopyNotFound)
+        );
+
+        let mut copy: BookCopy = create_copy().await;
+        copy.status = CopyStatus::Maintenance;
+        db.update_copy(copy.clone()).await.unwrap();
+
+        let mut rental: Rental = create_rental().await;
+        rental.copy_uuid = copy.id;
+
+        assert_eq!(
+            db.create_rental(rental).await.unwrap(),
+            Err(CheckoutRefusal::CopyNotLendable)
+        );
+        assert_eq!(
+            db.checkout_rental(create_costumer().await.id, copy.id)
+                .await
+                .unwrap(),
+            Err(CheckoutRefusal::CopyNotLendable)
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_create_rental_costumer_refused() {
+        let db: Database = conn_db().await;
+
+        let mut rental: Rental = create_rental().await;
+        rental.costumer_uuid = Uuid::new_v4();
+
+        assert_eq!(
+            db.create_rental(rental).await.unwrap(),
+            Err(CheckoutRefusal::CostumerNotFound)
+        );
+
+        let rental: Rental = create_rental().await;
+
+        db.block_costumer(
+            CostumerBlock::create(PayloadBlockCostumer {
+                id: rental.costumer_uuid,
+                reason: "Damaged a book".to_string(),
+                until: None,
+            })
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            db.create_rental(rental).await.unwrap(),
+            Err(CheckoutRefusal::Borrowing(BorrowingRefusal::Blocked))
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_checkout_rental_limit_concurrent() {
+        let db: Database = conn_db().await;
+
+        let costumer_uuid: Uuid = create_costumer().await.id;
+
+        for _ in 1..db.policy.borrowing.max_rentals {
+            db.checkout_rental(costumer_uuid, create_copy().await.id)
+                .await
+                .unwrap()
+                .unwrap();
+        }
+
+        let first_copy_uuid: Uuid = create_copy().await.id;
+        let second_copy_uuid: Uuid = create_copy().await.id;
+
+        let (first, second) = tokio::join!(
+            db.checkout_rental(costumer_uuid, first_copy_uuid),
+            db.checkout_rental(costumer_uuid, second_copy_uuid)
+        );
+        let results: Vec<Result<Rental, CheckoutRefusal>> = vec![first.unwrap(), second.unwrap()];
+
+        assert_eq!(
+            results
+                .iter()
+                .filter(|result: &&Result<Rental, CheckoutRefusal>| result.is_ok())
+                .count(),
+            1
+        );
+        assert!(results.contains(&Err(CheckoutRefusal::Borrowing(
+            BorrowingRefusal::RentalLimit
+        ))));
+    }
+
+    #[sqlx::test]
+    async fn test_checkout_rental() {
+        let db: Database = conn_db().await;
+
+        let copy_uuid: Uuid = create_copy().await.id;
+        let costumer_uuid: Uuid = create_costumer().await.id;
+
+        let sql_result: Rental = db
+            .checkout_rental(costumer_uuid, copy_uuid)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(sql_result.costumer_uuid, costumer_uuid);
+        assert_eq!(sql_result.copy_uuid, copy_uuid);
+        assert_eq!(sql_result.returned_at, None);
+        assert!(sql_result.due_date > sql_result.borrowed_at);
+
+        assert!(db.checkout_rental(costumer_uuid, copy_uuid).await.is_err());
+    }
+
+    #[sqlx::test]
+    async fn test_return_rental() {
+        let db: Database = conn_db().await;
+
+        let copy_uuid: Uuid = create_copy().await.id;
+        let costumer_uuid: Uuid = create_costumer().await.id;
+
+        let rental: Rental = db
+            .checkout_rental(costumer_uuid, copy_uuid)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let sql_result: ReturnedRental = db.return_rental(rental.id).await.unwrap().unwrap();
+
+        assert_eq!(sql_result.rental.id, rental.id);
+        assert_eq!(sql_result.rental.returned_at, Some(rental.borrowed_at));
+        assert_eq!(sql_result.fee, None);
+
+        assert_eq!(db.return_rental(rental.id).await.unwrap(), None);
+
+        db.checkout_rental(costumer_uuid, copy_uuid)
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn test_return_rental_overdue_fee() {
+        let db: Database = conn_db().await;
+
+        let rental: Rental = create_rental().await;
+
+        db.create_rental(rental.clone()).await.unwrap().unwrap();
+
+        let overdue: Vec<OverdueRental> = db.overdue_rentals().await.unwrap();
+        let overdue_rental: &OverdueRental = overdue
+            .iter()
+            .find(|overdue_rental: &&OverdueRental| overdue_rental.id == rental.id)
+            .unwrap();
+
+        assert!(overdue_rental.days_overdue > 0);
+        assert_eq!(
+            overdue_rental.fee,
+            db.policy.fee.fee(overdue_rental.days_overdue)
+        );
+
+        let sql_result: ReturnedRental = db.return_rental(rental.id).await.unwrap().unwrap();
+        let fee: Fee = sql_result.fee.unwrap();
+
+        assert_eq!(fee.costumer_uuid, rental.costumer_uuid);
+        assert_eq!(fee.rental_uuid, rental.id);
+        assert_eq!(fee.days_overdue, overdue_rental.days_overdue);
+        assert_eq!(fee.amount, db.policy.fee.fee(fee.days_overdue));
+        assert_eq!(fee.paid_at, None);
+
+        assert!(!db
+            .overdue_rentals()
+            .await
+            .unwrap()
+            .iter()
+            .any(|overdue_rental: &OverdueRental| overdue_rental.id == rental.id));
+    }
+
+    #[sqlx::test]
+    async fn test_costumer_loans_and_rentals() {
+        let db: Database = conn_db().await;
+
+        let costumer_uuid: Uuid = create_costumer().await.id;
+
+        let returned_rental: Rental = db
+            .checkout_rental(costumer_uuid, create_copy().await.id)
+            .await
+            .unwrap()
+            .unwrap();
+        db.return_rental(returned_rental.id).await.unwrap();
+
+        let open_rental: Rental = db
+            .checkout_rental(costumer_uuid, create_copy().await.id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let loans: Vec<RentalWithCostumerAndBook> = db.costumer_loans(costumer_uuid).await.unwrap();
+
+        assert_eq!(
+            loans
+                .iter()
+                .map(|rental: &RentalWithCostumerAndBook| rental.id)
+                .collect::<Vec<Uuid>>(),
+            vec![open_rental.id]
+        );
+
+        let mut pagination: Pagination = Pagination::new(RENTAL_ORDER_COLUMNS);
+        pagination.limit = Some(1);
+
+        let history: Page<RentalWithCostumerAndBook> = db
+            .costumer_rentals(costumer_uuid, pagination)
+            .await
+            .unwrap();
+
+        assert_eq!(history.total, 2);
+        assert_eq!(history.items.len(), 1);
+    }
+
+    #[sqlx::test]
+    async fn test_book_loans_and_rentals() {
+        let db: Database = conn_db().await;
+
+        let copy: BookCopy = create_copy().await;
+
+        let returned_rental: Rental = db
+            .checkout_rental(create_costumer().await.id, copy.id)
+            .await
+            .unwrap()
+            .unwrap();
+        db.return_rental(returned_rental.id).await.unwrap();
+
+        let open_rental: Rental = db
+            .checkout_rental(create_costumer().await.id, copy.id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let loans: Vec<RentalWithCostumerAndBook> = db.book_loans(copy.book_uuid).await.unwrap();
+
+        assert_eq!(
+            loans
+                .iter()
+                .map(|rental: &RentalWithCostumerAndBook| rental.id)
+                .collect::<Vec<Uuid>>(),
+            vec![open_rental.id]
+        );
+
+        let history: Page<RentalWithCostumerAndBook> = db
+            .book_rentals(copy.book_uuid, Pagination::new(RENTAL_ORDER_COLUMNS))
+            .await
+            .unwrap();
+
+        assert_eq!(history.total, 2);
+
+        let book: BookWithAuthor = db.get_book(copy.book_uuid).await.unwrap().unwrap();
+
+        assert_eq!(book.circulation_count, 2);
+        assert_eq!(book.last_borrowed_at, Some(open_rental.borrowed_at));
+    }
+
+    #[sqlx::test]
+    async fn test_renew_rental() {
+        let db: Database = conn_db().await;
+
+        let copy_uuid: Uuid = create_copy().await.id;
+        let costumer_uuid: Uuid = create_costumer().await.id;
+
+        let rental: Rental = db
+            .checkout_rental(costumer_uuid, copy_uuid)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let mut due_date: Date = rental.due_date;
+
+        for renewals in 1..=db.policy.renewal.max_renewals {
+            let renewed_rental: Rental = db.renew_rental(rental.id).await.unwrap().unwrap();
+
+            due_date += Duration::days(db.policy.renewal.period_days);
+
+            assert_eq!(renewed_rental.due_date, due_date);
+            assert_eq!(renewed_rental.renewals, renewals);
+        }
+
+        assert_eq!(db.renew_rental(rental.id).await.unwrap(), None);
+    }
+
+    #[sqlx::test]
+    async fn test_renew_rental_overdue_or_returned() {
+        let db: Database = conn_db().await;
+
+        let rental: Rental = create_rental().await;
+
+        db.create_rental(rental.clone()).await.unwrap().unwrap();
+
+        assert_eq!(db.renew_rental(rental.id).await.unwrap(), None);
+
+        db.return_rental(rental.id).await.unwrap();
+
+        assert_eq!(db.renew_rental(rental.id).await.unwrap(), None);
+    }
+
     #[sqlx::test]
     async fn test_get_rental_found() {
         let db: Database = conn_db().await;
 
         let rental: Rental = create_rental().await;
 
-        let rental_uuid: Uuid = db.create_rental(rental).await.unwrap();
+        let rental_uuid: Uuid = db.create_rental(rental).await.unwrap().unwrap();
 
         let sql_result: RentalWithCostumerAndBook =
-            db.get_rental(rental_uuid.clone()).await.unwrap().unwrap();
+            db.get_rental(rental_uuid).await.unwrap().unwrap();
 
         assert_eq!(
             sql_result,
@@ -385,14 +1264,43 @@ mod tests {
         );
     }
 
+    #[sqlx::test]
+    async fn test_get_rental_blank_costumer_name() {
+        let db: Database = conn_db().await;
+
+        let mut rental: Rental = create_rental().await;
+        rental.due_date = Date::from_calendar_date(2999, Month::January, 1).unwrap();
+
+        let rental_uuid: Uuid = db.create_rental(rental.clone()).await.unwrap().unwrap();
+
+        sqlx::query(
+            "
+            UPDATE tbl_costumers
+            SET name = '   '
+            WHERE id = $1
+        ",
+        )
+        .bind(rental.costumer_uuid)
+        .execute(&db.pool)
+        .await
+        .unwrap();
+
+        let sql_result: ResultDB<Option<RentalWithCostumerAndBook>> =
+            db.get_rental(rental_uuid).await;
+
+        db.delete_rental(rental_uuid).await.unwrap();
+        db.delete_costumer(rental.costumer_uuid).await.unwrap();
+
+        assert!(matches!(sql_result, Err(sqlx::Error::Decode(_))));
+    }
+
     #[sqlx::test]
     async fn test_get_rental_not_found() {
         let db: Database = conn_db().await;
 
         let rental: Rental = create_rental().await;
 
-        let sql_result: Option<RentalWithCostumerAndBook> =
-            db.get_rental(rental.id.clone()).await.unwrap();
+        let sql_result: Option<RentalWithCostumerAndBook> = db.get_rental(rental.id).await.unwrap();
 
         assert!(sql_result.is_none());
     }
@@ -403,13 +1311,9 @@ mod tests {
 
         let rental: Rental = create_rental().await;
 
-        let rental_uuid: Uuid = db.create_rental(rental.clone()).await.unwrap();
+        let rental_uuid: Uuid = db.create_rental(rental.clone()).await.unwrap().unwrap();
 
-        let sql_result: Rental = db
-            .get_rental_raw(rental_uuid.clone())
-            .await
-            .unwrap()
-            .unwrap();
+        let sql_result: Rental = db.get_rental_raw(rental_uuid).await.unwrap().unwrap();
 
         assert_eq!(sql_result, rental);
     }
@@ -420,7 +1324,7 @@ mod tests {
 
         let rental: Rental = create_rental().await;
 
-        let sql_result: Option<Rental> = db.get_rental_raw(rental.id.clone()).await.unwrap();
+        let sql_result: Option<Rental> = db.get_rental_raw(rental.id).await.unwrap();
 
         assert!(sql_result.is_none());
     }
@@ -431,13 +1335,9 @@ mod tests {
 
         let rental: Rental = create_rental().await;
 
-        let rental_uuid: Uuid = db.create_rental(rental.clone()).await.unwrap();
+        let rental_uuid: Uuid = db.create_rental(rental.clone()).await.unwrap().unwrap();
 
-        let sql_result: Uuid = db
-            .get_rental_id(rental_uuid.clone())
-            .await
-            .unwrap()
-            .unwrap();
+        let sql_result: Uuid = db.get_rental_id(rental_uuid).await.unwrap().unwrap();
 
         assert_eq!(sql_result, rental_uuid);
     }
@@ -448,7 +1348,7 @@ mod tests {
 
         let rental: Rental = create_rental().await;
 
-        let sql_result: Option<Uuid> = db.get_rental_id(rental.id.clone()).await.unwrap();
+        let sql_result: Option<Uuid> = db.get_rental_id(rental.id).await.unwrap();
 
         assert!(sql_result.is_none());
     }
@@ -461,12 +1361,20 @@ mod tests {
 
         let token: QueryURL = QueryURL {
             token: "Nam".to_string(),
+            ..Default::default()
         };
 
-        db.create_rental(rental.clone()).await.unwrap();
+        db.create_rental(rental.clone()).await.unwrap().unwrap();
 
-        let sql_result: Vec<RentalWithCostumerAndBook> =
-            db.search_rentals(token.token).await.unwrap();
+        let sql_result: Vec<RentalWithCostumerAndBook> = db
+            .search_rentals(
+                token.token,
+                Default::default(),
+                Pagination::new(RENTAL_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
 
         assert!(sql_result.contains(&RentalWithCostumerAndBook {
             id: rental.id,
@@ -486,15 +1394,23 @@ mod tests {
 
         let token: QueryURL = QueryURL {
             token: "nAM".to_string(),
+            ..Default::default()
         };
 
-        db.create_rental(rental.clone()).await.unwrap();
+        db.create_rental(rental.clone()).await.unwrap().unwrap();
 
-        let sql_result: Vec<RentalWithCostumerAndBook> =
-            db.search_rentals(token.token).await.unwrap();
+        let sql_result: Vec<RentalWithCostumerAndBook> = db
+            .search_rentals(
+                token.token,
+                Default::default(),
+                Pagination::new(RENTAL_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
 
         assert!(sql_result.contains(&RentalWithCostumerAndBook {
-            id: rental.id.clone(),
+            id: rental.id,
             costumer_name: PersonName::try_from(DEFAULT_NAME.to_string()).unwrap(),
             book_name: BookName::try_from(DEFAULT_NAME.to_string()).unwrap(),
             borrowed_at: DEFAULT_BORROWED_DATE.unwrap(),
@@ -511,15 +1427,23 @@ mod tests {
 
         let token: QueryURL = QueryURL {
             token: "foo".to_string(),
+            ..Default::default()
         };
 
-        db.create_rental(rental.clone()).await.unwrap();
+        db.create_rental(rental.clone()).await.unwrap().unwrap();
 
-        let sql_result: Vec<RentalWithCostumerAndBook> =
-            db.search_rentals(token.token).await.unwrap();
+        let sql_result: Vec<RentalWithCostumerAndBook> = db
+            .search_rentals(
+                token.token,
+                Default::default(),
+                Pagination::new(RENTAL_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
 
         assert!(!sql_result.contains(&RentalWithCostumerAndBook {
-            id: rental.id.clone(),
+            id: rental.id,
             costumer_name: PersonName::try_from(DEFAULT_NAME.to_string()).unwrap(),
             book_name: BookName::try_from(DEFAULT_NAME.to_string()).unwrap(),
             borrowed_at: DEFAULT_BORROWED_DATE.unwrap(),
@@ -536,11 +1460,20 @@ mod tests {
 
         let token: QueryURL = QueryURL {
             token: String::from(rental.costumer_uuid),
+            ..Default::default()
         };
 
-        db.create_rental(rental.clone()).await.unwrap();
+        db.create_rental(rental.clone()).await.unwrap().unwrap();
 
-        let sql_result: Vec<Rental> = db.search_rentals_raw(token.token).await.unwrap();
+        let sql_result: Vec<Rental> = db
+            .search_rentals_raw(
+                token.token,
+                Default::default(),
+                Pagination::new(RENTAL_RAW_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
 
         assert!(sql_result.contains(&rental));
     }
@@ -552,12 +1485,21 @@ mod tests {
         let rental: Rental = create_rental().await;
 
         let token: QueryURL = QueryURL {
-            token: String::from(rental.book_uuid).to_ascii_uppercase(),
+            token: String::from(rental.copy_uuid).to_ascii_uppercase(),
+            ..Default::default()
         };
 
-        db.create_rental(rental.clone()).await.unwrap();
+        db.create_rental(rental.clone()).await.unwrap().unwrap();
 
-        let sql_result: Vec<Rental> = db.search_rentals_raw(token.token).await.unwrap();
+        let sql_result: Vec<Rental> = db
+            .search_rentals_raw(
+                token.token,
+                Default::default(),
+                Pagination::new(RENTAL_RAW_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
 
         assert!(sql_result.contains(&rental));
     }
@@ -570,11 +1512,20 @@ mod tests {
 
         let token: QueryURL = QueryURL {
             token: "foo".to_string(),
+            ..Default::default()
         };
 
-        db.create_rental(rental.clone()).await.unwrap();
+        db.create_rental(rental.clone()).await.unwrap().unwrap();
 
-        let sql_result: Vec<Rental> = db.search_rentals_raw(token.token).await.unwrap();
+        let sql_result: Vec<Rental> = db
+            .search_rentals_raw(
+                token.token,
+                Default::default(),
+                Pagination::new(RENTAL_RAW_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
 
         assert!(!sql_result.contains(&rental));
     }
@@ -585,11 +1536,11 @@ mod tests {
 
         let rental: Rental = create_rental().await;
 
-        let sql_rental_uuid: Uuid = db.create_rental(rental).await.unwrap();
+        let sql_rental_uuid: Uuid = db.create_rental(rental).await.unwrap().unwrap();
 
         let payload_update_rental: PayloadUpdateRental = PayloadUpdateRental {
-            id: sql_rental_uuid.clone(),
-            book_uuid: create_book().await.id,
+            id: sql_rental_uuid,
+            copy_uuid: create_copy().await.id,
             costumer_uuid: create_costumer().await.id,
             borrowed_at: DEFAULT_BORROWED_DATE.unwrap(),
             due_date: DEFAULT_DUE_DATE.unwrap(),
@@ -611,9 +1562,9 @@ mod tests {
 
         let rental: Rental = create_rental().await;
 
-        db.create_rental(rental.clone()).await.unwrap();
+        db.create_rental(rental.clone()).await.unwrap().unwrap();
 
-        let sql_result_before: Option<Uuid> = db.get_rental_id(rental.id.clone()).await.unwrap();
+        let sql_result_before: Option<Uuid> = db.get_rental_id(rental.id).await.unwrap();
 
         let sql_result_uuid: Uuid = db.delete_rental(rental.id).await.unwrap();
 
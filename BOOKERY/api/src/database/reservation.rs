@@ -0,0 +1,600 @@
+use sqlx::{postgres::PgRow, Postgres, Row, Transaction};
+use time::Date;
+use uuid::Uuid;
+
+use crate::database::{conn::Database, ResultDB};
+use shared::structs::reservation::{PayloadReservation, Reservation, ReservationStatus};
+
+fn reservation_from_row(row: PgRow) -> Reservation {
+    let status_parser: String = row.get("status");
+
+    let id: Uuid = row.get("id");
+    let book_uuid: Uuid = row.get("book_uuid");
+    let costumer_uuid: Uuid = row.get("costumer_uuid");
+    let status: ReservationStatus = ReservationStatus::try_from(status_parser).unwrap();
+    let reserved_at: Date = row.get("reserved_at");
+    let expires_at: Option<Date> = row.get("expires_at");
+
+    Reservation {
+        id,
+        book_uuid,
+        costumer_uuid,
+        status,
+        reserved_at,
+        expires_at,
+    }
+}
+
+impl Database {
+    pub async fn create_reservation(
+        &self,
+        reservation: PayloadReservation,
+    ) -> ResultDB<Reservation> {
+        let mut tx: Transaction<'_, Postgres> = self.pool.begin().await?;
+
+        let reservation_uuid: Uuid = sqlx::query(
+            "
+            INSERT INTO tbl_reservations (id, book_uuid, costumer_uuid)
+            VALUES ($1, $2, $3)
+            RETURNING id
+        ",
+        )
+        .bind(Uuid::new_v4())
+        .bind(reservation.book_uuid)
+        .bind(reservation.costumer_uuid)
+        .map(|row: PgRow| {
+            let uuid: Uuid = row.get("id");
+            uuid
+        })
+        .fetch_one(&mut *tx)
+        .await?;
+
+        self.sync_reservations(&mut tx, reservation.book_uuid)
+            .await?;
+
+        let reservation: Reservation = sqlx::query(
+            "
+            SELECT id, book_uuid, costumer_uuid, status, reserved_at, expires_at
+            FROM tbl_reservations
+            WHERE id = $1
+        ",
+        )
+        .bind(reservation_uuid)
+        .map(reservation_from_row)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(reservation)
+    }
+
+    pub async fn get_reservation(&self, reservation_uuid: Uuid) -> ResultDB<Option<Reservation>> {
+        let reservation: Option<Reservation> = sqlx::query(
+            "
+            SELECT id, book_uuid, costumer_uuid, status, reserved_at, expires_at
+            FROM tbl_reservations
+            WHERE id = $1
+        ",
+        )
+        .bind(reservation_uuid)
+        .map(reservation_from_row)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(reservation)
+    }
+
+    pub async fn list_book_reservations(&self, book_uuid: Uuid) -> ResultDB<Vec<Reservation>> {
+        let mut tx: Transaction<'_, Postgres> = self.pool.begin().await?;
+
+        self.sync_reservations(&mut tx, book_uuid).await?;
+
+        let reservations_vec: Vec<Reservation> = sqlx::query(
+            "
+            SELECT id, book_uuid, costumer_uuid, status, reserved_at, expires_at
+            FROM tbl_reservations
+            WHERE book_uuid = $1 AND status IN ('waiting', 'ready')
+            ORDER BY queue_order
+        ",
+        )
+        .bind(book_uuid)
+        .map(reservation_from_row)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(reservations_vec)
+    }
+
+    pub async fn cancel_reservation(
+        &self,
+        reservation_uuid: Uuid,
+    ) -> ResultDB<Option<Reservation>> {
+        let mut tx: Transaction<'_, Postgres> = self.pool.begin().await?;
+
+        let reservation: Option<Reservation> = sqlx::query(
+            "
+            UPDATE tbl_reservations
+            SET status = 'cancelled'
+            WHERE id = $1 AND status IN ('waiting', 'ready')
+            RETURNING id, book_uuid, costumer_uuid, status, reserved_at, expires_at
+        ",
+        )
+        .bind(reservation_uuid)
+        .map(reservation_from_row)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let reservation: Reservation = match reservation {
+            Some(reservation) => reservation,
+            None => return Ok(None),
+        };
+
+        self.sync_reservations(&mut tx, reservation.book_uuid)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(reservation))
+    }
+
+    pub async fn count_reservations(&self) -> ResultDB<i64> {
+        let total: i64 = sqlx::query_scalar(
+            "
+            SELECT count(*) as total
+            FROM tbl_reservations
+        ",
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(total)
+    }
+
+    pub async fn sync_reservations(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        book_uuid: Uuid,
+    ) -> ResultDB<()> {
+        sqlx::query(
+            "
+            UPDATE tbl_reservations
+            SET status = 'expired'
+            WHERE book_uuid = $1 AND status = 'ready' AND expires_at < CURRENT_DATE
+        ",
+        )
+        .bind(book_uuid)
+        .execute(&mut **tx)
+        .await?;
+
+        sqlx::query(
+            "
+            UPDATE tbl_reservations
+            SET status = 'ready', expires_at = CURRENT_DATE + $2
+            WHERE id IN (
+                SELECT id
+                FROM tbl_reservations
+                WHERE book_uuid = $1 AND status = 'waiting'
+                ORDER BY queue_order
+                LIMIT GREATEST(0, (
+                    SELECT count(*)
+                    FROM tbl_copies cp
+                    WHERE cp.book_uuid = $1 AND cp.status = 'available'
+                    AND NOT EXISTS (
+                        SELECT 1
+                        FROM tbl_rentals r
+                        WHERE r.copy_uuid = cp.id AND r.returned_at IS NULL
+                    )
+                ) - (
+                    SELECT count(*)
+                    FROM tbl_reservations
+                    WHERE book_uuid = $1 AND status = 'ready'
+                ))
+            )
+        ",
+        )
+        .bind(book_uuid)
+        .bind(self.policy.reservation.hold_days as i32)
+        .execute(&mut **tx)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn claim_reservation(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        book_uuid: Uuid,
+        costumer_uuid: Uuid,
+    ) -> ResultDB<bool> {
+        let claimed: Option<Uuid> = sqlx::query(
+            "
+            UPDATE tbl_reservations
+            SET status = 'fulfilled'
+            WHERE book_uuid = $1 AND costumer_uuid = $2 AND status = 'ready'
+            RETURNING id
+        ",
+        )
+        .bind(book_uuid)
+        .bind(costumer_uuid)
+        .map(|row: PgRow| {
+            let uuid: Uuid = row.get("id");
+            uuid
+        })
+        .fetch_optional(&mut **tx)
+        .await?;
+
+        if claimed.is_some() {
+            return Ok(true);
+        }
+
+        let unclaimed: bool = sqlx::query_scalar(
+            "
+            SELECT (
+                SELECT count(*)
+                FROM tbl_copies cp
+                WHERE cp.book_uuid = $1 AND cp.status = 'available'
+                AND NOT EXISTS (
+                    SELECT 1
+                    FROM tbl_rentals r
+                    WHERE r.copy_uuid = cp.id AND r.returned_at IS NULL
+                )
+            ) >= (
+                SELECT count(*)
+                FROM tbl_reservations
+                WHERE book_uuid = $1 AND status = 'ready'
+            )
+        ",
+        )
+        .bind(book_uuid)
+        .fetch_one(&mut **tx)
+        .await?;
+
+        if !unclaimed {
+            return Ok(false);
+        }
+
+        sqlx::query(
+            "
+            UPDATE tbl_reservations
+            SET status = 'fulfilled'
+            WHERE book_uuid = $1 AND costumer_uuid = $2 AND status = 'waiting'
+        ",
+        )
+        .bind(book_uuid)
+        .bind(costumer_uuid)
+        .execute(&mut **tx)
+        .await?;
+
+        Ok(true)
+    }
+
+    pub async fn reserved_by_other(&self, copy_uuid: Uuid, costumer_uuid: Uuid) -> ResultDB<bool> {
+        let reserved: bool = sqlx::query_scalar(
+            "
+            SELECT EXISTS (
+                SELECT 1
+                FROM tbl_reservations rs
+                JOIN tbl_copies cp
+                ON rs.book_uuid = cp.book_uuid
+                WHERE cp.id = $1 AND rs.costumer_uuid <> $2 AND rs.status IN ('waiting', 'ready')
+            )
+        ",
+        )
+        .bind(copy_uuid)
+        .bind(costumer_uuid)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(reserved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::env::var;
+
+    use time::{error::ComponentRange, Duration, Month};
+
+    use crate::database::rental::CheckoutRefusal;
+
+    use shared::structs::{
+        author::{Author, PayloadAuthor},
+        book::{Book, PayloadBook, PayloadContributor},
+        copy::{BookCopy, PayloadBookCopy},
+        costumer::{Costumer, PayloadCostumer, PayloadDocument},
+        publisher::{PayloadPublisher, Publisher},
+        rental::{PayloadRental, Rental, ReturnedRental},
+    };
+
+    const DEFAULT_NAME: &str = "Name";
+    const DEFAULT_EDITOR: &str = "Editor";
+    const DEFAULT_SHELF_LOCATION: &str = "A-01";
+    const DEFAULT_DATE: Result<Date, ComponentRange> =
+        Date::from_calendar_date(2000, Month::January, 1);
+
+    fn random_document() -> PayloadDocument {
+        PayloadDocument {
+            kind: "rg".to_string(),
+            value: format!("{:014}", Uuid::new_v4().as_u128() % 10u128.pow(14)),
+        }
+    }
+
+    async fn conn_db() -> Database {
+        let db_url: String = var("DATABASE_URL").unwrap();
+        Database::conn(&db_url).await
+    }
+
+    async fn create_book() -> Uuid {
+        let db: Database = conn_db().await;
+
+        let payload_author: PayloadAuthor = PayloadAuthor {
+            name: DEFAULT_NAME.to_string(),
+            born: DEFAULT_DATE.unwrap(),
+        };
+
+        let author_uuid: Uuid = db
+            .create_author(Author::create(payload_author).unwrap())
+            .await
+            .unwrap();
+
+        let payload_publisher: PayloadPublisher = PayloadPublisher {
+            name: format!("{DEFAULT_EDITOR} {}", Uuid::new_v4().simple()),
+        };
+
+        let publisher_uuid: Uuid = db
+            .create_publisher(Publisher::create(payload_publisher).unwrap())
+            .await
+            .unwrap();
+
+        let payload_book: PayloadBook = PayloadBook {
+            name: DEFAULT_NAME.to_string(),
+            isbn: None,
+            contributors: vec![PayloadContributor {
+                author_uuid,
+                role: "author".to_string(),
+            }],
+            subjects: vec![],
+            publisher_uuid,
+            release: DEFAULT_DATE.unwrap(),
+        };
+
+        db.create_book(Book::create(payload_book).unwrap())
+            .await
+            .unwrap()
+    }
+
+    async fn create_copy(book_uuid: Uuid) -> Uuid {
+        let db: Database = conn_db().await;
+
+        let payload_copy: PayloadBookCopy = PayloadBookCopy {
+            book_uuid,
+            barcode: Uuid::new_v4().simple().to_string(),
+            condition: "good".to_string(),
+            shelf_location: DEFAULT_SHELF_LOCATION.to_string(),
+            status: "available".to_string(),
+        };
+
+        db.create_copy(BookCopy::create(payload_copy).unwrap())
+            .await
+            .unwrap()
+    }
+
+    async fn create_costumer() -> Uuid {
+        let db: Database = conn_db().await;
+
+        let payload_costumer: PayloadCostumer = PayloadCostumer {
+            name: DEFAULT_NAME.to_string(),
+            document: random_document(),
+            born: DEFAULT_DATE.unwrap(),
+        };
+
+        db.create_costumer(Costumer::create(payload_costumer).unwrap())
+            .await
+            .unwrap()
+    }
+
+    async fn reserve(db: &Database, book_uuid: Uuid, costumer_uuid: Uuid) -> Reservation {
+        db.create_reservation(PayloadReservation {
+            book_uuid,
+            costumer_uuid,
+        })
+        .await
+        .unwrap()
+    }
+
+    #[sqlx::test]
+    async fn test_create_reservation() {
+        let db: Database = conn_db().await;
+
+        let book_uuid: Uuid = create_book().await;
+        let copy_uuid: Uuid = create_copy(book_uuid).await;
+        let costumer_uuid: Uuid = create_costumer().await;
+
+        db.checkout_rental(create_costumer().await, copy_uuid)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let reservation: Reservation = reserve(&db, book_uuid, costumer_uuid).await;
+
+        assert_eq!(reservation.book_uuid, book_uuid);
+        assert_eq!(reservation.costumer_uuid, costumer_uuid);
+        assert_eq!(reservation.status, ReservationStatus::Waiting);
+        assert_eq!(reservation.expires_at, None);
+
+        assert_eq!(
+            db.get_reservation(reservation.id).await.unwrap(),
+            Some(reservation)
+        );
+
+        assert!(db
+            .create_reservation(PayloadReservation {
+                book_uuid,
+                costumer_uuid,
+            })
+            .await
+            .is_err());
+    }
+
+    #[sqlx::test]
+    async fn test_create_reservation_available_book() {
+        let db: Database = conn_db().await;
+
+        let book_uuid: Uuid = create_book().await;
+        create_copy(book_uuid).await;
+
+        let reservation: Reservation = reserve(&db, book_uuid, create_costumer().await).await;
+
+        assert_eq!(reservation.status, ReservationStatus::Ready);
+        assert!(reservation.expires_at.is_some());
+    }
+
+    #[sqlx::test]
+    async fn test_reservation_queue() {
+        let db: Database = conn_db().await;
+
+        let book_uuid: Uuid = create_book().await;
+        let copy_uuid: Uuid = create_copy(book_uuid).await;
+        let first_costumer_uuid: Uuid = create_costumer().await;
+        let second_costumer_uuid: Uuid = create_costumer().await;
+
+        let rental: Rental = db
+            .checkout_rental(create_costumer().await, copy_uuid)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let first: Reservation = reserve(&db, book_uuid, first_costumer_uuid).await;
+        let second: Reservation = reserve(&db, book_uuid, second_costumer_uuid).await;
+
+        assert!(db
+            .reserved_by_other(copy_uuid, rental.costumer_uuid)
+            .await
+            .unwrap());
+        assert_eq!(db.renew_rental(rental.id).await.unwrap(), None);
+
+        let returned_rental: ReturnedRental = db.return_rental(rental.id).await.unwrap().unwrap();
+
+        let queue: Vec<Reservation> = db.list_book_reservations(book_uuid).await.unwrap();
+
+        assert_eq!(
+            queue
+                .iter()
+                .map(|reservation: &Reservation| reservation.id)
+                .collect::<Vec<Uuid>>(),
+            vec![first.id, second.id]
+        );
+        assert_eq!(queue[0].status, ReservationStatus::Ready);
+        assert_eq!(
+            queue[0].expires_at,
+            Some(
+                returned_rental.rental.returned_at.unwrap()
+                    + Duration::days(db.policy.reservation.hold_days)
+            )
+        );
+        assert_eq!(queue[1].status, ReservationStatus::Waiting);
+
+        assert_eq!(
+            db.checkout_rental(second_costumer_uuid, copy_uuid)
+                .await
+                .unwrap(),
+            Err(CheckoutRefusal::Reserved)
+        );
+
+        db.checkout_rental(first_costumer_uuid, copy_uuid)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            db.get_reservation(first.id).await.unwrap().unwrap().status,
+            ReservationStatus::Fulfilled
+        );
+        assert_eq!(
+            db.list_book_reservations(book_uuid).await.unwrap(),
+            vec![second]
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_create_rental_reserved() {
+        let db: Database = conn_db().await;
+
+        let book_uuid: Uuid = create_book().await;
+        let copy_uuid: Uuid = create_copy(book_uuid).await;
+        let costumer_uuid: Uuid = create_costumer().await;
+
+        let reservation: Reservation = reserve(&db, book_uuid, costumer_uuid).await;
+
+        let rental: Rental = Rental::create(PayloadRental {
+            costumer_uuid: create_costumer().await,
+            copy_uuid,
+            borrowed_at: DEFAULT_DATE.unwrap(),
+            due_date: DEFAULT_DATE.unwrap(),
+        })
+        .unwrap();
+
+        assert_eq!(
+            db.create_rental(rental).await.unwrap(),
+            Err(CheckoutRefusal::Reserved)
+        );
+        assert!(db.book_loans(book_uuid).await.unwrap().is_empty());
+
+        let rental: Rental = Rental::create(PayloadRental {
+            costumer_uuid,
+            copy_uuid,
+            borrowed_at: DEFAULT_DATE.unwrap(),
+            due_date: DEFAULT_DATE.unwrap(),
+        })
+        .unwrap();
+
+        db.create_rental(rental).await.unwrap().unwrap();
+
+        assert_eq!(
+            db.get_reservation(reservation.id)
+                .await
+                .unwrap()
+                .unwrap()
+                .status,
+            ReservationStatus::Fulfilled
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_cancel_reservation() {
+        let db: Database = conn_db().await;
+
+        let book_uuid: Uuid = create_book().await;
+        create_copy(book_uuid).await;
+
+        let first: Reservation = reserve(&db, book_uuid, create_costumer().await).await;
+        let second: Reservation = reserve(&db, book_uuid, create_costumer().await).await;
+
+        assert_eq!(first.status, ReservationStatus::Ready);
+        assert_eq!(second.status, ReservationStatus::Waiting);
+
+        let cancelled: Reservation = db.cancel_reservation(first.id).await.unwrap().unwrap();
+
+        assert_eq!(cancelled.status, ReservationStatus::Cancelled);
+        assert_eq!(
+            db.get_reservation(second.id).await.unwrap().unwrap().status,
+            ReservationStatus::Ready
+        );
+
+        assert_eq!(db.cancel_reservation(first.id).await.unwrap(), None);
+    }
+
+    #[sqlx::test]
+    async fn test_count_reservations() {
+        let db: Database = conn_db().await;
+
+        reserve(&db, create_book().await, create_costumer().await).await;
+
+        assert!(db.count_reservations().await.unwrap() >= 1);
+    }
+}
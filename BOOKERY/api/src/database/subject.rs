@@ -0,0 +1,436 @@
+use sqlx::{postgres::PgRow, Postgres, QueryBuilder, Row};
+use uuid::Uuid;
+
+use crate::database::{conn::Database, OrderColumns, Pagination, ResultDB};
+use shared::structs::{page::Page, subject::Subject, SubjectName};
+
+pub const SUBJECT_ORDER_COLUMNS: OrderColumns = &[("name", "name")];
+
+fn push_subject_filters(query: &mut QueryBuilder<'_, Postgres>, token: &str) {
+    query
+        .push(" WHERE name ILIKE ")
+        .push_bind(format!("%{token}%"));
+}
+
+fn subject_from_row(row: PgRow) -> Subject {
+    let name_parser: String = row.get("name");
+
+    let id: Uuid = row.get("id");
+    let name: SubjectName = SubjectName::try_from(name_parser).unwrap();
+    let parent_uuid: Option<Uuid> = row.get("parent_uuid");
+
+    Subject {
+        id,
+        name,
+        parent_uuid,
+    }
+}
+
+impl Database {
+    pub async fn create_subject(&self, subject: Subject) -> ResultDB<Uuid> {
+        let subject_uuid: Uuid = sqlx::query(
+            "
+            INSERT INTO tbl_subjects (id, name, parent_uuid)
+            VALUES ($1, $2, $3)
+            RETURNING id
+        ",
+        )
+        .bind(subject.id)
+        .bind(subject.name.as_str())
+        .bind(subject.parent_uuid)
+        .map(|row: PgRow| {
+            let uuid: Uuid = row.get("id");
+            uuid
+        })
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(subject_uuid)
+    }
+
+    pub async fn get_subject(&self, subject_uuid: Uuid) -> ResultDB<Option<Subject>> {
+        let subject: Option<Subject> = sqlx::query(
+            "
+            SELECT id, name, parent_uuid
+            FROM tbl_subjects
+            WHERE id = $1
+        ",
+        )
+        .bind(subject_uuid)
+        .map(subject_from_row)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(subject)
+    }
+
+    pub async fn get_subject_id(&self, subject_uuid: Uuid) -> ResultDB<Option<Uuid>> {
+        let subject_uuid: Option<Uuid> = sqlx::query(
+            "
+            SELECT id
+            FROM tbl_subjects
+            WHERE id = $1
+        ",
+        )
+        .bind(subject_uuid)
+        .map(|row: PgRow| {
+            let id: Uuid = row.get("id");
+            id
+        })
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(subject_uuid)
+    }
+
+    pub async fn list_subject_children(&self, subject_uuid: Uuid) -> ResultDB<Vec<Subject>> {
+        let subjects_vec: Vec<Subject> = sqlx::query(
+            "
+            SELECT id, name, parent_uuid
+            FROM tbl_subjects
+            WHERE parent_uuid = $1
+            ORDER BY name, id
+        ",
+        )
+        .bind(subject_uuid)
+        .map(subject_from_row)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(subjects_vec)
+    }
+
+    pub async fn subject_descends_from(
+        &self,
+        subject_uuid: Uuid,
+        ancestor_uuid: Uuid,
+    ) -> ResultDB<bool> {
+        let descends: bool = sqlx::query_scalar(
+            "
+            WITH RECURSIVE ancestors AS (
+                SELECT id, parent_uuid
+                FROM tbl_subjects
+                WHERE id = $1
+                UNION
+                SELECT s.id, s.parent_uuid
+                FROM tbl_subjects s
+                JOIN ancestors a
+                ON s.id = a.parent_uuid
+            )
+            SELECT EXISTS (SELECT 1 FROM ancestors WHERE id = $2)
+        ",
+        )
+        .bind(subject_uuid)
+        .bind(ancestor_uuid)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(descends)
+    }
+
+    pub async fn search_subjects(
+        &self,
+        token: String,
+        pagination: Pagination,
+    ) -> ResultDB<Page<Subject>> {
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "
+            SELECT id, name, parent_uuid
+            FROM tbl_subjects
+        ",
+        );
+        push_subject_filters(&mut query, &token);
+        pagination.push_order(&mut query);
+
+        let subjects_vec: Vec<Subject> = query
+            .build()
+            .map(subject_from_row)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut count_query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "
+            SELECT count(*) as total
+            FROM tbl_subjects
+        ",
+        );
+        push_subject_filters(&mut count_query, &token);
+
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(pagination.page(subjects_vec, total))
+    }
+
+    pub async fn update_subject(&self, subject: Subject) -> ResultDB<Uuid> {
+        let subject_uuid: Uuid = sqlx::query(
+            "
+            UPDATE tbl_subjects
+            SET name = $1, parent_uuid = $2
+            WHERE id = $3
+            RETURNING id
+        ",
+        )
+        .bind(subject.name.as_str())
+        .bind(subject.parent_uuid)
+        .bind(subject.id)
+        .map(|row: PgRow| {
+            let uuid: Uuid = row.get("id");
+            uuid
+        })
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(subject_uuid)
+    }
+
+    pub async fn delete_subject(&self, subject_uuid: Uuid) -> ResultDB<Uuid> {
+        let subject_uuid: Uuid = sqlx::query(
+            "
+            DELETE FROM tbl_subjects
+            WHERE id = $1
+            RETURNING id
+        ",
+        )
+        .bind(subject_uuid)
+        .map(|row: PgRow| {
+            let uuid: Uuid = row.get("id");
+            uuid
+        })
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(subject_uuid)
+    }
+
+    pub async fn count_subjects(&self) -> ResultDB<i64> {
+        let total: i64 = sqlx::query_scalar(
+            "
+            SELECT count(*) as total
+            FROM tbl_subjects
+        ",
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::env::var;
+
+    use shared::structs::subject::{PayloadSubject, PayloadUpdateSubject};
+
+    async fn conn_db() -> Database {
+        let db_url: String = var("DATABASE_URL").unwrap();
+        Database::conn(&db_url).await
+    }
+
+    fn create_subject(parent_uuid: Option<Uuid>) -> Subject {
+        let payload_subject: PayloadSubject = PayloadSubject {
+            name: format!("Subject {}", Uuid::new_v4().simple()),
+            parent_uuid,
+        };
+
+        Subject::create(payload_subject).unwrap()
+    }
+
+    #[sqlx::test]
+    async fn test_create_subject() {
+        let db: Database = conn_db().await;
+
+        let subject: Subject = create_subject(None);
+
+        let subject_uuid: Uuid = subject.id;
+
+        let sql_result: Uuid = db.create_subject(subject).await.unwrap();
+
+        assert_eq!(sql_result, subject_uuid);
+    }
+
+    #[sqlx::test]
+    async fn test_create_subject_duplicated_name() {
+        let db: Database = conn_db().await;
+
+        let subject: Subject = create_subject(None);
+
+        db.create_subject(subject.clone()).await.unwrap();
+
+        let payload_subject: PayloadSubject = PayloadSubject {
+            name: subject.name.as_str().to_uppercase(),
+            parent_uuid: None,
+        };
+
+        let sql_result: sqlx::Error = db
+            .create_subject(Subject::create(payload_subject).unwrap())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            sql_result.as_database_error().unwrap().constraint(),
+            Some("idx_subjects_name")
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_get_subject_found() {
+        let db: Database = conn_db().await;
+
+        let parent: Subject = create_subject(None);
+        db.create_subject(parent.clone()).await.unwrap();
+
+        let subject: Subject = create_subject(Some(parent.id));
+        let subject_uuid: Uuid = db.create_subject(subject.clone()).await.unwrap();
+
+        let sql_result: Subject = db.get_subject(subject_uuid).await.unwrap().unwrap();
+
+        assert_eq!(sql_result, subject);
+    }
+
+    #[sqlx::test]
+    async fn test_get_subject_not_found() {
+        let db: Database = conn_db().await;
+
+        let sql_result: Option<Subject> = db.get_subject(Uuid::new_v4()).await.unwrap();
+
+        assert!(sql_result.is_none());
+    }
+
+    #[sqlx::test]
+    async fn test_list_subject_children() {
+        let db: Database = conn_db().await;
+
+        let parent: Subject = create_subject(None);
+        db.create_subject(parent.clone()).await.unwrap();
+
+        let child: Subject = create_subject(Some(parent.id));
+        db.create_subject(child.clone()).await.unwrap();
+
+        let grandchild: Subject = create_subject(Some(child.id));
+        db.create_subject(grandchild).await.unwrap();
+
+        let sql_result: Vec<Subject> = db.list_subject_children(parent.id).await.unwrap();
+
+        assert_eq!(sql_result, vec![child]);
+    }
+
+    #[sqlx::test]
+    async fn test_subject_descends_from() {
+        let db: Database = conn_db().await;
+
+        let parent: Subject = create_subject(None);
+        db.create_subject(parent.clone()).await.unwrap();
+
+        let child: Subject = create_subject(Some(parent.id));
+        db.create_subject(child.clone()).await.unwrap();
+
+        let grandchild: Subject = create_subject(Some(child.id));
+        db.create_subject(grandchild.clone()).await.unwrap();
+
+        assert!(db
+            .subject_descends_from(grandchild.id, parent.id)
+            .await
+            .unwrap());
+        assert!(!db
+            .subject_descends_from(parent.id, grandchild.id)
+            .await
+            .unwrap());
+    }
+
+    #[sqlx::test]
+    async fn test_search_subjects_case_insensitive_found() {
+        let db: Database = conn_db().await;
+
+        let subject: Subject = create_subject(None);
+
+        db.create_subject(subject.clone()).await.unwrap();
+
+        let sql_result: Vec<Subject> = db
+            .search_subjects(
+                subject.name.as_str().to_lowercase(),
+                Pagination::new(SUBJECT_ORDER_COLUMNS),
+            )
+            .await
+            .unwrap()
+            .items;
+
+        assert_eq!(sql_result, vec![subject]);
+    }
+
+    #[sqlx::test]
+    async fn test_update_subject() {
+        let db: Database = conn_db().await;
+
+        let parent: Subject = create_subject(None);
+        db.create_subject(parent.clone()).await.unwrap();
+
+        let subject: Subject = create_subject(None);
+        let sql_subject_uuid: Uuid = db.create_subject(subject).await.unwrap();
+
+        let payload_update_subject: PayloadUpdateSubject = PayloadUpdateSubject {
+            id: sql_subject_uuid,
+            name: format!("Subject {}", Uuid::new_v4().simple()),
+            parent_uuid: Some(parent.id),
+        };
+
+        let updated_subject: Subject = Subject::parse(payload_update_subject).unwrap();
+
+        db.update_subject(updated_subject.clone()).await.unwrap();
+
+        let sql_result: Subject = db.get_subject(sql_subject_uuid).await.unwrap().unwrap();
+
+        assert_eq!(sql_result, updated_subject);
+    }
+
+    #[sqlx::test]
+    async fn test_delete_subject() {
+        let db: Database = conn_db().await;
+
+        let subject: Subject = create_subject(None);
+
+        db.create_subject(subject.clone()).await.unwrap();
+
+        let sql_result_before: Option<Uuid> = db.get_subject_id(subject.id).await.unwrap();
+
+        let sql_result_uuid: Uuid = db.delete_subject(subject.id).await.unwrap();
+
+        let sql_result_after: Option<Uuid> = db.get_subject_id(sql_result_uuid).await.unwrap();
+
+        assert!(sql_result_before.is_some());
+        assert!(sql_result_after.is_none());
+    }
+
+    #[sqlx::test]
+    async fn test_delete_subject_with_children() {
+        let db: Database = conn_db().await;
+
+        let parent: Subject = create_subject(None);
+        db.create_subject(parent.clone()).await.unwrap();
+
+        db.create_subject(create_subject(Some(parent.id)))
+            .await
+            .unwrap();
+
+        let sql_result: sqlx::Error = db.delete_subject(parent.id).await.unwrap_err();
+
+        assert_eq!(
+            sql_result.as_database_error().unwrap().code().as_deref(),
+            Some("23503")
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_count_subjects() {
+        let db: Database = conn_db().await;
+
+        let sql_result: i64 = db.count_subjects().await.unwrap();
+
+        assert!(sql_result >= 0);
+    }
+}
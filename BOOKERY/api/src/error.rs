@@ -0,0 +1,367 @@
+use axum::{
+    extract::{
+        path::ErrorKind,
+        rejection::{JsonRejection, PathRejection, QueryRejection},
+    },
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::error::Error;
+use uuid::Uuid;
+
+use crate::database::rental::CheckoutRefusal;
+use crate::policy::BorrowingRefusal;
+use shared::structs::FieldError;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct FieldErrorBody {
+    pub code: String,
+    pub field: String,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ErrorBody {
+    pub code: String,
+    pub field: Option<String>,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FieldErrorBody>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub existing_id: Option<Uuid>,
+}
+
+impl ErrorBody {
+    fn new(code: &str, message: &str) -> Self {
+        Self {
+            code: code.to_string(),
+            field: None,
+            message: message.to_string(),
+            errors: vec![],
+            existing_id: None,
+        }
+    }
+}
+
+impl From<&FieldError> for FieldErrorBody {
+    fn from(value: &FieldError) -> Self {
+        Self {
+            code: value.error.code().to_string(),
+            field: value.field.to_string(),
+            message: value.error.message().to_string(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ApiError {
+    Validation(Vec<FieldError>),
+    InvalidParameter(&'static str, String),
+    NotFound(&'static str),
+    Conflict(&'static str, &'static str),
+    Duplicate(&'static str, &'static str, Uuid),
+    Rejection(StatusCode, &'static str, Option<String>, String),
+    Database(Error),
+}
+
+impl From<Vec<FieldError>> for ApiError {
+    fn from(value: Vec<FieldError>) -> Self {
+        ApiError::Validation(value)
+    }
+}
+
+impl From<Error> for ApiError {
+    fn from(value: Error) -> Self {
+        ApiError::Database(value)
+    }
+}
+
+impl From<JsonRejection> for ApiError {
+    fn from(value: JsonRejection) -> Self {
+        let code: &'static str = match value {
+            JsonRejection::JsonSyntaxError(_) => "malformed_body",
+            JsonRejection::MissingJsonContentType(_) => "missing_content_type",
+            _ => "invalid_body",
+        };
+
+        ApiError::Rejection(value.status(), code, None, value.body_text())
+    }
+}
+
+impl From<QueryRejection> for ApiError {
+    fn from(value: QueryRejection) -> Self {
+        ApiError::Rejection(value.status(), "invalid_query", None, value.body_text())
+    }
+}
+
+impl From<PathRejection> for ApiError {
+    fn from(value: PathRejection) -> Self {
+        let field: Option<String> = match &value {
+            PathRejection::FailedToDeserializePathParams(error) => match error.kind() {
+                ErrorKind::ParseErrorAtKey { key, .. }
+                | ErrorKind::InvalidUtf8InPathParam { key } => Some(key.clone()),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        ApiError::Rejection(value.status(), "invalid_path", field, value.body_text())
+    }
+}
+
+impl From<BorrowingRefusal> for ApiError {
+    fn from(value: BorrowingRefusal) -> Self {
+        match value {
+            BorrowingRefusal::Blocked => {
+                ApiError::Conflict("costumer_blocked", "Costumer is blocked")
+            }
+            BorrowingRefusal::RentalLimit => ApiError::Conflict(
+                "rental_limit_reached",
+                "Costumer reached the maximum number of open rentals",
+            ),
+            BorrowingRefusal::OutstandingFees => {
+                ApiError::Conflict("outstanding_fees", "Costumer owes more fees than allowed")
+            }
+        }
+    }
+}
+
+impl From<CheckoutRefusal> for ApiError {
+    fn from(value: CheckoutRefusal) -> Self {
+        match value {
+            CheckoutRefusal::CostumerNotFound => ApiError::NotFound("Costumer"),
+            CheckoutRefusal::Borrowing(refusal) => refusal.into(),
+            CheckoutRefusal::CopyNotFound => ApiError::NotFound("Copy"),
+            CheckoutRefusal::CopyNotLendable => {
+                ApiError::Conflict("copy_not_lendable", "Copy is not available for lending")
+            }
+            CheckoutRefusal::Reserved => {
+                ApiError::Conflict("copy_reserved", "Book is reserved for another costumer")
+            }
+        }
+    }
+}
+
+impl ApiError {
+    fn status_and_body(&self) -> (StatusCode, ErrorBody) {
+        match self {
+            ApiError::Validation(field_errors) => {
+                let errors: Vec<FieldErrorBody> =
+                    field_errors.iter().map(FieldErrorBody::from).collect();
+
+                let body: ErrorBody = match errors.first() {
+                    Some(first) => ErrorBody {
+                        code: first.code.clone(),
+                        field: Some(first.field.clone()),
+                        message: first.message.clone(),
+                        errors,
+                        existing_id: None,
+                    },
+                    None => ErrorBody::new("validation_failed", "Payload failed validation"),
+                };
+
+                (StatusCode::UNPROCESSABLE_ENTITY, body)
+            }
+            ApiError::InvalidParameter(parameter, message) => (
+                StatusCode::BAD_REQUEST,
+                ErrorBody {
+                    code: "invalid_parameter".to_string(),
+                    field: Some(parameter.to_string()),
+                    message: message.clone(),
+                    errors: vec![],
+                    existing_id: None,
+                },
+            ),
+            ApiError::NotFound(entity) => (
+                StatusCode::NOT_FOUND,
+                ErrorBody::new("not_found", &format!("{entity} not found")),
+            ),
+            ApiError::Conflict(code, message) => {
+                (StatusCode::CONFLICT, ErrorBody::new(code, message))
+            }
+            ApiError::Duplicate(code, message, existing_id) => (
+                StatusCode::CONFLICT,
+                ErrorBody {
+                    existing_id: Some(*existing_id),
+                    ..ErrorBody::new(code, message)
+                },
+            ),
+            ApiError::Rejection(status, code, field, message) => (
+                *status,
+                ErrorBody {
+                    field: field.clone(),
+                    ..ErrorBody::new(code, message)
+                },
+            ),
+            ApiError::Database(Error::RowNotFound) => (
+                StatusCode::NOT_FOUND,
+                ErrorBody::new("not_found", "Record not found"),
+            ),
+            ApiError::Database(Error::Database(db_error)) => match db_error.code().as_deref() {
+                Some("23503") => (
+                    StatusCode::CONFLICT,
+                    ErrorBody::new("foreign_key_violation", db_error.message()),
+                ),
+                Some("23505") if db_error.constraint() == Some("idx_rentals_open_copy") => (
+                    StatusCode::CONFLICT,
+                    ErrorBody::new("copy_unavailable", "Copy already has an open rental"),
+                ),
+                Some("23505") if db_error.constraint() == Some("idx_reservations_active") => (
+                    StatusCode::CONFLICT,
+                    ErrorBody::new("already_reserved", "Costumer already reserved this book"),
+                ),
+                Some("23505") if db_error.constraint() == Some("idx_publishers_name") => (
+                    StatusCode::CONFLICT,
+                    ErrorBody::new("publisher_exists", "Publisher already registered"),
+                ),
+                Some("23505") if db_error.constraint() == Some("idx_books_isbn") => (
+                    StatusCode::CONFLICT,
+                    ErrorBody::new("isbn_exists", "ISBN already registered"),
+                ),
+                Some("23505") if db_error.constraint() == Some("idx_subjects_name") => (
+                    StatusCode::CONFLICT,
+                    ErrorBody::new("subject_exists", "Subject already registered"),
+                ),
+                Some("23505") if db_error.constraint() == Some("idx_costumers_document") => (
+                    StatusCode::CONFLICT,
+                    ErrorBody::new("document_exists", "Document already registered"),
+                ),
+                Some("23505") => (
+                    StatusCode::CONFLICT,
+                    ErrorBody::new("unique_violation", db_error.message()),
+                ),
+                _ => internal_error(),
+            },
+            ApiError::Database(_) => internal_error(),
+        }
+    }
+}
+
+fn internal_error() -> (StatusCode, ErrorBody) {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        ErrorBody::new("database_error", "Internal database error"),
+    )
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, body): (StatusCode, ErrorBody) = self.status_and_body();
+
+        (status, Json(body)).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use shared::structs::ConversionError;
+
+    #[test]
+    fn test_validation_error_body() {
+        let api_error: ApiError = vec![ConversionError::TokenTooLong.on("name")].into();
+
+        let (status, body): (StatusCode, ErrorBody) = api_error.status_and_body();
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            body,
+            ErrorBody {
+                code: "token_too_long".to_string(),
+                field: Some("name".to_string()),
+                message: ConversionError::TokenTooLong.message().to_string(),
+                errors: vec![FieldErrorBody {
+                    code: "token_too_long".to_string(),
+                    field: "name".to_string(),
+                    message: ConversionError::TokenTooLong.message().to_string(),
+                }],
+                existing_id: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_validation_error_body_every_field() {
+        let api_error: ApiError = vec![
+            ConversionError::TokenTooLong.on("name"),
+            ConversionError::InvalidType.on("document"),
+        ]
+        .into();
+
+        let (status, body): (StatusCode, ErrorBody) = api_error.status_and_body();
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body.field, Some("name".to_string()));
+        assert_eq!(
+            body.errors
+                .iter()
+                .map(|e: &FieldErrorBody| (e.field.as_str(), e.code.as_str()))
+                .collect::<Vec<(&str, &str)>>(),
+            vec![("name", "token_too_long"), ("document", "invalid_type")]
+        );
+    }
+
+    #[test]
+    fn test_invalid_parameter_error_body() {
+        let (status, body): (StatusCode, ErrorBody) =
+            ApiError::InvalidParameter("limit", "Must be between 1 and 500".to_string())
+                .status_and_body();
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body.code, "invalid_parameter");
+        assert_eq!(body.field, Some("limit".to_string()));
+        assert_eq!(body.message, "Must be between 1 and 500");
+    }
+
+    #[test]
+    fn test_not_found_error_body() {
+        let (status, body): (StatusCode, ErrorBody) =
+            ApiError::NotFound("Author").status_and_body();
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body.code, "not_found");
+        assert_eq!(body.field, None);
+        assert_eq!(body.message, "Author not found");
+        assert!(body.errors.is_empty());
+    }
+
+    #[test]
+    fn test_conflict_error_body() {
+        let (status, body): (StatusCode, ErrorBody) =
+            ApiError::Conflict("already_returned", "Rental was already returned").status_and_body();
+
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body.code, "already_returned");
+        assert_eq!(body.field, None);
+        assert_eq!(body.message, "Rental was already returned");
+    }
+
+    #[test]
+    fn test_duplicate_error_body() {
+        let existing_id: Uuid = Uuid::new_v4();
+
+        let (status, body): (StatusCode, ErrorBody) = ApiError::Duplicate(
+            "document_exists",
+            "Document already registered",
+            existing_id,
+        )
+        .status_and_body();
+
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body.code, "document_exists");
+        assert_eq!(body.existing_id, Some(existing_id));
+    }
+
+    #[test]
+    fn test_database_error_body() {
+        let (status, body): (StatusCode, ErrorBody) =
+            ApiError::from(Error::PoolTimedOut).status_and_body();
+
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(body.code, "database_error");
+        assert_eq!(body.field, None);
+    }
+}
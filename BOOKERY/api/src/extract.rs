@@ -0,0 +1,88 @@
+use axum::{
+    async_trait,
+    extract::{rejection::PathRejection, FromRequest, FromRequestParts, RawPathParams, Request},
+    http::request::Parts,
+    response::{IntoResponse, Response},
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::error::ApiError;
+
+pub struct Json<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for Json<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let axum::Json(value): axum::Json<T> = axum::Json::from_request(req, state).await?;
+
+        Ok(Self(value))
+    }
+}
+
+impl<T: Serialize> IntoResponse for Json<T> {
+    fn into_response(self) -> Response {
+        axum::Json(self.0).into_response()
+    }
+}
+
+pub struct Query<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequestParts<S> for Query<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let axum::extract::Query(value): axum::extract::Query<T> =
+            axum::extract::Query::from_request_parts(parts, state).await?;
+
+        Ok(Self(value))
+    }
+}
+
+pub struct Path<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequestParts<S> for Path<T>
+where
+    T: DeserializeOwned + Send,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let rejection: PathRejection =
+            match axum::extract::Path::from_request_parts(parts, state).await {
+                Ok(axum::extract::Path(value)) => return Ok(Self(value)),
+                Err(rejection) => rejection,
+            };
+
+        let error: ApiError = ApiError::from(rejection);
+
+        if let ApiError::Rejection(status, code, None, message) = error {
+            let params: Option<RawPathParams> =
+                RawPathParams::from_request_parts(parts, state).await.ok();
+            let field: Option<String> = params.and_then(|params: RawPathParams| {
+                let keys: Vec<&str> = params.iter().map(|(key, _value)| key).collect();
+
+                match keys.as_slice() {
+                    [key] => Some(key.to_string()),
+                    _ => None,
+                }
+            });
+
+            return Err(ApiError::Rejection(status, code, field, message));
+        }
+
+        Err(error)
+    }
+}
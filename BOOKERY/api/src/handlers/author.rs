@@ -1,87 +1,123 @@
-use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
-    Json,
-};
+use axum::{extract::State, http::StatusCode};
 use uuid::Uuid;
 
-use shared::structs::author::{Author, PayloadAuthor, PayloadUpdateAuthor};
+use crate::database::{
+    author::{AUTHOR_FUZZY_ORDER_COLUMNS, AUTHOR_ORDER_COLUMNS},
+    Pagination,
+};
+use crate::extract::{Json, Path, Query};
+use shared::structs::{
+    author::{Author, AuthorFilter, PayloadAuthor, PayloadUpdateAuthor},
+    duplicate::{AuthorMergeSummary, DuplicateGroup, Merge, PayloadMerge},
+    page::Page,
+};
 
-use super::{DeletingStruct, QueryURL, ResultStatus, DB};
+use super::{ApiError, DeletingStruct, QueryURL, ResultStatus, DB};
 
 pub async fn create_author(
     State(db): State<DB>,
     Json(incoming_author): Json<PayloadAuthor>,
 ) -> ResultStatus<Uuid> {
-    match Author::create(incoming_author) {
-        Ok(author) => match db.create_author(author).await {
-            Ok(author_uuid) => Ok((StatusCode::CREATED, Json(author_uuid))),
-            Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-        },
-        Err(_) => Err(StatusCode::UNPROCESSABLE_ENTITY),
-    }
+    let author: Author = Author::create(incoming_author)?;
+    let author_uuid: Uuid = db.create_author(author).await?;
+
+    Ok((StatusCode::CREATED, Json(author_uuid)))
 }
 
 pub async fn get_author(
     State(db): State<DB>,
     Path(author_uuid): Path<Uuid>,
 ) -> ResultStatus<Author> {
-    match db.get_author(author_uuid).await {
-        Ok(Some(author)) => Ok((StatusCode::OK, Json(author))),
-        Ok(None) => Err(StatusCode::NOT_FOUND),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    let author: Author = db
+        .get_author(author_uuid)
+        .await?
+        .ok_or(ApiError::NotFound("Author"))?;
+
+    Ok((StatusCode::OK, Json(author)))
 }
 
 pub async fn search_authors(
     State(db): State<DB>,
     Query(t): Query<QueryURL>,
-) -> ResultStatus<Vec<Author>> {
-    match db.search_authors(t.token).await {
-        Ok(authors_vec) => Ok((StatusCode::OK, Json(authors_vec))),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    Query(filter): Query<AuthorFilter>,
+) -> ResultStatus<Page<Author>> {
+    let page: Page<Author> = match t.fuzzy_threshold()? {
+        Some(threshold) => {
+            let pagination: Pagination = t.ranked_pagination(AUTHOR_FUZZY_ORDER_COLUMNS)?;
+            db.search_authors_fuzzy(t.token, threshold, filter, pagination)
+                .await?
+        }
+        None => {
+            let pagination: Pagination = t.pagination(AUTHOR_ORDER_COLUMNS)?;
+            db.search_authors(t.token, filter, pagination).await?
+        }
+    };
+
+    Ok((StatusCode::OK, Json(page)))
+}
+
+pub async fn list_author_duplicates(
+    State(db): State<DB>,
+) -> ResultStatus<Vec<DuplicateGroup<Author>>> {
+    let duplicates: Vec<DuplicateGroup<Author>> = db.list_author_duplicates().await?;
+
+    Ok((StatusCode::OK, Json(duplicates)))
 }
 
 pub async fn update_author(
     State(db): State<DB>,
     Json(payload_update_author): Json<PayloadUpdateAuthor>,
 ) -> ResultStatus<Uuid> {
-    match db.get_author_id(payload_update_author.id).await {
-        Ok(Some(_author_uuid)) => match Author::parse(payload_update_author) {
-            Ok(updated_author) => match db.update_author(updated_author).await {
-                Ok(author_uuid) => Ok((StatusCode::ACCEPTED, Json(author_uuid))),
-                Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-            },
-            Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-        },
-        Ok(None) => Err(StatusCode::NOT_FOUND),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    db.get_author_id(payload_update_author.id)
+        .await?
+        .ok_or(ApiError::NotFound("Author"))?;
+
+    let updated_author: Author = Author::parse(payload_update_author)?;
+    let author_uuid: Uuid = db.update_author(updated_author).await?;
+
+    Ok((StatusCode::ACCEPTED, Json(author_uuid)))
+}
+
+pub async fn merge_authors(
+    State(db): State<DB>,
+    Json(payload_merge): Json<PayloadMerge>,
+) -> ResultStatus<AuthorMergeSummary> {
+    let merge: Merge = Merge::parse(payload_merge).ok_or(ApiError::Conflict(
+        "merge_into_itself",
+        "Survivor and loser must be different records",
+    ))?;
+
+    for author_uuid in [merge.survivor_uuid, merge.loser_uuid] {
+        db.get_author_id(author_uuid)
+            .await?
+            .ok_or(ApiError::NotFound("Author"))?;
     }
+
+    let summary: AuthorMergeSummary = db.merge_authors(merge).await?;
+
+    Ok((StatusCode::OK, Json(summary)))
 }
 
 pub async fn delete_author(
     State(db): State<DB>,
     Json(incoming_struct): Json<DeletingStruct>,
 ) -> ResultStatus<String> {
-    match db.get_author(incoming_struct.id).await {
-        Ok(Some(author)) => match db.delete_author(author.id).await {
-            Ok(author_uuid) => Ok((
-                StatusCode::NO_CONTENT,
-                Json(format!("Author {author_uuid} deleted")),
-            )),
-            Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-        },
-        Ok(None) => Err(StatusCode::NOT_FOUND),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    let author_uuid: Uuid = db
+        .get_author_id(incoming_struct.id)
+        .await?
+        .ok_or(ApiError::NotFound("Author"))?;
+    let author_uuid: Uuid = db.delete_author(author_uuid).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(format!("Author {author_uuid} deleted")),
+    ))
 }
 
 pub async fn count_authors(State(db): State<DB>) -> ResultStatus<i64> {
-    match db.count_authors().await {
-        Ok(num) => Ok((StatusCode::OK, Json(num))),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    let num: i64 = db.count_authors().await?;
+
+    Ok((StatusCode::OK, Json(num)))
 }
 
 #[cfg(test)]
@@ -96,9 +132,11 @@ mod tests {
     use time::{error::ComponentRange, Date, Month};
 
     use crate::database::conn::Database;
+    use crate::error::{ErrorBody, FieldErrorBody};
     use crate::router::router;
+    use shared::structs::ConversionError;
 
-    const DEFAULT_NAME: &'static str = "Name";
+    const DEFAULT_NAME: &str = "Name";
     const DEFAULT_BORN: Result<Date, ComponentRange> =
         Date::from_calendar_date(2000, Month::January, 1);
 
@@ -126,6 +164,13 @@ mod tests {
             .await
     }
 
+    fn random_letters() -> String {
+        Uuid::new_v4().simple().to_string()[..12]
+            .chars()
+            .map(|c: char| (b'a' + c.to_digit(16).unwrap() as u8) as char)
+            .collect()
+    }
+
     #[tokio::test]
     async fn test_create_author_get() {
         let res: TestResponse = server().await.get("/author/create").await;
@@ -138,6 +183,7 @@ mod tests {
         let res: TestResponse = server().await.post("/author/create").await;
 
         res.assert_status(StatusCode::UNSUPPORTED_MEDIA_TYPE);
+        assert_eq!(res.json::<ErrorBody>().code, "missing_content_type");
     }
 
     #[tokio::test]
@@ -149,6 +195,31 @@ mod tests {
             .await;
 
         res.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+        let res_json: ErrorBody = res.json();
+        assert_eq!(res_json.code, "invalid_body");
+        assert!(res_json.message.contains("missing field `born`"));
+    }
+
+    #[tokio::test]
+    async fn test_create_author_post_invalid_name() {
+        let res: TestResponse = server()
+            .await
+            .post("/author/create")
+            .json(&json!({"name":"Name 0", "born":"2000-01-01"}))
+            .await;
+
+        res.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+        res.assert_json(&ErrorBody {
+            code: "invalid_type".to_string(),
+            field: Some("name".to_string()),
+            message: ConversionError::InvalidType.message().to_string(),
+            errors: vec![FieldErrorBody {
+                code: "invalid_type".to_string(),
+                field: "name".to_string(),
+                message: ConversionError::InvalidType.message().to_string(),
+            }],
+            existing_id: None,
+        });
     }
 
     #[tokio::test]
@@ -170,6 +241,9 @@ mod tests {
         let res: TestResponse = server().await.get("/author/get/12345").await;
 
         res.assert_status_bad_request();
+        let res_json: ErrorBody = res.json();
+        assert_eq!(res_json.code, "invalid_path");
+        assert_eq!(res_json.field, Some("id".to_string()));
     }
 
     #[tokio::test]
@@ -197,6 +271,22 @@ mod tests {
         res.assert_status_ok();
     }
 
+    #[tokio::test]
+    async fn test_get_author_get_not_found_body() {
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/author/get/{}", Uuid::new_v4()))
+            .await;
+
+        res.assert_json(&ErrorBody {
+            code: "not_found".to_string(),
+            field: None,
+            message: "Author not found".to_string(),
+            errors: vec![],
+            existing_id: None,
+        });
+    }
+
     #[tokio::test]
     async fn test_get_author_post() {
         let res: TestResponse = server()
@@ -213,6 +303,7 @@ mod tests {
 
         let res: TestResponse = server().await.get("/author/search").await;
         res.assert_status_bad_request();
+        assert_eq!(res.json::<ErrorBody>().code, "invalid_query");
 
         let res: TestResponse = server().await.get("/author/search?").await;
         res.assert_status_bad_request();
@@ -220,9 +311,17 @@ mod tests {
 
     #[tokio::test]
     async fn test_search_authors_get_found() {
-        let create_res: TestResponse = create_author_on_server().await;
+        let letters: String = random_letters();
 
-        let created_author_uuid: Uuid = create_res.json();
+        let created_author_uuid: Uuid = server()
+            .await
+            .post("/author/create")
+            .json(&json!(PayloadAuthor {
+                name: format!("{DEFAULT_NAME} {letters}"),
+                born: DEFAULT_BORN.unwrap(),
+            }))
+            .await
+            .json();
 
         let created_author: Author = server()
             .await
@@ -230,20 +329,175 @@ mod tests {
             .await
             .json();
 
-        let res: TestResponse = server().await.get("/author/search?token").await;
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/author/search?token={letters}"))
+            .await;
         res.assert_status_ok();
-        let res_json: Vec<Author> = res.json();
+        let res_json: Vec<Author> = res.json::<Page<Author>>().items;
         assert!(res_json.contains(&created_author));
 
-        let res: TestResponse = server().await.get("/author/search?token=").await;
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/author/search?token={}", &letters[2..8]))
+            .await;
         res.assert_status_ok();
-        let res_json: Vec<Author> = res.json();
+        let res_json: Vec<Author> = res.json::<Page<Author>>().items;
         assert!(res_json.contains(&created_author));
+    }
+
+    #[tokio::test]
+    async fn test_search_authors_get_paginated() {
+        create_author_on_server().await;
+        create_author_on_server().await;
 
-        let res: TestResponse = server().await.get("/author/search?token=am").await;
+        let res: TestResponse = server()
+            .await
+            .get("/author/search?token=&limit=1&offset=1&order_by=born&direction=desc")
+            .await;
         res.assert_status_ok();
-        let res_json: Vec<Author> = res.json();
-        assert!(res_json.contains(&created_author));
+        let res_json: Page<Author> = res.json();
+        assert_eq!(res_json.items.len(), 1);
+        assert!(res_json.total >= 2);
+        assert_eq!(res_json.limit, Some(1));
+        assert_eq!(res_json.offset, 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_author_duplicates_get() {
+        let letters: String = random_letters();
+
+        let mut author_uuids: Vec<Uuid> = vec![];
+        for name in [format!("Brás {letters}"), format!("BRAS {letters}")] {
+            let author_uuid: Uuid = server()
+                .await
+                .post("/author/create")
+                .json(&json!(PayloadAuthor {
+                    name,
+                    born: DEFAULT_BORN.unwrap(),
+                }))
+                .await
+                .json();
+            author_uuids.push(author_uuid);
+        }
+        author_uuids.sort();
+
+        let res: TestResponse = server().await.get("/author/duplicates").await;
+
+        res.assert_status_ok();
+        let res_json: Vec<DuplicateGroup<Author>> = res.json();
+        let group: &DuplicateGroup<Author> = res_json
+            .iter()
+            .find(|group: &&DuplicateGroup<Author>| group.key == format!("bras{letters}"))
+            .unwrap();
+        assert_eq!(
+            group
+                .items
+                .iter()
+                .map(|author: &Author| author.id)
+                .collect::<Vec<Uuid>>(),
+            author_uuids
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_authors_get_fuzzy() {
+        let letters: String = random_letters();
+
+        let author_uuid: Uuid = server()
+            .await
+            .post("/author/create")
+            .json(&json!(PayloadAuthor {
+                name: format!("Machado {letters}"),
+                born: DEFAULT_BORN.unwrap(),
+            }))
+            .await
+            .json();
+
+        let misspelled: String = format!("{}{}", &letters[..5], &letters[6..]);
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/author/search?token={misspelled}"))
+            .await;
+        res.assert_status_ok();
+        assert_eq!(res.json::<Page<Author>>().total, 0);
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!(
+                "/author/search?token={misspelled}&fuzzy=true&threshold=0.5"
+            ))
+            .await;
+        res.assert_status_ok();
+        let res_json: Page<Author> = res.json();
+        assert_eq!(res_json.items[0].id, author_uuid);
+
+        let res: TestResponse = server()
+            .await
+            .get("/author/search?token=name&fuzzy=true&threshold=1.5")
+            .await;
+        res.assert_status_bad_request();
+        assert_eq!(res.json::<ErrorBody>().field, Some("threshold".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_search_authors_get_default_limit() {
+        create_author_on_server().await;
+
+        let res: TestResponse = server().await.get("/author/search?token=").await;
+
+        res.assert_status_ok();
+        let res_json: Page<Author> = res.json();
+        assert_eq!(res_json.limit, Some(50));
+        assert!(res_json.items.len() <= 50);
+    }
+
+    #[tokio::test]
+    async fn test_search_authors_get_invalid_pagination() {
+        let res: TestResponse = server()
+            .await
+            .get("/author/search?token=&order_by=id")
+            .await;
+        res.assert_status_bad_request();
+        let res_json: ErrorBody = res.json();
+        assert_eq!(res_json.code, "invalid_parameter");
+        assert_eq!(res_json.field, Some("order_by".to_string()));
+
+        let res: TestResponse = server().await.get("/author/search?token=&limit=0").await;
+        res.assert_status_bad_request();
+
+        let res: TestResponse = server().await.get("/author/search?token=&offset=-1").await;
+        res.assert_status_bad_request();
+
+        let res: TestResponse = server()
+            .await
+            .get("/author/search?token=&direction=up")
+            .await;
+        res.assert_status_bad_request();
+    }
+
+    #[tokio::test]
+    async fn test_search_authors_get_filtered_by_born() {
+        create_author_on_server().await;
+
+        let res: TestResponse = server()
+            .await
+            .get("/author/search?token=&born_from=2000-01-01&born_until=2000-01-01")
+            .await;
+        res.assert_status_ok();
+        let res_json: Page<Author> = res.json();
+        assert!(res_json.total >= 1);
+        assert!(res_json
+            .items
+            .iter()
+            .all(|author: &Author| author.born == DEFAULT_BORN.unwrap()));
+
+        let res: TestResponse = server()
+            .await
+            .get("/author/search?token=&born_from=01/01/2000")
+            .await;
+        res.assert_status_bad_request();
     }
 
     #[tokio::test]
@@ -299,6 +553,58 @@ mod tests {
         res.assert_status(StatusCode::ACCEPTED);
     }
 
+    #[tokio::test]
+    async fn test_merge_authors_post_valid() {
+        let survivor_uuid: Uuid = create_author_on_server().await.json();
+        let loser_uuid: Uuid = create_author_on_server().await.json();
+
+        let res: TestResponse = server()
+            .await
+            .post("/author/merge")
+            .json(&json!(PayloadMerge {
+                survivor_uuid,
+                loser_uuid,
+            }))
+            .await;
+
+        res.assert_status_ok();
+        let res_json: AuthorMergeSummary = res.json();
+        assert_eq!(res_json.survivor_uuid, survivor_uuid);
+        assert_eq!(res_json.loser_uuid, loser_uuid);
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/author/get/{loser_uuid}"))
+            .await;
+        res.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_merge_authors_post_invalid() {
+        let author_uuid: Uuid = create_author_on_server().await.json();
+
+        let res: TestResponse = server()
+            .await
+            .post("/author/merge")
+            .json(&json!(PayloadMerge {
+                survivor_uuid: author_uuid,
+                loser_uuid: author_uuid,
+            }))
+            .await;
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "merge_into_itself");
+
+        let res: TestResponse = server()
+            .await
+            .post("/author/merge")
+            .json(&json!(PayloadMerge {
+                survivor_uuid: author_uuid,
+                loser_uuid: Uuid::new_v4(),
+            }))
+            .await;
+        res.assert_status_not_found();
+    }
+
     #[tokio::test]
     async fn test_delete_author_get() {
         let res: TestResponse = server().await.post("/author/delete").await;
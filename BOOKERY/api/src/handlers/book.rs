@@ -1,105 +1,197 @@
-use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
-    Json,
-};
+use axum::{extract::State, http::StatusCode};
 use uuid::Uuid;
 
-use shared::structs::book::{Book, BookWithAuthor, PayloadBook, PayloadUpdateBook};
+use crate::database::{
+    book::{
+        prefix_tsquery, BOOK_FUZZY_ORDER_COLUMNS, BOOK_ORDER_COLUMNS, BOOK_RANKED_ORDER_COLUMNS,
+        BOOK_RAW_ORDER_COLUMNS,
+    },
+    rental::RENTAL_ORDER_COLUMNS,
+    Pagination,
+};
+use crate::extract::{Json, Path, Query};
+use shared::structs::{
+    book::{Book, BookFilter, BookWithAuthor, PayloadBook, PayloadUpdateBook},
+    copy::BookCopies,
+    page::Page,
+    rental::{BookRentals, RentalWithCostumerAndBook},
+    reservation::Reservation,
+    ConversionError, Isbn,
+};
 
-use super::{DeletingStruct, QueryURL, ResultStatus, DB};
+use super::{ApiError, DeletingStruct, PageURL, QueryURL, ResultStatus, DB};
 
 pub async fn create_book(
     State(db): State<DB>,
     Json(incoming_book): Json<PayloadBook>,
 ) -> ResultStatus<Uuid> {
-    match Book::create(incoming_book) {
-        Ok(book) => match db.create_book(book).await {
-            Ok(book_uuid) => Ok((StatusCode::CREATED, Json(book_uuid))),
-            Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-        },
-        Err(_) => Err(StatusCode::UNPROCESSABLE_ENTITY),
-    }
+    let book: Book = Book::create(incoming_book)?;
+    let book_uuid: Uuid = db.create_book(book).await?;
+
+    Ok((StatusCode::CREATED, Json(book_uuid)))
 }
 
 pub async fn get_book(
     State(db): State<DB>,
     Path(book_uuid): Path<Uuid>,
 ) -> ResultStatus<BookWithAuthor> {
-    match db.get_book(book_uuid).await {
-        Ok(Some(book)) => Ok((StatusCode::OK, Json(book))),
-        Ok(None) => Err(StatusCode::NOT_FOUND),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    let book: BookWithAuthor = db
+        .get_book(book_uuid)
+        .await?
+        .ok_or(ApiError::NotFound("Book"))?;
+
+    Ok((StatusCode::OK, Json(book)))
+}
+
+pub async fn get_book_by_isbn(
+    State(db): State<DB>,
+    Path(isbn): Path<String>,
+) -> ResultStatus<BookWithAuthor> {
+    let isbn: Isbn = Isbn::try_from(isbn).map_err(|e: ConversionError| {
+        ApiError::InvalidParameter("isbn", e.message().to_string())
+    })?;
+
+    let book: BookWithAuthor = db
+        .get_book_by_isbn(isbn)
+        .await?
+        .ok_or(ApiError::NotFound("Book"))?;
+
+    Ok((StatusCode::OK, Json(book)))
 }
 
 pub async fn get_book_raw(State(db): State<DB>, Path(book_uuid): Path<Uuid>) -> ResultStatus<Book> {
-    match db.get_book_raw(book_uuid).await {
-        Ok(Some(book)) => Ok((StatusCode::OK, Json(book))),
-        Ok(None) => Err(StatusCode::NOT_FOUND),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    let book: Book = db
+        .get_book_raw(book_uuid)
+        .await?
+        .ok_or(ApiError::NotFound("Book"))?;
+
+    Ok((StatusCode::OK, Json(book)))
+}
+
+pub async fn list_book_copies(
+    State(db): State<DB>,
+    Path(book_uuid): Path<Uuid>,
+) -> ResultStatus<BookCopies> {
+    db.get_book_id(book_uuid)
+        .await?
+        .ok_or(ApiError::NotFound("Book"))?;
+
+    let book_copies: BookCopies = db.list_book_copies(book_uuid).await?;
+
+    Ok((StatusCode::OK, Json(book_copies)))
+}
+
+pub async fn list_book_reservations(
+    State(db): State<DB>,
+    Path(book_uuid): Path<Uuid>,
+) -> ResultStatus<Vec<Reservation>> {
+    db.get_book_id(book_uuid)
+        .await?
+        .ok_or(ApiError::NotFound("Book"))?;
+
+    let reservations: Vec<Reservation> = db.list_book_reservations(book_uuid).await?;
+
+    Ok((StatusCode::OK, Json(reservations)))
+}
+
+pub async fn book_rentals(
+    State(db): State<DB>,
+    Path(book_uuid): Path<Uuid>,
+    Query(p): Query<PageURL>,
+) -> ResultStatus<BookRentals> {
+    let pagination: Pagination = p.pagination(RENTAL_ORDER_COLUMNS)?;
+
+    db.get_book_id(book_uuid)
+        .await?
+        .ok_or(ApiError::NotFound("Book"))?;
+
+    let current: Vec<RentalWithCostumerAndBook> = db.book_loans(book_uuid).await?;
+    let history: Page<RentalWithCostumerAndBook> = db.book_rentals(book_uuid, pagination).await?;
+
+    Ok((StatusCode::OK, Json(BookRentals { current, history })))
 }
 
 pub async fn search_books(
     State(db): State<DB>,
     Query(t): Query<QueryURL>,
-) -> ResultStatus<Vec<BookWithAuthor>> {
-    match db.search_books(t.token).await {
-        Ok(books_vec) => Ok((StatusCode::OK, Json(books_vec))),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    Query(filter): Query<BookFilter>,
+) -> ResultStatus<Page<BookWithAuthor>> {
+    let page: Page<BookWithAuthor> = match t.fuzzy_threshold()? {
+        Some(threshold) => {
+            let pagination: Pagination = t.ranked_pagination(BOOK_FUZZY_ORDER_COLUMNS)?;
+            db.search_books_fuzzy(t.token, threshold, filter, pagination)
+                .await?
+        }
+        None => {
+            let pagination: Pagination = t.pagination(BOOK_ORDER_COLUMNS)?;
+            db.search_books(t.token, filter, pagination).await?
+        }
+    };
+
+    Ok((StatusCode::OK, Json(page)))
+}
+
+pub async fn search_books_ranked(
+    State(db): State<DB>,
+    Query(t): Query<QueryURL>,
+    Query(filter): Query<BookFilter>,
+) -> ResultStatus<Page<BookWithAuthor>> {
+    let pagination: Pagination = t.ranked_pagination(BOOK_RANKED_ORDER_COLUMNS)?;
+    let tsquery: String = prefix_tsquery(&t.token).ok_or(ApiError::InvalidParameter(
+        "token",
+        "Must contain at least one word".to_string(),
+    ))?;
+
+    let page: Page<BookWithAuthor> = db.search_books_ranked(tsquery, filter, pagination).await?;
+
+    Ok((StatusCode::OK, Json(page)))
 }
 
 pub async fn search_books_raw(
     State(db): State<DB>,
     Query(t): Query<QueryURL>,
-) -> ResultStatus<Vec<Book>> {
-    match db.search_books_raw(t.token).await {
-        Ok(books_vec) => Ok((StatusCode::OK, Json(books_vec))),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    Query(filter): Query<BookFilter>,
+) -> ResultStatus<Page<Book>> {
+    let pagination: Pagination = t.pagination(BOOK_RAW_ORDER_COLUMNS)?;
+    let page: Page<Book> = db.search_books_raw(t.token, filter, pagination).await?;
+
+    Ok((StatusCode::OK, Json(page)))
 }
 
 pub async fn update_book(
     State(db): State<DB>,
     Json(payload_update_book): Json<PayloadUpdateBook>,
 ) -> ResultStatus<Uuid> {
-    match db.get_book_id(payload_update_book.id).await {
-        Ok(Some(_book_uuid)) => match Book::parse(payload_update_book) {
-            Ok(updated_book) => match db.update_book(updated_book).await {
-                Ok(book_uuid) => Ok((StatusCode::ACCEPTED, Json(book_uuid))),
-                Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-            },
-            Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-        },
-        Ok(None) => Err(StatusCode::NOT_FOUND),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    db.get_book_id(payload_update_book.id)
+        .await?
+        .ok_or(ApiError::NotFound("Book"))?;
+
+    let updated_book: Book = Book::parse(payload_update_book)?;
+    let book_uuid: Uuid = db.update_book(updated_book).await?;
+
+    Ok((StatusCode::ACCEPTED, Json(book_uuid)))
 }
 
 pub async fn delete_book(
     State(db): State<DB>,
     Json(incoming_struct): Json<DeletingStruct>,
 ) -> ResultStatus<String> {
-    match db.get_book_raw(incoming_struct.id).await {
-        Ok(Some(book)) => match db.delete_book(book.id).await {
-            Ok(book_uuid) => Ok((
-                StatusCode::NO_CONTENT,
-                Json(format!("Book {book_uuid} deleted")),
-            )),
-            Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-        },
-        Ok(None) => Err(StatusCode::NOT_FOUND),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    let book_uuid: Uuid = db
+        .get_book_id(incoming_struct.id)
+        .await?
+        .ok_or(ApiError::NotFound("Book"))?;
+    let book_uuid: Uuid = db.delete_book(book_uuid).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(format!("Book {book_uuid} deleted")),
+    ))
 }
 
 pub async fn count_books(State(db): State<DB>) -> ResultStatus<i64> {
-    match db.count_books().await {
-        Ok(num) => Ok((StatusCode::OK, Json(num))),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    let num: i64 = db.count_books().await?;
+
+    Ok((StatusCode::OK, Json(num)))
 }
 
 #[cfg(test)]
@@ -114,11 +206,17 @@ mod tests {
     use time::{error::ComponentRange, Date, Month};
 
     use crate::database::conn::Database;
+    use crate::error::ErrorBody;
     use crate::router::router;
-    use shared::structs::author::PayloadAuthor;
-
-    const DEFAULT_NAME: &'static str = "Name";
-    const DEFAULT_EDITOR: &'static str = "Editor";
+    use shared::structs::{
+        author::PayloadAuthor,
+        book::{BookContributorName, ContributorRole, PayloadContributor},
+        publisher::PayloadPublisher,
+        subject::PayloadSubject,
+    };
+
+    const DEFAULT_NAME: &str = "Name";
+    const DEFAULT_EDITOR: &str = "Editor";
     const DEFAULT_RELEASE: Result<Date, ComponentRange> =
         Date::from_calendar_date(2000, Month::January, 1);
 
@@ -149,13 +247,40 @@ mod tests {
             .await
     }
 
+    fn random_isbn_10() -> String {
+        let prefix: String = format!("{:09}", Uuid::new_v4().as_u128() % 1_000_000_000);
+
+        ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "X"]
+            .into_iter()
+            .map(|check: &str| format!("{prefix}{check}"))
+            .find(|isbn: &String| Isbn::try_from(isbn.clone()).is_ok())
+            .unwrap()
+    }
+
+    async fn create_publisher_on_server() -> Uuid {
+        server()
+            .await
+            .post("/publisher/create")
+            .json(&json!(PayloadPublisher {
+                name: format!("{DEFAULT_EDITOR} {}", Uuid::new_v4().simple()),
+            }))
+            .await
+            .json()
+    }
+
     async fn create_payload_book() -> PayloadBook {
         let author_uuid: Uuid = create_author_on_server().await.json();
+        let publisher_uuid: Uuid = create_publisher_on_server().await;
 
         PayloadBook {
             name: DEFAULT_NAME.to_string(),
-            author_uuid,
-            editor: DEFAULT_EDITOR.to_string(),
+            isbn: None,
+            contributors: vec![PayloadContributor {
+                author_uuid,
+                role: "author".to_string(),
+            }],
+            subjects: vec![],
+            publisher_uuid,
             release: DEFAULT_RELEASE.unwrap(),
         }
     }
@@ -200,6 +325,93 @@ mod tests {
         res.assert_status(StatusCode::CREATED);
     }
 
+    #[tokio::test]
+    async fn test_create_book_post_unknown_publisher() {
+        let mut payload_book: PayloadBook = create_payload_book().await;
+        payload_book.publisher_uuid = Uuid::new_v4();
+
+        let res: TestResponse = server()
+            .await
+            .post("/book/create")
+            .json(&json!(payload_book))
+            .await;
+
+        res.assert_status(StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn test_get_book_by_isbn_get() {
+        let isbn_10: String = random_isbn_10();
+        let isbn_13: String = Isbn::try_from(isbn_10.clone()).unwrap().as_str();
+
+        let mut payload_book: PayloadBook = create_payload_book().await;
+        payload_book.isbn = Some(isbn_10.clone());
+
+        let book_uuid: Uuid = server()
+            .await
+            .post("/book/create")
+            .json(&json!(payload_book))
+            .await
+            .json();
+
+        for isbn in [
+            isbn_13.clone(),
+            format!("{}-{}-{}", &isbn_13[..3], &isbn_13[3..12], &isbn_13[12..]),
+            isbn_10,
+        ] {
+            let res: TestResponse = server().await.get(&format!("/book/isbn/{isbn}")).await;
+
+            res.assert_status_ok();
+            let res_json: BookWithAuthor = res.json();
+            assert_eq!(res_json.id, book_uuid);
+            assert_eq!(res_json.isbn.unwrap().as_str(), isbn_13);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_book_by_isbn_get_invalid() {
+        let res: TestResponse = server().await.get("/book/isbn/9780306406158").await;
+
+        res.assert_status_bad_request();
+        assert_eq!(res.json::<ErrorBody>().field, Some("isbn".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_get_book_by_isbn_get_not_found() {
+        let isbn: String = random_isbn_10();
+
+        let res: TestResponse = server().await.get(&format!("/book/isbn/{isbn}")).await;
+
+        res.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_create_book_post_duplicated_isbn() {
+        let isbn: String = random_isbn_10();
+
+        let mut payload_book: PayloadBook = create_payload_book().await;
+        payload_book.isbn = Some(isbn.clone());
+
+        server()
+            .await
+            .post("/book/create")
+            .json(&json!(payload_book))
+            .await
+            .assert_status(StatusCode::CREATED);
+
+        let mut payload_book: PayloadBook = create_payload_book().await;
+        payload_book.isbn = Some(Isbn::try_from(isbn).unwrap().as_str());
+
+        let res: TestResponse = server()
+            .await
+            .post("/book/create")
+            .json(&json!(payload_book))
+            .await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "isbn_exists");
+    }
+
     #[tokio::test]
     async fn test_get_book_get_empty() {
         let res: TestResponse = server().await.get("/book/get/").await;
@@ -308,9 +520,17 @@ mod tests {
 
     #[tokio::test]
     async fn test_search_books_get_found() {
-        let create_res: TestResponse = create_book_on_server().await;
+        let suffix: String = Uuid::new_v4().simple().to_string();
 
-        let created_book_uuid: Uuid = create_res.json();
+        let mut payload_book: PayloadBook = create_payload_book().await;
+        payload_book.name = format!("{DEFAULT_NAME} {suffix}");
+
+        let created_book_uuid: Uuid = server()
+            .await
+            .post("/book/create")
+            .json(&json!(payload_book))
+            .await
+            .json();
 
         let created_book: BookWithAuthor = server()
             .await
@@ -318,20 +538,218 @@ mod tests {
             .await
             .json();
 
-        let res: TestResponse = server().await.get("/book/search?token").await;
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/book/search?token={suffix}"))
+            .await;
         res.assert_status_ok();
-        let res_json: Vec<BookWithAuthor> = res.json();
+        let res_json: Vec<BookWithAuthor> = res.json::<Page<BookWithAuthor>>().items;
         assert!(res_json.contains(&created_book));
 
-        let res: TestResponse = server().await.get("/book/search?token=").await;
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/book/search?token={}", &suffix[4..16]))
+            .await;
         res.assert_status_ok();
-        let res_json: Vec<BookWithAuthor> = res.json();
+        let res_json: Vec<BookWithAuthor> = res.json::<Page<BookWithAuthor>>().items;
         assert!(res_json.contains(&created_book));
+    }
 
-        let res: TestResponse = server().await.get("/book/search?token=am").await;
+    #[tokio::test]
+    async fn test_search_books_get_ordered_by_author() {
+        create_book_on_server().await;
+
+        let res: TestResponse = server()
+            .await
+            .get("/book/search?token=&order_by=author&limit=5")
+            .await;
         res.assert_status_ok();
-        let res_json: Vec<BookWithAuthor> = res.json();
-        assert!(res_json.contains(&created_book));
+        let res_json: Page<BookWithAuthor> = res.json();
+        assert!(res_json.items.len() <= 5);
+        assert!(res_json.total >= 1);
+
+        let res: TestResponse = server()
+            .await
+            .get("/book/search-raw?token=&order_by=author")
+            .await;
+        res.assert_status_bad_request();
+    }
+
+    #[tokio::test]
+    async fn test_search_books_get_ordered_by_circulation() {
+        let res: TestResponse = server()
+            .await
+            .get("/book/search?token=&order_by=circulation&limit=5")
+            .await;
+        res.assert_status_ok();
+        let res_json: Page<BookWithAuthor> = res.json();
+        assert!(res_json
+            .items
+            .windows(2)
+            .all(
+                |books: &[BookWithAuthor]| books[0].circulation_count <= books[1].circulation_count
+            ));
+    }
+
+    #[tokio::test]
+    async fn test_book_rentals_get() {
+        let book_uuid: Uuid = create_book_on_server().await.json();
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/book/rentals/{book_uuid}?limit=10"))
+            .await;
+
+        res.assert_status_ok();
+        let res_json: BookRentals = res.json();
+        assert!(res_json.current.is_empty());
+        assert_eq!(res_json.history.total, 0);
+        assert_eq!(res_json.history.limit, Some(10));
+    }
+
+    #[tokio::test]
+    async fn test_book_rentals_get_not_found() {
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/book/rentals/{}", Uuid::new_v4()))
+            .await;
+
+        res.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_search_books_get_filtered() {
+        create_book_on_server().await;
+
+        let res: TestResponse = server()
+            .await
+            .get("/book/search?token=&author=nam&editor=edit&release_from=2000-01-01")
+            .await;
+        res.assert_status_ok();
+        let res_json: Page<BookWithAuthor> = res.json();
+        assert!(res_json.total >= 1);
+
+        let res: TestResponse = server()
+            .await
+            .get("/book/search?token=&release_until=2000-01-01&release_from=2000-01-02")
+            .await;
+        res.assert_status_ok();
+        let res_json: Page<BookWithAuthor> = res.json();
+        assert_eq!(res_json.total, 0);
+        assert!(res_json.items.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_books_get_filtered_by_subject() {
+        let subject_name: String = format!("Subject {}", Uuid::new_v4().simple());
+
+        let subject_uuid: Uuid = server()
+            .await
+            .post("/subject/create")
+            .json(&json!(PayloadSubject {
+                name: subject_name.clone(),
+                parent_uuid: None,
+            }))
+            .await
+            .json();
+
+        let mut payload_book: PayloadBook = create_payload_book().await;
+        payload_book.subjects = vec![subject_uuid];
+
+        let book_uuid: Uuid = server()
+            .await
+            .post("/book/create")
+            .json(&json!(payload_book))
+            .await
+            .json();
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!(
+                "/book/search?token=&subject={}",
+                subject_name.replace(' ', "%20")
+            ))
+            .await;
+        res.assert_status_ok();
+        let res_json: Page<BookWithAuthor> = res.json();
+        assert_eq!(res_json.total, 1);
+        assert_eq!(res_json.items[0].id, book_uuid);
+        assert_eq!(res_json.items[0].subjects[0].as_str(), subject_name);
+    }
+
+    #[tokio::test]
+    async fn test_create_book_post_unknown_subject() {
+        let mut payload_book: PayloadBook = create_payload_book().await;
+        payload_book.subjects = vec![Uuid::new_v4()];
+
+        let res: TestResponse = server()
+            .await
+            .post("/book/create")
+            .json(&json!(payload_book))
+            .await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "foreign_key_violation");
+    }
+
+    #[tokio::test]
+    async fn test_search_books_ranked_get() {
+        let word: String = format!("q{}", Uuid::new_v4().simple());
+
+        let mut payload_book: PayloadBook = create_payload_book().await;
+        payload_book.name = format!("Coração {word}");
+
+        let book_uuid: Uuid = server()
+            .await
+            .post("/book/create")
+            .json(&json!(payload_book))
+            .await
+            .json();
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!(
+                "/book/search-ranked?token=coracao%20{}",
+                &word[..8]
+            ))
+            .await;
+        res.assert_status_ok();
+        let res_json: Page<BookWithAuthor> = res.json();
+        assert_eq!(res_json.total, 1);
+        assert_eq!(res_json.items[0].id, book_uuid);
+
+        let res: TestResponse = server()
+            .await
+            .get("/book/search-ranked?token=%20!%20")
+            .await;
+        res.assert_status_bad_request();
+        assert_eq!(res.json::<ErrorBody>().field, Some("token".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_search_books_get_fuzzy() {
+        let word: String = format!("q{}", Uuid::new_v4().simple());
+
+        let mut payload_book: PayloadBook = create_payload_book().await;
+        payload_book.name = format!("Memorias {word}");
+
+        let book_uuid: Uuid = server()
+            .await
+            .post("/book/create")
+            .json(&json!(payload_book))
+            .await
+            .json();
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!(
+                "/book/search?token=memorais%20{}&fuzzy=true&threshold=0.5",
+                &word[..30]
+            ))
+            .await;
+        res.assert_status_ok();
+        let res_json: Page<BookWithAuthor> = res.json();
+        assert_eq!(res_json.items[0].id, book_uuid);
     }
 
     #[tokio::test]
@@ -354,9 +772,17 @@ mod tests {
 
     #[tokio::test]
     async fn test_search_books_raw_get_found() {
-        let create_res: TestResponse = create_book_on_server().await;
+        let suffix: String = Uuid::new_v4().simple().to_string();
 
-        let created_book_uuid: Uuid = create_res.json();
+        let mut payload_book: PayloadBook = create_payload_book().await;
+        payload_book.name = format!("{DEFAULT_NAME} {suffix}");
+
+        let created_book_uuid: Uuid = server()
+            .await
+            .post("/book/create")
+            .json(&json!(payload_book))
+            .await
+            .json();
 
         let created_book: Book = server()
             .await
@@ -364,19 +790,20 @@ mod tests {
             .await
             .json();
 
-        let res: TestResponse = server().await.get("/book/search-raw?token").await;
-        res.assert_status_ok();
-        let res_json: Vec<Book> = res.json();
-        assert!(res_json.contains(&created_book));
-
-        let res: TestResponse = server().await.get("/book/search-raw?token=").await;
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/book/search-raw?token={suffix}"))
+            .await;
         res.assert_status_ok();
-        let res_json: Vec<Book> = res.json();
+        let res_json: Vec<Book> = res.json::<Page<Book>>().items;
         assert!(res_json.contains(&created_book));
 
-        let res: TestResponse = server().await.get("/book/search-raw?token=am").await;
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/book/search-raw?token={}", &suffix[4..16]))
+            .await;
         res.assert_status_ok();
-        let res_json: Vec<Book> = res.json();
+        let res_json: Vec<Book> = res.json::<Page<Book>>().items;
         assert!(res_json.contains(&created_book));
     }
 
@@ -424,13 +851,24 @@ mod tests {
             .await
             .json();
 
-        let created_book_author_uuid: Uuid = created_book.author_uuid;
+        let translator_uuid: Uuid = create_author_on_server().await.json();
 
         let payload_update_book: PayloadUpdateBook = PayloadUpdateBook {
             id: created_book_uuid,
             name: DEFAULT_NAME.to_string(),
-            author_uuid: created_book_author_uuid,
-            editor: DEFAULT_EDITOR.to_string(),
+            isbn: None,
+            contributors: vec![
+                PayloadContributor {
+                    author_uuid: created_book.contributors[0].author_uuid,
+                    role: "author".to_string(),
+                },
+                PayloadContributor {
+                    author_uuid: translator_uuid,
+                    role: "translator".to_string(),
+                },
+            ],
+            subjects: vec![],
+            publisher_uuid: created_book.publisher_uuid,
             release: DEFAULT_RELEASE.unwrap(),
         };
 
@@ -441,6 +879,47 @@ mod tests {
             .await;
 
         res.assert_status(StatusCode::ACCEPTED);
+
+        let updated_book: BookWithAuthor = server()
+            .await
+            .get(&format!("/book/get/{created_book_uuid}"))
+            .await
+            .json();
+
+        assert_eq!(
+            updated_book
+                .contributors
+                .iter()
+                .map(|contributor: &BookContributorName| contributor.role)
+                .collect::<Vec<ContributorRole>>(),
+            vec![ContributorRole::Author, ContributorRole::Translator]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_book_post_unknown_contributor() {
+        let created_book_uuid: Uuid = create_book_on_server().await.json();
+
+        let payload_update_book: PayloadUpdateBook = PayloadUpdateBook {
+            id: created_book_uuid,
+            name: DEFAULT_NAME.to_string(),
+            isbn: None,
+            contributors: vec![PayloadContributor {
+                author_uuid: Uuid::new_v4(),
+                role: "author".to_string(),
+            }],
+            subjects: vec![],
+            publisher_uuid: create_publisher_on_server().await,
+            release: DEFAULT_RELEASE.unwrap(),
+        };
+
+        let res: TestResponse = server()
+            .await
+            .post("/book/update")
+            .json(&json!(payload_update_book))
+            .await;
+
+        res.assert_status(StatusCode::CONFLICT);
     }
 
     #[tokio::test]
@@ -480,13 +959,16 @@ mod tests {
             .await
             .json();
 
-        let created_book_author_uuid: Uuid = created_book.author_uuid;
-
         let payload_delete_book: PayloadUpdateBook = PayloadUpdateBook {
             id: created_book_uuid,
             name: DEFAULT_NAME.to_string(),
-            author_uuid: created_book_author_uuid,
-            editor: DEFAULT_EDITOR.to_string(),
+            isbn: None,
+            contributors: vec![PayloadContributor {
+                author_uuid: created_book.contributors[0].author_uuid,
+                role: "author".to_string(),
+            }],
+            subjects: vec![],
+            publisher_uuid: created_book.publisher_uuid,
             release: DEFAULT_RELEASE.unwrap(),
         };
 
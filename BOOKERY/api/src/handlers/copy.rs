@@ -0,0 +1,307 @@
+use axum::{extract::State, http::StatusCode};
+use uuid::Uuid;
+
+use crate::extract::{Json, Path};
+use shared::structs::copy::{BookCopy, PayloadBookCopy, PayloadUpdateBookCopy};
+
+use super::{ApiError, DeletingStruct, ResultStatus, DB};
+
+pub async fn create_copy(
+    State(db): State<DB>,
+    Json(incoming_copy): Json<PayloadBookCopy>,
+) -> ResultStatus<Uuid> {
+    let copy: BookCopy = BookCopy::create(incoming_copy)?;
+
+    db.get_book_id(copy.book_uuid)
+        .await?
+        .ok_or(ApiError::NotFound("Book"))?;
+
+    let copy_uuid: Uuid = db.create_copy(copy).await?;
+
+    Ok((StatusCode::CREATED, Json(copy_uuid)))
+}
+
+pub async fn get_copy(State(db): State<DB>, Path(copy_uuid): Path<Uuid>) -> ResultStatus<BookCopy> {
+    let copy: BookCopy = db
+        .get_copy(copy_uuid)
+        .await?
+        .ok_or(ApiError::NotFound("Copy"))?;
+
+    Ok((StatusCode::OK, Json(copy)))
+}
+
+pub async fn update_copy(
+    State(db): State<DB>,
+    Json(payload_update_copy): Json<PayloadUpdateBookCopy>,
+) -> ResultStatus<Uuid> {
+    db.get_copy_id(payload_update_copy.id)
+        .await?
+        .ok_or(ApiError::NotFound("Copy"))?;
+
+    let updated_copy: BookCopy = BookCopy::parse(payload_update_copy)?;
+    let copy_uuid: Uuid = db.update_copy(updated_copy).await?;
+
+    Ok((StatusCode::ACCEPTED, Json(copy_uuid)))
+}
+
+pub async fn delete_copy(
+    State(db): State<DB>,
+    Json(incoming_struct): Json<DeletingStruct>,
+) -> ResultStatus<String> {
+    let copy_uuid: Uuid = db
+        .get_copy_id(incoming_struct.id)
+        .await?
+        .ok_or(ApiError::NotFound("Copy"))?;
+    let copy_uuid: Uuid = db.delete_copy(copy_uuid).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(format!("Copy {copy_uuid} deleted")),
+    ))
+}
+
+pub async fn count_copies(State(db): State<DB>) -> ResultStatus<i64> {
+    let num: i64 = db.count_copies().await?;
+
+    Ok((StatusCode::OK, Json(num)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{env::var, sync::Arc};
+
+    use axum::Router;
+    use axum_test::{TestResponse, TestServer};
+    use serde_json::json;
+    use time::{error::ComponentRange, Date, Month};
+
+    use crate::database::conn::Database;
+    use crate::error::ErrorBody;
+    use crate::router::router;
+    use shared::structs::{
+        author::PayloadAuthor,
+        book::{PayloadBook, PayloadContributor},
+        copy::{BookCopies, CopyStatus},
+        publisher::PayloadPublisher,
+    };
+
+    const DEFAULT_NAME: &str = "Name";
+    const DEFAULT_EDITOR: &str = "Editor";
+    const DEFAULT_SHELF_LOCATION: &str = "A-01";
+    const DEFAULT_DATE: Result<Date, ComponentRange> =
+        Date::from_calendar_date(2000, Month::January, 1);
+
+    async fn server() -> TestServer {
+        let db_url: String = var("DATABASE_URL").unwrap();
+        let db: Database = Database::conn(&db_url).await;
+
+        let app: Router = router(Arc::new(db));
+
+        TestServer::new(app).unwrap()
+    }
+
+    async fn create_book_on_server() -> Uuid {
+        let payload_author: PayloadAuthor = PayloadAuthor {
+            name: DEFAULT_NAME.to_string(),
+            born: DEFAULT_DATE.unwrap(),
+        };
+
+        let author_uuid: Uuid = server()
+            .await
+            .post("/author/create")
+            .json(&json!(payload_author))
+            .await
+            .json();
+
+        let publisher_uuid: Uuid = server()
+            .await
+            .post("/publisher/create")
+            .json(&json!(PayloadPublisher {
+                name: format!("{DEFAULT_EDITOR} {}", Uuid::new_v4().simple()),
+            }))
+            .await
+            .json();
+
+        let payload_book: PayloadBook = PayloadBook {
+            name: DEFAULT_NAME.to_string(),
+            isbn: None,
+            contributors: vec![PayloadContributor {
+                author_uuid,
+                role: "author".to_string(),
+            }],
+            subjects: vec![],
+            publisher_uuid,
+            release: DEFAULT_DATE.unwrap(),
+        };
+
+        server()
+            .await
+            .post("/book/create")
+            .json(&json!(payload_book))
+            .await
+            .json()
+    }
+
+    fn create_payload_copy(book_uuid: Uuid) -> PayloadBookCopy {
+        PayloadBookCopy {
+            book_uuid,
+            barcode: Uuid::new_v4().simple().to_string(),
+            condition: "good".to_string(),
+            shelf_location: DEFAULT_SHELF_LOCATION.to_string(),
+            status: "available".to_string(),
+        }
+    }
+
+    async fn create_copy_on_server(book_uuid: Uuid) -> TestResponse {
+        server()
+            .await
+            .post("/copy/create")
+            .json(&json!(create_payload_copy(book_uuid)))
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_create_copy_get() {
+        let res: TestResponse = server().await.get("/copy/create").await;
+
+        res.assert_status(StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[tokio::test]
+    async fn test_create_copy_post_invalid_fields() {
+        let mut payload_copy: PayloadBookCopy = create_payload_copy(Uuid::new_v4());
+        payload_copy.condition = "shiny".to_string();
+        payload_copy.status = "borrowed".to_string();
+
+        let res: TestResponse = server()
+            .await
+            .post("/copy/create")
+            .json(&json!(payload_copy))
+            .await;
+
+        res.assert_status_unprocessable_entity();
+        let res_json: ErrorBody = res.json();
+        assert_eq!(res_json.field, Some("condition".to_string()));
+        assert_eq!(res_json.errors.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_create_copy_post_book_not_found() {
+        let res: TestResponse = create_copy_on_server(Uuid::new_v4()).await;
+
+        res.assert_status_not_found();
+        assert_eq!(res.json::<ErrorBody>().message, "Book not found");
+    }
+
+    #[tokio::test]
+    async fn test_create_copy_post_valid() {
+        let res: TestResponse = create_copy_on_server(create_book_on_server().await).await;
+
+        res.assert_status(StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_get_copy_get_not_found() {
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/copy/get/{}", Uuid::new_v4()))
+            .await;
+
+        res.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_get_copy_get_found() {
+        let copy_uuid: Uuid = create_copy_on_server(create_book_on_server().await)
+            .await
+            .json();
+
+        let res: TestResponse = server().await.get(&format!("/copy/get/{copy_uuid}")).await;
+
+        res.assert_status_ok();
+        assert_eq!(res.json::<BookCopy>().id, copy_uuid);
+    }
+
+    #[tokio::test]
+    async fn test_list_book_copies_get() {
+        let book_uuid: Uuid = create_book_on_server().await;
+
+        create_copy_on_server(book_uuid).await;
+        create_copy_on_server(book_uuid).await;
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/book/copies/{book_uuid}"))
+            .await;
+
+        res.assert_status_ok();
+        let res_json: BookCopies = res.json();
+        assert_eq!(res_json.total, 2);
+        assert_eq!(res_json.available, 2);
+    }
+
+    #[tokio::test]
+    async fn test_list_book_copies_get_not_found() {
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/book/copies/{}", Uuid::new_v4()))
+            .await;
+
+        res.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_update_copy_post_valid() {
+        let book_uuid: Uuid = create_book_on_server().await;
+        let copy_uuid: Uuid = create_copy_on_server(book_uuid).await.json();
+
+        let payload_update_copy: PayloadUpdateBookCopy = PayloadUpdateBookCopy {
+            id: copy_uuid,
+            book_uuid,
+            barcode: Uuid::new_v4().simple().to_string(),
+            condition: "damaged".to_string(),
+            shelf_location: DEFAULT_SHELF_LOCATION.to_string(),
+            status: "maintenance".to_string(),
+        };
+
+        let res: TestResponse = server()
+            .await
+            .post("/copy/update")
+            .json(&json!(payload_update_copy))
+            .await;
+
+        res.assert_status(StatusCode::ACCEPTED);
+
+        let copy: BookCopy = server()
+            .await
+            .get(&format!("/copy/get/{copy_uuid}"))
+            .await
+            .json();
+        assert_eq!(copy.status, CopyStatus::Maintenance);
+    }
+
+    #[tokio::test]
+    async fn test_delete_copy_post_valid() {
+        let copy_uuid: Uuid = create_copy_on_server(create_book_on_server().await)
+            .await
+            .json();
+
+        let res: TestResponse = server()
+            .await
+            .post("/copy/delete")
+            .json(&json!({ "id": copy_uuid }))
+            .await;
+
+        res.assert_status(StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_count_copies_get() {
+        let res: TestResponse = server().await.get("/copy/count").await;
+
+        res.assert_status_ok();
+        assert!(res.json::<i64>() >= 0);
+    }
+}
@@ -1,87 +1,250 @@
-use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
-    Json,
-};
+use axum::{extract::State, http::StatusCode};
 use uuid::Uuid;
 
-use shared::structs::costumer::{Costumer, PayloadCostumer, PayloadUpdateCostumer};
+use crate::database::{
+    conn::Database,
+    costumer::{COSTUMER_FUZZY_ORDER_COLUMNS, COSTUMER_ORDER_COLUMNS},
+    rental::RENTAL_ORDER_COLUMNS,
+    Pagination,
+};
+use crate::extract::{Json, Path, Query};
+use shared::structs::{
+    costumer::{
+        Costumer, CostumerBlock, CostumerFilter, CostumerSummary, Document, PayloadBlockCostumer,
+        PayloadCostumer, PayloadUnblockCostumer, PayloadUpdateCostumer,
+    },
+    duplicate::{CostumerMergeSummary, DuplicateGroup, Merge, PayloadMerge},
+    page::Page,
+    rental::RentalWithCostumerAndBook,
+};
+
+use super::{ApiError, DeletingStruct, PageURL, QueryURL, ResultStatus, DB};
+
+async fn check_document_available(db: &Database, costumer: &Costumer) -> Result<(), ApiError> {
+    match db.get_costumer_id_by_document(&costumer.document).await? {
+        Some(existing_id) if existing_id != costumer.id => Err(ApiError::Duplicate(
+            "document_exists",
+            "Document already registered",
+            existing_id,
+        )),
+        _ => Ok(()),
+    }
+}
 
-use super::{DeletingStruct, QueryURL, ResultStatus, DB};
+async fn document_conflict(db: &Database, document: &Document, error: sqlx::Error) -> ApiError {
+    match &error {
+        sqlx::Error::Database(db_error)
+            if db_error.constraint() == Some("idx_costumers_document") =>
+        {
+            match db.get_costumer_id_by_document(document).await {
+                Ok(Some(existing_id)) => ApiError::Duplicate(
+                    "document_exists",
+                    "Document already registered",
+                    existing_id,
+                ),
+                _ => ApiError::from(error),
+            }
+        }
+        _ => ApiError::from(error),
+    }
+}
 
 pub async fn create_costumer(
     State(db): State<DB>,
     Json(incoming_costumer): Json<PayloadCostumer>,
 ) -> ResultStatus<Uuid> {
-    match Costumer::create(incoming_costumer) {
-        Ok(costumer) => match db.create_costumer(costumer).await {
-            Ok(costumer_uuid) => Ok((StatusCode::CREATED, Json(costumer_uuid))),
-            Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-        },
-        Err(_) => Err(StatusCode::UNPROCESSABLE_ENTITY),
-    }
+    let costumer: Costumer = Costumer::create(incoming_costumer)?;
+    check_document_available(&db, &costumer).await?;
+    let document: Document = costumer.document.clone();
+    let costumer_uuid: Uuid = match db.create_costumer(costumer).await {
+        Ok(costumer_uuid) => costumer_uuid,
+        Err(error) => return Err(document_conflict(&db, &document, error).await),
+    };
+
+    Ok((StatusCode::CREATED, Json(costumer_uuid)))
 }
 
 pub async fn get_costumer(
     State(db): State<DB>,
     Path(costumer_uuid): Path<Uuid>,
 ) -> ResultStatus<Costumer> {
-    match db.get_costumer(costumer_uuid).await {
-        Ok(Some(costumer)) => Ok((StatusCode::OK, Json(costumer))),
-        Ok(None) => Err(StatusCode::NOT_FOUND),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    let costumer: Costumer = db
+        .get_costumer(costumer_uuid)
+        .await?
+        .ok_or(ApiError::NotFound("Costumer"))?;
+
+    Ok((StatusCode::OK, Json(costumer)))
 }
 
 pub async fn search_costumers(
     State(db): State<DB>,
     Query(t): Query<QueryURL>,
-) -> ResultStatus<Vec<Costumer>> {
-    match db.search_costumers(t.token).await {
-        Ok(costumers_vec) => Ok((StatusCode::OK, Json(costumers_vec))),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    Query(filter): Query<CostumerFilter>,
+) -> ResultStatus<Page<Costumer>> {
+    let page: Page<Costumer> = match t.fuzzy_threshold()? {
+        Some(threshold) => {
+            let pagination: Pagination = t.ranked_pagination(COSTUMER_FUZZY_ORDER_COLUMNS)?;
+            db.search_costumers_fuzzy(t.token, threshold, filter, pagination)
+                .await?
+        }
+        None => {
+            let pagination: Pagination = t.pagination(COSTUMER_ORDER_COLUMNS)?;
+            db.search_costumers(t.token, filter, pagination).await?
+        }
+    };
+
+    Ok((StatusCode::OK, Json(page)))
+}
+
+pub async fn list_costumer_duplicates(
+    State(db): State<DB>,
+) -> ResultStatus<Vec<DuplicateGroup<Costumer>>> {
+    let duplicates: Vec<DuplicateGroup<Costumer>> = db.list_costumer_duplicates().await?;
+
+    Ok((StatusCode::OK, Json(duplicates)))
 }
 
 pub async fn update_costumer(
     State(db): State<DB>,
     Json(payload_update_costumer): Json<PayloadUpdateCostumer>,
 ) -> ResultStatus<Uuid> {
-    match db.get_costumer_id(payload_update_costumer.id).await {
-        Ok(Some(_costumer_uuid)) => match Costumer::parse(payload_update_costumer) {
-            Ok(updated_costumer) => match db.update_costumer(updated_costumer).await {
-                Ok(costumer_uuid) => Ok((StatusCode::ACCEPTED, Json(costumer_uuid))),
-                Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-            },
-            Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-        },
-        Ok(None) => Err(StatusCode::NOT_FOUND),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    db.get_costumer_id(payload_update_costumer.id)
+        .await?
+        .ok_or(ApiError::NotFound("Costumer"))?;
+
+    let updated_costumer: Costumer = Costumer::parse(payload_update_costumer)?;
+    check_document_available(&db, &updated_costumer).await?;
+    let document: Document = updated_costumer.document.clone();
+    let costumer_uuid: Uuid = match db.update_costumer(updated_costumer).await {
+        Ok(costumer_uuid) => costumer_uuid,
+        Err(error) => return Err(document_conflict(&db, &document, error).await),
+    };
+
+    Ok((StatusCode::ACCEPTED, Json(costumer_uuid)))
+}
+
+pub async fn merge_costumers(
+    State(db): State<DB>,
+    Json(payload_merge): Json<PayloadMerge>,
+) -> ResultStatus<CostumerMergeSummary> {
+    let merge: Merge = Merge::parse(payload_merge).ok_or(ApiError::Conflict(
+        "merge_into_itself",
+        "Survivor and loser must be different records",
+    ))?;
+
+    for costumer_uuid in [merge.survivor_uuid, merge.loser_uuid] {
+        db.get_costumer_id(costumer_uuid)
+            .await?
+            .ok_or(ApiError::NotFound("Costumer"))?;
     }
+
+    let summary: CostumerMergeSummary = db.merge_costumers(merge).await?;
+
+    Ok((StatusCode::OK, Json(summary)))
 }
 
 pub async fn delete_costumer(
     State(db): State<DB>,
     Json(incoming_struct): Json<DeletingStruct>,
 ) -> ResultStatus<String> {
-    match db.get_costumer(incoming_struct.id).await {
-        Ok(Some(costumer)) => match db.delete_costumer(costumer.id).await {
-            Ok(costumer_uuid) => Ok((
-                StatusCode::NO_CONTENT,
-                Json(format!("Costumer {costumer_uuid} deleted")),
-            )),
-            Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-        },
-        Ok(None) => Err(StatusCode::NOT_FOUND),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    let costumer_uuid: Uuid = db
+        .get_costumer_id(incoming_struct.id)
+        .await?
+        .ok_or(ApiError::NotFound("Costumer"))?;
+    let costumer_uuid: Uuid = db.delete_costumer(costumer_uuid).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(format!("Costumer {costumer_uuid} deleted")),
+    ))
+}
+
+pub async fn costumer_loans(
+    State(db): State<DB>,
+    Path(costumer_uuid): Path<Uuid>,
+) -> ResultStatus<Vec<RentalWithCostumerAndBook>> {
+    db.get_costumer_id(costumer_uuid)
+        .await?
+        .ok_or(ApiError::NotFound("Costumer"))?;
+
+    let rentals: Vec<RentalWithCostumerAndBook> = db.costumer_loans(costumer_uuid).await?;
+
+    Ok((StatusCode::OK, Json(rentals)))
+}
+
+pub async fn costumer_rentals(
+    State(db): State<DB>,
+    Path(costumer_uuid): Path<Uuid>,
+    Query(p): Query<PageURL>,
+) -> ResultStatus<Page<RentalWithCostumerAndBook>> {
+    let pagination: Pagination = p.pagination(RENTAL_ORDER_COLUMNS)?;
+
+    db.get_costumer_id(costumer_uuid)
+        .await?
+        .ok_or(ApiError::NotFound("Costumer"))?;
+
+    let page: Page<RentalWithCostumerAndBook> =
+        db.costumer_rentals(costumer_uuid, pagination).await?;
+
+    Ok((StatusCode::OK, Json(page)))
+}
+
+pub async fn costumer_summary(
+    State(db): State<DB>,
+    Path(costumer_uuid): Path<Uuid>,
+) -> ResultStatus<CostumerSummary> {
+    let summary: CostumerSummary = db
+        .costumer_summary(costumer_uuid)
+        .await?
+        .ok_or(ApiError::NotFound("Costumer"))?;
+
+    Ok((StatusCode::OK, Json(summary)))
+}
+
+pub async fn block_costumer(
+    State(db): State<DB>,
+    Json(payload_block): Json<PayloadBlockCostumer>,
+) -> ResultStatus<Uuid> {
+    db.get_costumer_id(payload_block.id)
+        .await?
+        .ok_or(ApiError::NotFound("Costumer"))?;
+
+    let block: CostumerBlock = CostumerBlock::create(payload_block)?;
+    let costumer_uuid: Uuid = db.block_costumer(block).await?;
+
+    Ok((StatusCode::ACCEPTED, Json(costumer_uuid)))
+}
+
+pub async fn unblock_costumer(
+    State(db): State<DB>,
+    Json(payload_unblock): Json<PayloadUnblockCostumer>,
+) -> ResultStatus<Uuid> {
+    let costumer_uuid: Uuid = db
+        .get_costumer_id(payload_unblock.id)
+        .await?
+        .ok_or(ApiError::NotFound("Costumer"))?;
+    let costumer_uuid: Uuid = db.unblock_costumer(costumer_uuid).await?;
+
+    Ok((StatusCode::ACCEPTED, Json(costumer_uuid)))
+}
+
+pub async fn get_costumer_block(
+    State(db): State<DB>,
+    Path(costumer_uuid): Path<Uuid>,
+) -> ResultStatus<Option<CostumerBlock>> {
+    db.get_costumer_id(costumer_uuid)
+        .await?
+        .ok_or(ApiError::NotFound("Costumer"))?;
+
+    let block: Option<CostumerBlock> = db.get_costumer_block(costumer_uuid).await?;
+
+    Ok((StatusCode::OK, Json(block)))
 }
 
 pub async fn count_costumers(State(db): State<DB>) -> ResultStatus<i64> {
-    match db.count_costumers().await {
-        Ok(num) => Ok((StatusCode::OK, Json(num))),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    let num: i64 = db.count_costumers().await?;
+
+    Ok((StatusCode::OK, Json(num)))
 }
 
 #[cfg(test)]
@@ -96,17 +259,25 @@ mod tests {
     use time::{error::ComponentRange, Date, Month};
 
     use crate::database::conn::Database;
+    use crate::error::{ErrorBody, FieldErrorBody};
     use crate::router::router;
+    use shared::structs::{costumer::PayloadDocument, ConversionError};
 
-    const DEFAULT_NAME: &'static str = "Name";
-    const DEFAULT_DOCUMENT: &'static str = "12345678901";
+    const DEFAULT_NAME: &str = "Name";
     const DEFAULT_BORN: Result<Date, ComponentRange> =
         Date::from_calendar_date(2000, Month::January, 1);
 
+    fn random_document() -> PayloadDocument {
+        PayloadDocument {
+            kind: "rg".to_string(),
+            value: format!("{:014}", Uuid::new_v4().as_u128() % 10u128.pow(14)),
+        }
+    }
+
     fn create_payload_costumer() -> PayloadCostumer {
         PayloadCostumer {
             name: DEFAULT_NAME.to_string(),
-            document: DEFAULT_DOCUMENT.to_string(),
+            document: random_document(),
             born: DEFAULT_BORN.unwrap(),
         }
     }
@@ -128,6 +299,13 @@ mod tests {
             .await
     }
 
+    fn random_letters() -> String {
+        Uuid::new_v4().simple().to_string()[..12]
+            .chars()
+            .map(|c: char| (b'a' + c.to_digit(16).unwrap() as u8) as char)
+            .collect()
+    }
+
     #[tokio::test]
     async fn test_create_costumer_get() {
         let res: TestResponse = server().await.get("/costumer/create").await;
@@ -153,6 +331,39 @@ mod tests {
         res.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
     }
 
+    #[tokio::test]
+    async fn test_create_costumer_post_invalid_fields() {
+        let res: TestResponse = server()
+            .await
+            .post("/costumer/create")
+            .json(&json!({
+                "name": "Name 0",
+                "document": {"kind": "cpf", "value": "123"},
+                "born": "2000-01-01"
+            }))
+            .await;
+
+        res.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+        res.assert_json(&ErrorBody {
+            code: "invalid_type".to_string(),
+            field: Some("name".to_string()),
+            message: ConversionError::InvalidType.message().to_string(),
+            errors: vec![
+                FieldErrorBody {
+                    code: "invalid_type".to_string(),
+                    field: "name".to_string(),
+                    message: ConversionError::InvalidType.message().to_string(),
+                },
+                FieldErrorBody {
+                    code: "token_incompatible_size".to_string(),
+                    field: "document".to_string(),
+                    message: ConversionError::TokenIncompatibleSize.message().to_string(),
+                },
+            ],
+            existing_id: None,
+        });
+    }
+
     #[tokio::test]
     async fn test_create_costumer_post_valid() {
         let res: TestResponse = create_costumer_on_server().await;
@@ -160,6 +371,67 @@ mod tests {
         res.assert_status(StatusCode::CREATED);
     }
 
+    #[tokio::test]
+    async fn test_create_costumer_post_duplicated_document() {
+        let payload_costumer: PayloadCostumer = create_payload_costumer();
+
+        let costumer_uuid: Uuid = server()
+            .await
+            .post("/costumer/create")
+            .json(&json!(payload_costumer))
+            .await
+            .json();
+
+        let res: TestResponse = server()
+            .await
+            .post("/costumer/create")
+            .json(&json!(payload_costumer))
+            .await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        let res_json: ErrorBody = res.json();
+        assert_eq!(res_json.code, "document_exists");
+        assert_eq!(res_json.existing_id, Some(costumer_uuid));
+    }
+
+    #[tokio::test]
+    async fn test_document_conflict_existing_id() {
+        let db_url: String = var("DATABASE_URL").unwrap();
+        let db: Database = Database::conn(&db_url).await;
+
+        let payload_costumer: PayloadCostumer = create_payload_costumer();
+        let document: PayloadDocument = PayloadDocument {
+            kind: payload_costumer.document.kind.clone(),
+            value: payload_costumer.document.value.clone(),
+        };
+
+        let costumer: Costumer = Costumer::create(payload_costumer).unwrap();
+        let costumer_uuid: Uuid = db.create_costumer(costumer.clone()).await.unwrap();
+
+        let racing_costumer: Costumer = Costumer::create(PayloadCostumer {
+            name: DEFAULT_NAME.to_string(),
+            document,
+            born: DEFAULT_BORN.unwrap(),
+        })
+        .unwrap();
+        let error: sqlx::Error = db.create_costumer(racing_costumer).await.unwrap_err();
+
+        let api_error: ApiError = document_conflict(&db, &costumer.document, error).await;
+
+        assert!(matches!(
+            api_error,
+            ApiError::Duplicate("document_exists", _, existing_id) if existing_id == costumer_uuid
+        ));
+
+        let api_error: ApiError =
+            document_conflict(&db, &costumer.document, sqlx::Error::RowNotFound).await;
+
+        assert!(matches!(
+            api_error,
+            ApiError::Database(sqlx::Error::RowNotFound)
+        ));
+    }
+
     #[tokio::test]
     async fn test_get_costumer_get_empty() {
         let res: TestResponse = server().await.get("/costumer/get/").await;
@@ -222,9 +494,17 @@ mod tests {
 
     #[tokio::test]
     async fn test_search_costumers_get_found() {
-        let create_res: TestResponse = create_costumer_on_server().await;
+        let letters: String = random_letters();
 
-        let created_costumer_uuid: Uuid = create_res.json();
+        let mut payload_costumer: PayloadCostumer = create_payload_costumer();
+        payload_costumer.name = format!("{DEFAULT_NAME} {letters}");
+
+        let created_costumer_uuid: Uuid = server()
+            .await
+            .post("/costumer/create")
+            .json(&json!(payload_costumer))
+            .await
+            .json();
 
         let created_costumer: Costumer = server()
             .await
@@ -232,20 +512,133 @@ mod tests {
             .await
             .json();
 
-        let res: TestResponse = server().await.get("/costumer/search?token").await;
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/costumer/search?token={letters}"))
+            .await;
         res.assert_status_ok();
-        let res_json: Vec<Costumer> = res.json();
+        let res_json: Vec<Costumer> = res.json::<Page<Costumer>>().items;
         assert!(res_json.contains(&created_costumer));
 
-        let res: TestResponse = server().await.get("/costumer/search?token=").await;
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/costumer/search?token={}", &letters[2..8]))
+            .await;
         res.assert_status_ok();
-        let res_json: Vec<Costumer> = res.json();
+        let res_json: Vec<Costumer> = res.json::<Page<Costumer>>().items;
         assert!(res_json.contains(&created_costumer));
+    }
+
+    #[tokio::test]
+    async fn test_search_costumers_get_fuzzy() {
+        let letters: String = random_letters();
+
+        let mut payload_costumer: PayloadCostumer = create_payload_costumer();
+        payload_costumer.name = format!("Capitu {letters}");
+
+        let costumer_uuid: Uuid = server()
+            .await
+            .post("/costumer/create")
+            .json(&json!(payload_costumer))
+            .await
+            .json();
 
-        let res: TestResponse = server().await.get("/costumer/search?token=am").await;
+        let res: TestResponse = server()
+            .await
+            .get(&format!(
+                "/costumer/search?token=capito%20{}&fuzzy=true",
+                &letters[1..]
+            ))
+            .await;
         res.assert_status_ok();
-        let res_json: Vec<Costumer> = res.json();
-        assert!(res_json.contains(&created_costumer));
+        let res_json: Page<Costumer> = res.json();
+        assert_eq!(res_json.items[0].id, costumer_uuid);
+    }
+
+    #[tokio::test]
+    async fn test_search_costumers_get_by_document_kind() {
+        let passport: String = format!("p{}1", &Uuid::new_v4().simple().to_string()[..7]);
+
+        let mut payload_costumer: PayloadCostumer = create_payload_costumer();
+        payload_costumer.document = PayloadDocument {
+            kind: "passport".to_string(),
+            value: passport.clone(),
+        };
+
+        let costumer_uuid: Uuid = server()
+            .await
+            .post("/costumer/create")
+            .json(&json!(payload_costumer))
+            .await
+            .json();
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!(
+                "/costumer/search?token=&document_kind=passport&document={passport}"
+            ))
+            .await;
+        res.assert_status_ok();
+        let res_json: Page<Costumer> = res.json();
+        assert_eq!(res_json.total, 1);
+        assert_eq!(res_json.items[0].id, costumer_uuid);
+        assert_eq!(res_json.items[0].document.as_str(), passport.to_uppercase());
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!(
+                "/costumer/search?token=&document_kind=cpf&document={passport}"
+            ))
+            .await;
+        res.assert_status_ok();
+        assert_eq!(res.json::<Page<Costumer>>().total, 0);
+
+        let res: TestResponse = server()
+            .await
+            .get("/costumer/search?token=&document_kind=ssn")
+            .await;
+        res.assert_status_bad_request();
+    }
+
+    #[tokio::test]
+    async fn test_list_costumer_duplicates_get() {
+        let letters: String = random_letters();
+
+        let mut payload_costumer: PayloadCostumer = create_payload_costumer();
+        payload_costumer.name = format!("Bentinho {letters}");
+        let costumer_uuid: Uuid = server()
+            .await
+            .post("/costumer/create")
+            .json(&json!(payload_costumer))
+            .await
+            .json();
+
+        let mut payload_duplicate: PayloadCostumer = create_payload_costumer();
+        payload_duplicate.name = format!("bentinho {}", letters.to_uppercase());
+        let duplicate_uuid: Uuid = server()
+            .await
+            .post("/costumer/create")
+            .json(&json!(payload_duplicate))
+            .await
+            .json();
+
+        let res: TestResponse = server().await.get("/costumer/duplicates").await;
+
+        res.assert_status_ok();
+        let res_json: Vec<DuplicateGroup<Costumer>> = res.json();
+        let group: &DuplicateGroup<Costumer> = res_json
+            .iter()
+            .find(|group: &&DuplicateGroup<Costumer>| group.key == format!("bentinho{letters}"))
+            .unwrap();
+        let mut ids: Vec<Uuid> = group
+            .items
+            .iter()
+            .map(|costumer: &Costumer| costumer.id)
+            .collect();
+        ids.sort();
+        let mut expected: Vec<Uuid> = vec![costumer_uuid, duplicate_uuid];
+        expected.sort();
+        assert_eq!(ids, expected);
     }
 
     #[tokio::test]
@@ -289,7 +682,7 @@ mod tests {
         let payload_update_costumer: PayloadUpdateCostumer = PayloadUpdateCostumer {
             id: created_costumer_uuid,
             name: DEFAULT_NAME.to_string(),
-            document: DEFAULT_DOCUMENT.to_string(),
+            document: random_document(),
             born: DEFAULT_BORN.unwrap(),
         };
 
@@ -302,6 +695,95 @@ mod tests {
         res.assert_status(StatusCode::ACCEPTED);
     }
 
+    #[tokio::test]
+    async fn test_update_costumer_post_duplicated_document() {
+        let payload_costumer: PayloadCostumer = create_payload_costumer();
+        let existing_uuid: Uuid = server()
+            .await
+            .post("/costumer/create")
+            .json(&json!(payload_costumer))
+            .await
+            .json();
+        let costumer_uuid: Uuid = create_costumer_on_server().await.json();
+
+        let res: TestResponse = server()
+            .await
+            .post("/costumer/update")
+            .json(&json!(PayloadUpdateCostumer {
+                id: costumer_uuid,
+                name: DEFAULT_NAME.to_string(),
+                document: payload_costumer.document.clone(),
+                born: DEFAULT_BORN.unwrap(),
+            }))
+            .await;
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().existing_id, Some(existing_uuid));
+
+        let res: TestResponse = server()
+            .await
+            .post("/costumer/update")
+            .json(&json!(PayloadUpdateCostumer {
+                id: existing_uuid,
+                name: DEFAULT_NAME.to_string(),
+                document: payload_costumer.document,
+                born: DEFAULT_BORN.unwrap(),
+            }))
+            .await;
+        res.assert_status(StatusCode::ACCEPTED);
+    }
+
+    #[tokio::test]
+    async fn test_merge_costumers_post_valid() {
+        let survivor_uuid: Uuid = create_costumer_on_server().await.json();
+        let loser_uuid: Uuid = create_costumer_on_server().await.json();
+
+        let res: TestResponse = server()
+            .await
+            .post("/costumer/merge")
+            .json(&json!(PayloadMerge {
+                survivor_uuid,
+                loser_uuid,
+            }))
+            .await;
+
+        res.assert_status_ok();
+        let res_json: CostumerMergeSummary = res.json();
+        assert_eq!(res_json.survivor_uuid, survivor_uuid);
+        assert_eq!(res_json.loser_uuid, loser_uuid);
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/costumer/get/{loser_uuid}"))
+            .await;
+        res.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_merge_costumers_post_invalid() {
+        let costumer_uuid: Uuid = create_costumer_on_server().await.json();
+
+        let res: TestResponse = server()
+            .await
+            .post("/costumer/merge")
+            .json(&json!(PayloadMerge {
+                survivor_uuid: costumer_uuid,
+                loser_uuid: costumer_uuid,
+            }))
+            .await;
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "merge_into_itself");
+
+        let res: TestResponse = server()
+            .await
+            .post("/costumer/merge")
+            .json(&json!(PayloadMerge {
+                survivor_uuid: costumer_uuid,
+                loser_uuid: Uuid::new_v4(),
+            }))
+            .await;
+        res.assert_status_not_found();
+    }
+
     #[tokio::test]
     async fn test_delete_costumer_get() {
         let res: TestResponse = server().await.post("/costumer/delete").await;
@@ -336,7 +818,7 @@ mod tests {
         let payload_delete_costumer: PayloadUpdateCostumer = PayloadUpdateCostumer {
             id: created_costumer_uuid,
             name: DEFAULT_NAME.to_string(),
-            document: DEFAULT_DOCUMENT.to_string(),
+            document: random_document(),
             born: DEFAULT_BORN.unwrap(),
         };
 
@@ -363,4 +845,163 @@ mod tests {
 
         res.assert_status(StatusCode::METHOD_NOT_ALLOWED);
     }
+
+    #[tokio::test]
+    async fn test_block_costumer_post_not_found() {
+        let res: TestResponse = server()
+            .await
+            .post("/costumer/block")
+            .json(&json!(PayloadBlockCostumer {
+                id: Uuid::new_v4(),
+                reason: "Lost a book".to_string(),
+                until: None,
+            }))
+            .await;
+
+        res.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_block_costumer_post_invalid_reason() {
+        let costumer_uuid: Uuid = create_costumer_on_server().await.json();
+
+        let res: TestResponse = server()
+            .await
+            .post("/costumer/block")
+            .json(&json!(PayloadBlockCostumer {
+                id: costumer_uuid,
+                reason: String::new(),
+                until: None,
+            }))
+            .await;
+
+        res.assert_status_unprocessable_entity();
+        assert_eq!(res.json::<ErrorBody>().field, Some("reason".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_block_costumer_post_valid() {
+        let costumer_uuid: Uuid = create_costumer_on_server().await.json();
+
+        let res: TestResponse = server()
+            .await
+            .post("/costumer/block")
+            .json(&json!(PayloadBlockCostumer {
+                id: costumer_uuid,
+                reason: "Lost a book".to_string(),
+                until: None,
+            }))
+            .await;
+
+        res.assert_status(StatusCode::ACCEPTED);
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/costumer/block/{costumer_uuid}"))
+            .await;
+
+        res.assert_status_ok();
+        let res_json: Option<CostumerBlock> = res.json();
+        assert_eq!(res_json.unwrap().reason.as_str(), "Lost a book");
+
+        server()
+            .await
+            .post("/costumer/unblock")
+            .json(&json!(PayloadUnblockCostumer { id: costumer_uuid }))
+            .await
+            .assert_status(StatusCode::ACCEPTED);
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/costumer/block/{costumer_uuid}"))
+            .await;
+
+        res.assert_status_ok();
+        assert_eq!(res.json::<Option<CostumerBlock>>(), None);
+    }
+
+    #[tokio::test]
+    async fn test_costumer_loans_get_not_found() {
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/costumer/{}/loans", Uuid::new_v4()))
+            .await;
+
+        res.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_costumer_loans_get_empty() {
+        let costumer_uuid: Uuid = create_costumer_on_server().await.json();
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/costumer/{costumer_uuid}/loans"))
+            .await;
+
+        res.assert_status_ok();
+        assert!(res.json::<Vec<RentalWithCostumerAndBook>>().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_costumer_rentals_get() {
+        let costumer_uuid: Uuid = create_costumer_on_server().await.json();
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!(
+                "/costumer/{costumer_uuid}/rentals?limit=10&order_by=due_date"
+            ))
+            .await;
+
+        res.assert_status_ok();
+        let res_json: Page<RentalWithCostumerAndBook> = res.json();
+        assert_eq!(res_json.total, 0);
+        assert_eq!(res_json.limit, Some(10));
+    }
+
+    #[tokio::test]
+    async fn test_costumer_rentals_get_invalid_order() {
+        let costumer_uuid: Uuid = create_costumer_on_server().await.json();
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/costumer/{costumer_uuid}/rentals?order_by=name"))
+            .await;
+
+        res.assert_status_bad_request();
+        assert_eq!(res.json::<ErrorBody>().field, Some("order_by".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_costumer_summary_get() {
+        let costumer_uuid: Uuid = create_costumer_on_server().await.json();
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/costumer/{costumer_uuid}/summary"))
+            .await;
+
+        res.assert_status_ok();
+        assert_eq!(
+            res.json::<CostumerSummary>(),
+            CostumerSummary {
+                id: costumer_uuid,
+                total_borrowed: 0,
+                current_loans: 0,
+                overdue_count: 0,
+                fees_owed: 0,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_costumer_summary_get_not_found() {
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/costumer/{}/summary", Uuid::new_v4()))
+            .await;
+
+        res.assert_status_not_found();
+    }
 }
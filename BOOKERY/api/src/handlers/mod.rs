@@ -1,17 +1,127 @@
 use std::sync::Arc;
 
-use axum::{http::StatusCode, Json};
+use axum::http::StatusCode;
 use serde::Deserialize;
 use uuid::Uuid;
 
-use crate::database::conn::Database;
+use crate::database::{conn::Database, Direction, OrderColumns, Pagination};
+use crate::error::ApiError;
+use crate::extract::Json;
 
 type DB = Arc<Database>;
-type ResultStatus<T> = Result<(StatusCode, Json<T>), StatusCode>;
+type ResultStatus<T> = Result<(StatusCode, Json<T>), ApiError>;
 
-#[derive(Deserialize)]
+const DEFAULT_LIMIT: i64 = 50;
+const MAX_LIMIT: i64 = 500;
+const DEFAULT_FUZZY_THRESHOLD: f32 = 0.6;
+
+#[derive(Deserialize, Default)]
 pub struct QueryURL {
     pub token: String,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    pub order_by: Option<String>,
+    pub direction: Option<Direction>,
+    #[serde(default)]
+    pub fuzzy: bool,
+    pub threshold: Option<f32>,
+}
+
+impl QueryURL {
+    pub fn pagination(&self, order_columns: OrderColumns) -> Result<Pagination, ApiError> {
+        PageURL {
+            limit: self.limit,
+            offset: self.offset,
+            order_by: self.order_by.clone(),
+            direction: self.direction,
+        }
+        .pagination(order_columns)
+    }
+
+    pub fn ranked_pagination(&self, order_columns: OrderColumns) -> Result<Pagination, ApiError> {
+        let mut pagination: Pagination = self.pagination(order_columns)?;
+
+        if self.direction.is_none() && pagination.order_by == order_columns[0].1 {
+            pagination.direction = Direction::Desc;
+        }
+
+        Ok(pagination)
+    }
+
+    pub fn fuzzy_threshold(&self) -> Result<Option<f32>, ApiError> {
+        if !self.fuzzy {
+            return Ok(None);
+        }
+
+        let threshold: f32 = self.threshold.unwrap_or(DEFAULT_FUZZY_THRESHOLD);
+
+        if !(threshold > 0.0 && threshold <= 1.0) {
+            return Err(ApiError::InvalidParameter(
+                "threshold",
+                "Must be greater than 0 and at most 1".to_string(),
+            ));
+        }
+
+        Ok(Some(threshold))
+    }
+}
+
+#[derive(Deserialize, Default)]
+pub struct PageURL {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    pub order_by: Option<String>,
+    pub direction: Option<Direction>,
+}
+
+impl PageURL {
+    pub fn pagination(&self, order_columns: OrderColumns) -> Result<Pagination, ApiError> {
+        let mut pagination: Pagination = Pagination::new(order_columns);
+
+        let limit: i64 = self.limit.unwrap_or(DEFAULT_LIMIT);
+
+        if !(1..=MAX_LIMIT).contains(&limit) {
+            return Err(ApiError::InvalidParameter(
+                "limit",
+                format!("Must be between 1 and {MAX_LIMIT}"),
+            ));
+        }
+        pagination.limit = Some(limit);
+
+        if let Some(offset) = self.offset {
+            if offset < 0 {
+                return Err(ApiError::InvalidParameter(
+                    "offset",
+                    "Must not be negative".to_string(),
+                ));
+            }
+            pagination.offset = offset;
+        }
+
+        if let Some(order_by) = &self.order_by {
+            pagination.order_by = match order_columns
+                .iter()
+                .find(|(name, _column): &&(&str, &str)| name == order_by)
+            {
+                Some((_name, column)) => column,
+                None => {
+                    let allowed: Vec<&str> = order_columns
+                        .iter()
+                        .map(|(name, _column): &(&str, &str)| *name)
+                        .collect();
+
+                    return Err(ApiError::InvalidParameter(
+                        "order_by",
+                        format!("Must be one of: {}", allowed.join(", ")),
+                    ));
+                }
+            };
+        }
+
+        pagination.direction = self.direction.unwrap_or_default();
+
+        Ok(pagination)
+    }
 }
 
 #[derive(Deserialize)]
@@ -21,5 +131,9 @@ pub struct DeletingStruct {
 
 pub mod author;
 pub mod book;
+pub mod copy;
 pub mod costumer;
+pub mod publisher;
 pub mod rental;
+pub mod reservation;
+pub mod subject;
@@ -0,0 +1,265 @@
+use axum::{extract::State, http::StatusCode};
+use uuid::Uuid;
+
+use crate::database::{publisher::PUBLISHER_ORDER_COLUMNS, Pagination};
+use crate::extract::{Json, Path, Query};
+use shared::structs::{
+    page::Page,
+    publisher::{PayloadPublisher, PayloadUpdatePublisher, Publisher},
+};
+
+use super::{ApiError, DeletingStruct, QueryURL, ResultStatus, DB};
+
+pub async fn create_publisher(
+    State(db): State<DB>,
+    Json(incoming_publisher): Json<PayloadPublisher>,
+) -> ResultStatus<Uuid> {
+    let publisher: Publisher = Publisher::create(incoming_publisher)?;
+    let publisher_uuid: Uuid = db.create_publisher(publisher).await?;
+
+    Ok((StatusCode::CREATED, Json(publisher_uuid)))
+}
+
+pub async fn get_publisher(
+    State(db): State<DB>,
+    Path(publisher_uuid): Path<Uuid>,
+) -> ResultStatus<Publisher> {
+    let publisher: Publisher = db
+        .get_publisher(publisher_uuid)
+        .await?
+        .ok_or(ApiError::NotFound("Publisher"))?;
+
+    Ok((StatusCode::OK, Json(publisher)))
+}
+
+pub async fn search_publishers(
+    State(db): State<DB>,
+    Query(t): Query<QueryURL>,
+) -> ResultStatus<Page<Publisher>> {
+    let pagination: Pagination = t.pagination(PUBLISHER_ORDER_COLUMNS)?;
+    let page: Page<Publisher> = db.search_publishers(t.token, pagination).await?;
+
+    Ok((StatusCode::OK, Json(page)))
+}
+
+pub async fn update_publisher(
+    State(db): State<DB>,
+    Json(payload_update_publisher): Json<PayloadUpdatePublisher>,
+) -> ResultStatus<Uuid> {
+    db.get_publisher_id(payload_update_publisher.id)
+        .await?
+        .ok_or(ApiError::NotFound("Publisher"))?;
+
+    let updated_publisher: Publisher = Publisher::parse(payload_update_publisher)?;
+    let publisher_uuid: Uuid = db.update_publisher(updated_publisher).await?;
+
+    Ok((StatusCode::ACCEPTED, Json(publisher_uuid)))
+}
+
+pub async fn delete_publisher(
+    State(db): State<DB>,
+    Json(incoming_struct): Json<DeletingStruct>,
+) -> ResultStatus<String> {
+    let publisher_uuid: Uuid = db
+        .get_publisher_id(incoming_struct.id)
+        .await?
+        .ok_or(ApiError::NotFound("Publisher"))?;
+    let publisher_uuid: Uuid = db.delete_publisher(publisher_uuid).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(format!("Publisher {publisher_uuid} deleted")),
+    ))
+}
+
+pub async fn count_publishers(State(db): State<DB>) -> ResultStatus<i64> {
+    let num: i64 = db.count_publishers().await?;
+
+    Ok((StatusCode::OK, Json(num)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{env::var, sync::Arc};
+
+    use axum::Router;
+    use axum_test::{TestResponse, TestServer};
+    use serde_json::json;
+
+    use crate::database::conn::Database;
+    use crate::error::ErrorBody;
+    use crate::router::router;
+
+    fn create_payload_publisher() -> PayloadPublisher {
+        PayloadPublisher {
+            name: format!("Publisher {}", Uuid::new_v4().simple()),
+        }
+    }
+
+    async fn server() -> TestServer {
+        let db_url: String = var("DATABASE_URL").unwrap();
+        let db: Database = Database::conn(&db_url).await;
+
+        let app: Router = router(Arc::new(db));
+
+        TestServer::new(app).unwrap()
+    }
+
+    async fn create_publisher_on_server(payload_publisher: &PayloadPublisher) -> TestResponse {
+        server()
+            .await
+            .post("/publisher/create")
+            .json(&json!(payload_publisher))
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_create_publisher_get() {
+        let res: TestResponse = server().await.get("/publisher/create").await;
+
+        res.assert_status(StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[tokio::test]
+    async fn test_create_publisher_post_invalid_name() {
+        let res: TestResponse = server()
+            .await
+            .post("/publisher/create")
+            .json(&json!({ "name": "x".repeat(65) }))
+            .await;
+
+        res.assert_status_unprocessable_entity();
+        assert_eq!(res.json::<ErrorBody>().field, Some("name".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_create_publisher_post_valid() {
+        let res: TestResponse = create_publisher_on_server(&create_payload_publisher()).await;
+
+        res.assert_status(StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_create_publisher_post_duplicated_name() {
+        let payload_publisher: PayloadPublisher = create_payload_publisher();
+
+        create_publisher_on_server(&payload_publisher).await;
+
+        let res: TestResponse = create_publisher_on_server(&PayloadPublisher {
+            name: payload_publisher.name.to_lowercase(),
+        })
+        .await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "publisher_exists");
+    }
+
+    #[tokio::test]
+    async fn test_get_publisher_get_not_found() {
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/publisher/get/{}", Uuid::new_v4()))
+            .await;
+
+        res.assert_status_not_found();
+        assert_eq!(res.json::<ErrorBody>().message, "Publisher not found");
+    }
+
+    #[tokio::test]
+    async fn test_get_publisher_get_found() {
+        let publisher_uuid: Uuid = create_publisher_on_server(&create_payload_publisher())
+            .await
+            .json();
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/publisher/get/{publisher_uuid}"))
+            .await;
+
+        res.assert_status_ok();
+        assert_eq!(res.json::<Publisher>().id, publisher_uuid);
+    }
+
+    #[tokio::test]
+    async fn test_search_publishers_get_found() {
+        let payload_publisher: PayloadPublisher = create_payload_publisher();
+        let publisher_uuid: Uuid = create_publisher_on_server(&payload_publisher).await.json();
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!(
+                "/publisher/search?token={}",
+                payload_publisher.name.to_lowercase().replace(' ', "%20")
+            ))
+            .await;
+
+        res.assert_status_ok();
+        let res_json: Page<Publisher> = res.json();
+        assert_eq!(res_json.total, 1);
+        assert_eq!(res_json.items[0].id, publisher_uuid);
+
+        let res: TestResponse = server().await.get("/publisher/search").await;
+        res.assert_status_bad_request();
+    }
+
+    #[tokio::test]
+    async fn test_update_publisher_post_valid() {
+        let publisher_uuid: Uuid = create_publisher_on_server(&create_payload_publisher())
+            .await
+            .json();
+
+        let payload_update_publisher: PayloadUpdatePublisher = PayloadUpdatePublisher {
+            id: publisher_uuid,
+            name: create_payload_publisher().name,
+        };
+
+        let res: TestResponse = server()
+            .await
+            .post("/publisher/update")
+            .json(&json!(payload_update_publisher))
+            .await;
+
+        res.assert_status(StatusCode::ACCEPTED);
+    }
+
+    #[tokio::test]
+    async fn test_update_publisher_post_not_found() {
+        let payload_update_publisher: PayloadUpdatePublisher = PayloadUpdatePublisher {
+            id: Uuid::new_v4(),
+            name: create_payload_publisher().name,
+        };
+
+        let res: TestResponse = server()
+            .await
+            .post("/publisher/update")
+            .json(&json!(payload_update_publisher))
+            .await;
+
+        res.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_delete_publisher_post_valid() {
+        let publisher_uuid: Uuid = create_publisher_on_server(&create_payload_publisher())
+            .await
+            .json();
+
+        let res: TestResponse = server()
+            .await
+            .post("/publisher/delete")
+            .json(&json!({ "id": publisher_uuid }))
+            .await;
+
+        res.assert_status(StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_count_publishers_get() {
+        let res: TestResponse = server().await.get("/publisher/count").await;
+
+        res.assert_status_ok();
+        assert!(res.json::<i64>() >= 0);
+    }
+}
@@ -1,110 +1,193 @@
-use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
-    Json,
-};
+use axum::{extract::State, http::StatusCode};
 use uuid::Uuid;
 
-use shared::structs::rental::{
-    PayloadRental, PayloadUpdateRental, Rental, RentalWithCostumerAndBook,
+use crate::database::{
+    rental::{RENTAL_ORDER_COLUMNS, RENTAL_RAW_ORDER_COLUMNS},
+    Pagination,
+};
+use crate::extract::{Json, Path, Query};
+use shared::structs::{
+    page::Page,
+    rental::{
+        OverdueRental, PayloadCheckoutRental, PayloadRenewRental, PayloadRental,
+        PayloadReturnRental, PayloadUpdateRental, Rental, RentalFilter, RentalWithCostumerAndBook,
+        ReturnedRental,
+    },
 };
 
-use super::{DeletingStruct, QueryURL, ResultStatus, DB};
+use super::{ApiError, DeletingStruct, QueryURL, ResultStatus, DB};
 
 pub async fn create_rental(
     State(db): State<DB>,
-    Json(incoming_rent): Json<PayloadRental>,
+    Json(incoming_rental): Json<PayloadRental>,
 ) -> ResultStatus<Uuid> {
-    match Rental::create(incoming_rent) {
-        Ok(rental) => match db.create_rental(rental).await {
-            Ok(rental_uuid) => Ok((StatusCode::CREATED, Json(rental_uuid))),
-            Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-        },
-        Err(_) => Err(StatusCode::UNPROCESSABLE_ENTITY),
+    let rental: Rental = Rental::create(incoming_rental)?;
+
+    let rental_uuid: Uuid = db.create_rental(rental).await??;
+
+    Ok((StatusCode::CREATED, Json(rental_uuid)))
+}
+
+pub async fn checkout_rental(
+    State(db): State<DB>,
+    Json(payload_checkout): Json<PayloadCheckoutRental>,
+) -> ResultStatus<Rental> {
+    let rental: Rental = db
+        .checkout_rental(payload_checkout.costumer_uuid, payload_checkout.copy_uuid)
+        .await??;
+
+    Ok((StatusCode::CREATED, Json(rental)))
+}
+
+pub async fn return_rental(
+    State(db): State<DB>,
+    Json(payload_return): Json<PayloadReturnRental>,
+) -> ResultStatus<ReturnedRental> {
+    db.get_rental_id(payload_return.id)
+        .await?
+        .ok_or(ApiError::NotFound("Rental"))?;
+
+    let returned_rental: ReturnedRental =
+        db.return_rental(payload_return.id)
+            .await?
+            .ok_or(ApiError::Conflict(
+                "already_returned",
+                "Rental was already returned",
+            ))?;
+
+    Ok((StatusCode::OK, Json(returned_rental)))
+}
+
+pub async fn renew_rental(
+    State(db): State<DB>,
+    Json(payload_renew): Json<PayloadRenewRental>,
+) -> ResultStatus<Rental> {
+    let rental: Rental = db
+        .get_rental_raw(payload_renew.id)
+        .await?
+        .ok_or(ApiError::NotFound("Rental"))?;
+
+    if let Some(renewed_rental) = db.renew_rental(rental.id).await? {
+        return Ok((StatusCode::OK, Json(renewed_rental)));
     }
+
+    if rental.returned_at.is_some() {
+        return Err(ApiError::Conflict(
+            "already_returned",
+            "Rental was already returned",
+        ));
+    }
+
+    if !db.policy.renewal.can_renew(rental.renewals) {
+        return Err(ApiError::Conflict(
+            "renewal_limit_reached",
+            "Rental reached the maximum number of renewals",
+        ));
+    }
+
+    if db
+        .reserved_by_other(rental.copy_uuid, rental.costumer_uuid)
+        .await?
+    {
+        return Err(ApiError::Conflict(
+            "rental_reserved",
+            "Book is reserved by another costumer",
+        ));
+    }
+
+    Err(ApiError::Conflict(
+        "rental_overdue",
+        "Overdue rentals cannot be renewed",
+    ))
+}
+
+pub async fn overdue_rentals(State(db): State<DB>) -> ResultStatus<Vec<OverdueRental>> {
+    let rentals: Vec<OverdueRental> = db.overdue_rentals().await?;
+
+    Ok((StatusCode::OK, Json(rentals)))
 }
 
 pub async fn get_rental(
     State(db): State<DB>,
     Path(rental_uuid): Path<Uuid>,
 ) -> ResultStatus<RentalWithCostumerAndBook> {
-    match db.get_rental(rental_uuid).await {
-        Ok(Some(rental)) => Ok((StatusCode::OK, Json(rental))),
-        Ok(None) => Err(StatusCode::NOT_FOUND),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    let rental: RentalWithCostumerAndBook = db
+        .get_rental(rental_uuid)
+        .await?
+        .ok_or(ApiError::NotFound("Rental"))?;
+
+    Ok((StatusCode::OK, Json(rental)))
 }
 
 pub async fn get_rental_raw(
     State(db): State<DB>,
     Path(rental_uuid): Path<Uuid>,
 ) -> ResultStatus<Rental> {
-    match db.get_rental_raw(rental_uuid).await {
-        Ok(Some(rental)) => Ok((StatusCode::OK, Json(rental))),
-        Ok(None) => Err(StatusCode::NOT_FOUND),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    let rental: Rental = db
+        .get_rental_raw(rental_uuid)
+        .await?
+        .ok_or(ApiError::NotFound("Rental"))?;
+
+    Ok((StatusCode::OK, Json(rental)))
 }
 
 pub async fn search_rentals(
     State(db): State<DB>,
     Query(t): Query<QueryURL>,
-) -> ResultStatus<Vec<RentalWithCostumerAndBook>> {
-    match db.search_rentals(t.token).await {
-        Ok(rental_vec) => Ok((StatusCode::OK, Json(rental_vec))),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    Query(filter): Query<RentalFilter>,
+) -> ResultStatus<Page<RentalWithCostumerAndBook>> {
+    let pagination: Pagination = t.pagination(RENTAL_ORDER_COLUMNS)?;
+    let page: Page<RentalWithCostumerAndBook> =
+        db.search_rentals(t.token, filter, pagination).await?;
+
+    Ok((StatusCode::OK, Json(page)))
 }
 
 pub async fn search_rentals_raw(
     State(db): State<DB>,
     Query(t): Query<QueryURL>,
-) -> ResultStatus<Vec<Rental>> {
-    match db.search_rentals_raw(t.token).await {
-        Ok(rental_vec) => Ok((StatusCode::OK, Json(rental_vec))),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    Query(filter): Query<RentalFilter>,
+) -> ResultStatus<Page<Rental>> {
+    let pagination: Pagination = t.pagination(RENTAL_RAW_ORDER_COLUMNS)?;
+    let page: Page<Rental> = db.search_rentals_raw(t.token, filter, pagination).await?;
+
+    Ok((StatusCode::OK, Json(page)))
 }
 
 pub async fn update_rental(
     State(db): State<DB>,
-    Json(payload_update_rent): Json<PayloadUpdateRental>,
+    Json(payload_update_rental): Json<PayloadUpdateRental>,
 ) -> ResultStatus<Uuid> {
-    match db.get_rental_id(payload_update_rent.id).await {
-        Ok(Some(_rental_uuid)) => match Rental::parse(payload_update_rent) {
-            Ok(updated_rent) => match db.update_rental(updated_rent).await {
-                Ok(rental_uuid) => Ok((StatusCode::ACCEPTED, Json(rental_uuid))),
-                Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-            },
-            Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-        },
-        Ok(None) => Err(StatusCode::NOT_FOUND),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    db.get_rental_id(payload_update_rental.id)
+        .await?
+        .ok_or(ApiError::NotFound("Rental"))?;
+
+    let updated_rental: Rental = Rental::parse(payload_update_rental)?;
+    let rental_uuid: Uuid = db.update_rental(updated_rental).await?;
+
+    Ok((StatusCode::ACCEPTED, Json(rental_uuid)))
 }
 
 pub async fn delete_rental(
     State(db): State<DB>,
     Json(incoming_struct): Json<DeletingStruct>,
 ) -> ResultStatus<String> {
-    match db.get_rental_raw(incoming_struct.id).await {
-        Ok(Some(rental)) => match db.delete_rental(rental.id).await {
-            Ok(rental_uuid) => Ok((
-                StatusCode::NO_CONTENT,
-                Json(format!("Rent {rental_uuid} deleted")),
-            )),
-            Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-        },
-        Ok(None) => Err(StatusCode::NOT_FOUND),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    let rental_uuid: Uuid = db
+        .get_rental_id(incoming_struct.id)
+        .await?
+        .ok_or(ApiError::NotFound("Rental"))?;
+    let rental_uuid: Uuid = db.delete_rental(rental_uuid).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(format!("Rent {rental_uuid} deleted")),
+    ))
 }
 
 pub async fn count_rentals(State(db): State<DB>) -> ResultStatus<i64> {
-    match db.count_rentals().await {
-        Ok(num) => Ok((StatusCode::OK, Json(num))),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
+    let num: i64 = db.count_rentals().await?;
+
+    Ok((StatusCode::OK, Json(num)))
 }
 
 #[cfg(test)]
@@ -119,12 +202,19 @@ mod tests {
     use time::{error::ComponentRange, Date, Month};
 
     use crate::database::conn::Database;
+    use crate::error::ErrorBody;
     use crate::router::router;
-    use shared::structs::{author::PayloadAuthor, book::PayloadBook, costumer::PayloadCostumer};
-
-    const DEFAULT_NAME: &'static str = "Name";
-    const DEFAULT_EDITOR: &'static str = "Editor";
-    const DEFAULT_DOCUMENT: &'static str = "12345678901";
+    use shared::structs::{
+        author::PayloadAuthor,
+        book::{PayloadBook, PayloadContributor},
+        copy::{BookCopy, PayloadBookCopy, PayloadUpdateBookCopy},
+        costumer::{PayloadBlockCostumer, PayloadCostumer, PayloadDocument},
+        publisher::PayloadPublisher,
+    };
+
+    const DEFAULT_NAME: &str = "Name";
+    const DEFAULT_EDITOR: &str = "Editor";
+    const DEFAULT_SHELF_LOCATION: &str = "A-01";
     const DEFAULT_BORN: Result<Date, ComponentRange> =
         Date::from_calendar_date(2000, Month::January, 1);
 
@@ -138,6 +228,13 @@ mod tests {
     const DEFAULT_RETURNED_DATE: Result<Date, ComponentRange> =
         Date::from_calendar_date(2000, Month::January, 1);
 
+    fn random_document() -> PayloadDocument {
+        PayloadDocument {
+            kind: "rg".to_string(),
+            value: format!("{:014}", Uuid::new_v4().as_u128() % 10u128.pow(14)),
+        }
+    }
+
     fn create_payload_author() -> PayloadAuthor {
         PayloadAuthor {
             name: DEFAULT_NAME.to_string(),
@@ -148,7 +245,7 @@ mod tests {
     fn create_payload_costumer() -> PayloadCostumer {
         PayloadCostumer {
             name: DEFAULT_NAME.to_string(),
-            document: DEFAULT_DOCUMENT.to_string(),
+            document: random_document(),
             born: DEFAULT_BORN.unwrap(),
         }
     }
@@ -170,13 +267,30 @@ mod tests {
             .await
     }
 
+    async fn create_publisher_on_server() -> Uuid {
+        server()
+            .await
+            .post("/publisher/create")
+            .json(&json!(PayloadPublisher {
+                name: format!("{DEFAULT_EDITOR} {}", Uuid::new_v4().simple()),
+            }))
+            .await
+            .json()
+    }
+
     async fn create_payload_book() -> PayloadBook {
         let author_uuid: Uuid = create_author_on_server().await.json();
+        let publisher_uuid: Uuid = create_publisher_on_server().await;
 
         PayloadBook {
-            name: DEFAULT_NAME.to_string(),
-            author_uuid,
-            editor: DEFAULT_EDITOR.to_string(),
+            name: format!("{DEFAULT_NAME} {}", Uuid::new_v4().simple()),
+            isbn: None,
+            contributors: vec![PayloadContributor {
+                author_uuid,
+                role: "author".to_string(),
+            }],
+            subjects: vec![],
+            publisher_uuid,
             release: DEFAULT_RELEASE.unwrap(),
         }
     }
@@ -189,6 +303,22 @@ mod tests {
             .await
     }
 
+    async fn create_copy_on_server() -> TestResponse {
+        let payload_copy: PayloadBookCopy = PayloadBookCopy {
+            book_uuid: create_book_on_server().await.json(),
+            barcode: Uuid::new_v4().simple().to_string(),
+            condition: "good".to_string(),
+            shelf_location: DEFAULT_SHELF_LOCATION.to_string(),
+            status: "available".to_string(),
+        };
+
+        server()
+            .await
+            .post("/copy/create")
+            .json(&json!(payload_copy))
+            .await
+    }
+
     async fn create_costumer_on_server() -> TestResponse {
         server()
             .await
@@ -198,11 +328,11 @@ mod tests {
     }
 
     async fn create_payload_rental() -> PayloadRental {
-        let book_uuid: Uuid = create_book_on_server().await.json();
+        let copy_uuid: Uuid = create_copy_on_server().await.json();
         let costumer_uuid: Uuid = create_costumer_on_server().await.json();
 
         PayloadRental {
-            book_uuid,
+            copy_uuid,
             costumer_uuid,
             borrowed_at: DEFAULT_BORROWED_DATE.unwrap(),
             due_date: DEFAULT_DUE_DATE.unwrap(),
@@ -249,6 +379,374 @@ mod tests {
         res.assert_status(StatusCode::CREATED);
     }
 
+    #[tokio::test]
+    async fn test_create_rental_post_copy_unavailable() {
+        let payload_rental: PayloadRental = create_payload_rental().await;
+
+        server()
+            .await
+            .post("/rental/create")
+            .json(&json!(payload_rental))
+            .await
+            .assert_status(StatusCode::CREATED);
+
+        let res: TestResponse = server()
+            .await
+            .post("/rental/create")
+            .json(&json!(payload_rental))
+            .await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "copy_unavailable");
+    }
+
+    #[tokio::test]
+    async fn test_checkout_rental_get() {
+        let res: TestResponse = server().await.get("/rental/checkout").await;
+
+        res.assert_status(StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[tokio::test]
+    async fn test_checkout_rental_post_not_found() {
+        let payload_checkout: PayloadCheckoutRental = PayloadCheckoutRental {
+            costumer_uuid: create_costumer_on_server().await.json(),
+            copy_uuid: Uuid::new_v4(),
+        };
+
+        let res: TestResponse = server()
+            .await
+            .post("/rental/checkout")
+            .json(&json!(payload_checkout))
+            .await;
+
+        res.assert_status_not_found();
+        assert_eq!(res.json::<ErrorBody>().message, "Copy not found");
+    }
+
+    #[tokio::test]
+    async fn test_checkout_rental_post_valid() {
+        let payload_checkout: PayloadCheckoutRental = PayloadCheckoutRental {
+            costumer_uuid: create_costumer_on_server().await.json(),
+            copy_uuid: create_copy_on_server().await.json(),
+        };
+
+        let res: TestResponse = server()
+            .await
+            .post("/rental/checkout")
+            .json(&json!(payload_checkout))
+            .await;
+
+        res.assert_status(StatusCode::CREATED);
+        let res_json: Rental = res.json();
+        assert_eq!(res_json.copy_uuid, payload_checkout.copy_uuid);
+        assert_eq!(res_json.returned_at, None);
+
+        let res: TestResponse = server()
+            .await
+            .post("/rental/checkout")
+            .json(&json!(payload_checkout))
+            .await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "copy_unavailable");
+    }
+
+    #[tokio::test]
+    async fn test_checkout_rental_post_copy_not_lendable() {
+        let copy_uuid: Uuid = create_copy_on_server().await.json();
+        let copy: BookCopy = server()
+            .await
+            .get(&format!("/copy/get/{copy_uuid}"))
+            .await
+            .json();
+
+        let payload_update_copy: PayloadUpdateBookCopy = PayloadUpdateBookCopy {
+            id: copy.id,
+            book_uuid: copy.book_uuid,
+            barcode: copy.barcode.as_str(),
+            condition: copy.condition.as_str().to_string(),
+            shelf_location: copy.shelf_location.as_str(),
+            status: "lost".to_string(),
+        };
+
+        server()
+            .await
+            .post("/copy/update")
+            .json(&json!(payload_update_copy))
+            .await
+            .assert_status(StatusCode::ACCEPTED);
+
+        let payload_checkout: PayloadCheckoutRental = PayloadCheckoutRental {
+            costumer_uuid: create_costumer_on_server().await.json(),
+            copy_uuid,
+        };
+
+        let res: TestResponse = server()
+            .await
+            .post("/rental/checkout")
+            .json(&json!(payload_checkout))
+            .await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "copy_not_lendable");
+    }
+
+    #[tokio::test]
+    async fn test_return_rental_post_not_found() {
+        let res: TestResponse = server()
+            .await
+            .post("/rental/return")
+            .json(&json!(PayloadReturnRental { id: Uuid::new_v4() }))
+            .await;
+
+        res.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_return_rental_post_valid() {
+        let payload_checkout: PayloadCheckoutRental = PayloadCheckoutRental {
+            costumer_uuid: create_costumer_on_server().await.json(),
+            copy_uuid: create_copy_on_server().await.json(),
+        };
+
+        let rental: Rental = server()
+            .await
+            .post("/rental/checkout")
+            .json(&json!(payload_checkout))
+            .await
+            .json();
+
+        let res: TestResponse = server()
+            .await
+            .post("/rental/return")
+            .json(&json!(PayloadReturnRental { id: rental.id }))
+            .await;
+
+        res.assert_status_ok();
+        let res_json: ReturnedRental = res.json();
+        assert_eq!(res_json.rental.returned_at, Some(rental.borrowed_at));
+        assert_eq!(res_json.fee, None);
+
+        let res: TestResponse = server()
+            .await
+            .post("/rental/return")
+            .json(&json!(PayloadReturnRental { id: rental.id }))
+            .await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "already_returned");
+
+        server()
+            .await
+            .post("/rental/checkout")
+            .json(&json!(payload_checkout))
+            .await
+            .assert_status(StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_checkout_rental_post_costumer_blocked() {
+        let costumer_uuid: Uuid = create_costumer_on_server().await.json();
+
+        server()
+            .await
+            .post("/costumer/block")
+            .json(&json!(PayloadBlockCostumer {
+                id: costumer_uuid,
+                reason: "Lost a book".to_string(),
+                until: None,
+            }))
+            .await
+            .assert_status(StatusCode::ACCEPTED);
+
+        let res: TestResponse = server()
+            .await
+            .post("/rental/checkout")
+            .json(&json!(PayloadCheckoutRental {
+                costumer_uuid,
+                copy_uuid: create_copy_on_server().await.json(),
+            }))
+            .await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "costumer_blocked");
+    }
+
+    #[tokio::test]
+    async fn test_checkout_rental_post_rental_limit() {
+        let costumer_uuid: Uuid = create_costumer_on_server().await.json();
+
+        let mut res: TestResponse = server()
+            .await
+            .post("/rental/checkout")
+            .json(&json!(PayloadCheckoutRental {
+                costumer_uuid,
+                copy_uuid: create_copy_on_server().await.json(),
+            }))
+            .await;
+
+        while res.status_code() == StatusCode::CREATED {
+            res = server()
+                .await
+                .post("/rental/checkout")
+                .json(&json!(PayloadCheckoutRental {
+                    costumer_uuid,
+                    copy_uuid: create_copy_on_server().await.json(),
+                }))
+                .await;
+        }
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "rental_limit_reached");
+
+        let mut payload_rental: PayloadRental = create_payload_rental().await;
+        payload_rental.costumer_uuid = costumer_uuid;
+
+        let res: TestResponse = server()
+            .await
+            .post("/rental/create")
+            .json(&json!(payload_rental))
+            .await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "rental_limit_reached");
+    }
+
+    #[tokio::test]
+    async fn test_checkout_rental_post_outstanding_fees() {
+        let payload_rental: PayloadRental = create_payload_rental().await;
+        let costumer_uuid: Uuid = payload_rental.costumer_uuid;
+
+        let rental_uuid: Uuid = server()
+            .await
+            .post("/rental/create")
+            .json(&json!(payload_rental))
+            .await
+            .json();
+
+        let returned_rental: ReturnedRental = server()
+            .await
+            .post("/rental/return")
+            .json(&json!(PayloadReturnRental { id: rental_uuid }))
+            .await
+            .json();
+        assert!(returned_rental.fee.is_some());
+
+        let res: TestResponse = server()
+            .await
+            .post("/rental/checkout")
+            .json(&json!(PayloadCheckoutRental {
+                costumer_uuid,
+                copy_uuid: create_copy_on_server().await.json(),
+            }))
+            .await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "outstanding_fees");
+    }
+
+    #[tokio::test]
+    async fn test_renew_rental_post_not_found() {
+        let res: TestResponse = server()
+            .await
+            .post("/rental/renew")
+            .json(&json!(PayloadRenewRental { id: Uuid::new_v4() }))
+            .await;
+
+        res.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_renew_rental_post_valid() {
+        let payload_checkout: PayloadCheckoutRental = PayloadCheckoutRental {
+            costumer_uuid: create_costumer_on_server().await.json(),
+            copy_uuid: create_copy_on_server().await.json(),
+        };
+
+        let rental: Rental = server()
+            .await
+            .post("/rental/checkout")
+            .json(&json!(payload_checkout))
+            .await
+            .json();
+
+        let mut res: TestResponse = server()
+            .await
+            .post("/rental/renew")
+            .json(&json!(PayloadRenewRental { id: rental.id }))
+            .await;
+
+        res.assert_status_ok();
+        let res_json: Rental = res.json();
+        assert!(res_json.due_date > rental.due_date);
+        assert_eq!(res_json.renewals, 1);
+
+        while res.status_code() == StatusCode::OK {
+            res = server()
+                .await
+                .post("/rental/renew")
+                .json(&json!(PayloadRenewRental { id: rental.id }))
+                .await;
+        }
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "renewal_limit_reached");
+    }
+
+    #[tokio::test]
+    async fn test_renew_rental_post_overdue() {
+        let rental_uuid: Uuid = create_rental_on_server().await.json();
+
+        let res: TestResponse = server()
+            .await
+            .post("/rental/renew")
+            .json(&json!(PayloadRenewRental { id: rental_uuid }))
+            .await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "rental_overdue");
+
+        server()
+            .await
+            .post("/rental/return")
+            .json(&json!(PayloadReturnRental { id: rental_uuid }))
+            .await
+            .assert_status_ok();
+
+        let res: TestResponse = server()
+            .await
+            .post("/rental/renew")
+            .json(&json!(PayloadRenewRental { id: rental_uuid }))
+            .await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "already_returned");
+    }
+
+    #[tokio::test]
+    async fn test_overdue_rentals_get() {
+        let rental_uuid: Uuid = create_rental_on_server().await.json();
+
+        let res: TestResponse = server().await.get("/rental/overdue").await;
+
+        res.assert_status_ok();
+        let res_json: Vec<OverdueRental> = res.json();
+        assert!(res_json
+            .iter()
+            .any(|rental: &OverdueRental| rental.id == rental_uuid && rental.days_overdue > 0));
+
+        let res: TestResponse = server()
+            .await
+            .post("/rental/return")
+            .json(&json!(PayloadReturnRental { id: rental_uuid }))
+            .await;
+
+        res.assert_status_ok();
+        let res_json: ReturnedRental = res.json();
+        assert!(res_json.fee.unwrap().amount > 0);
+    }
+
     #[tokio::test]
     async fn test_get_rental_get_empty() {
         let res: TestResponse = server().await.get("/rental/get/").await;
@@ -370,25 +868,28 @@ mod tests {
             .await
             .json();
 
-        let res: TestResponse = server().await.get("/rental/search?token").await;
-        res.assert_status_ok();
-        let res_json: Vec<RentalWithCostumerAndBook> = res.json();
-        assert!(res_json.contains(&created_rent));
+        let book_name: String = created_rent.book_name.as_str();
+        let suffix: &str = book_name.rsplit(' ').next().unwrap();
 
-        let res: TestResponse = server().await.get("/rental/search?token=").await;
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/rental/search?token={suffix}"))
+            .await;
         res.assert_status_ok();
-        let res_json: Vec<RentalWithCostumerAndBook> = res.json();
+        let res_json: Vec<RentalWithCostumerAndBook> =
+            res.json::<Page<RentalWithCostumerAndBook>>().items;
         assert!(res_json.contains(&created_rent));
 
         let res: TestResponse = server()
             .await
             .get(&format!(
-                "/rental/search?token={}",
+                "/rental/search?token={}&book={suffix}",
                 String::from(DEFAULT_NAME)
             ))
             .await;
         res.assert_status_ok();
-        let res_json: Vec<RentalWithCostumerAndBook> = res.json();
+        let res_json: Vec<RentalWithCostumerAndBook> =
+            res.json::<Page<RentalWithCostumerAndBook>>().items;
         assert!(res_json.contains(&created_rent));
     }
 
@@ -422,25 +923,26 @@ mod tests {
             .await
             .json();
 
-        let res: TestResponse = server().await.get("/rental/search-raw?token").await;
-        res.assert_status_ok();
-        let res_json: Vec<Rental> = res.json();
-        assert!(res_json.contains(&created_rent));
-
-        let res: TestResponse = server().await.get("/rental/search-raw?token=").await;
+        let res: TestResponse = server()
+            .await
+            .get(&format!(
+                "/rental/search-raw?token={}",
+                created_rent.costumer_uuid
+            ))
+            .await;
         res.assert_status_ok();
-        let res_json: Vec<Rental> = res.json();
+        let res_json: Vec<Rental> = res.json::<Page<Rental>>().items;
         assert!(res_json.contains(&created_rent));
 
         let res: TestResponse = server()
             .await
             .get(&format!(
                 "/rental/search-raw?token={}",
-                String::from(created_rent.book_uuid).chars().nth(2).unwrap()
+                &String::from(created_rent.copy_uuid)[..13]
             ))
             .await;
         res.assert_status_ok();
-        let res_json: Vec<Rental> = res.json();
+        let res_json: Vec<Rental> = res.json::<Page<Rental>>().items;
         assert!(res_json.contains(&created_rent));
     }
 
@@ -484,7 +986,7 @@ mod tests {
 
         let payload_update_rent: PayloadUpdateRental = PayloadUpdateRental {
             id: created_rental_uuid,
-            book_uuid: create_book_on_server().await.json(),
+            copy_uuid: create_copy_on_server().await.json(),
             costumer_uuid: create_costumer_on_server().await.json(),
             borrowed_at: DEFAULT_BORROWED_DATE.unwrap(),
             due_date: DEFAULT_DUE_DATE.unwrap(),
@@ -532,8 +1034,8 @@ mod tests {
         let created_rental_uuid: Uuid = create_res.json();
 
         let payload_delete_rent: PayloadUpdateRental = PayloadUpdateRental {
-            id: created_rental_uuid.clone(),
-            book_uuid: create_book_on_server().await.json(),
+            id: created_rental_uuid,
+            copy_uuid: create_copy_on_server().await.json(),
             costumer_uuid: create_costumer_on_server().await.json(),
             borrowed_at: DEFAULT_BORROWED_DATE.unwrap(),
             due_date: DEFAULT_DUE_DATE.unwrap(),
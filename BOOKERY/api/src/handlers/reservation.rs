@@ -0,0 +1,418 @@
+use axum::{extract::State, http::StatusCode};
+use uuid::Uuid;
+
+use crate::extract::{Json, Path};
+use shared::structs::reservation::{PayloadCancelReservation, PayloadReservation, Reservation};
+
+use super::{ApiError, ResultStatus, DB};
+
+pub async fn create_reservation(
+    State(db): State<DB>,
+    Json(incoming_reservation): Json<PayloadReservation>,
+) -> ResultStatus<Reservation> {
+    db.get_book_id(incoming_reservation.book_uuid)
+        .await?
+        .ok_or(ApiError::NotFound("Book"))?;
+    db.get_costumer_id(incoming_reservation.costumer_uuid)
+        .await?
+        .ok_or(ApiError::NotFound("Costumer"))?;
+
+    let reservation: Reservation = db.create_reservation(incoming_reservation).await?;
+
+    Ok((StatusCode::CREATED, Json(reservation)))
+}
+
+pub async fn get_reservation(
+    State(db): State<DB>,
+    Path(reservation_uuid): Path<Uuid>,
+) -> ResultStatus<Reservation> {
+    let reservation: Reservation = db
+        .get_reservation(reservation_uuid)
+        .await?
+        .ok_or(ApiError::NotFound("Reservation"))?;
+
+    Ok((StatusCode::OK, Json(reservation)))
+}
+
+pub async fn cancel_reservation(
+    State(db): State<DB>,
+    Json(payload_cancel): Json<PayloadCancelReservation>,
+) -> ResultStatus<Reservation> {
+    db.get_reservation(payload_cancel.id)
+        .await?
+        .ok_or(ApiError::NotFound("Reservation"))?;
+
+    let reservation: Reservation =
+        db.cancel_reservation(payload_cancel.id)
+            .await?
+            .ok_or(ApiError::Conflict(
+                "reservation_inactive",
+                "Reservation is no longer active",
+            ))?;
+
+    Ok((StatusCode::OK, Json(reservation)))
+}
+
+pub async fn count_reservations(State(db): State<DB>) -> ResultStatus<i64> {
+    let num: i64 = db.count_reservations().await?;
+
+    Ok((StatusCode::OK, Json(num)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{env::var, sync::Arc};
+
+    use axum::Router;
+    use axum_test::{TestResponse, TestServer};
+    use serde_json::json;
+    use time::{error::ComponentRange, Date, Month};
+
+    use crate::database::conn::Database;
+    use crate::error::ErrorBody;
+    use crate::router::router;
+    use shared::structs::{
+        author::PayloadAuthor,
+        book::{PayloadBook, PayloadContributor},
+        copy::PayloadBookCopy,
+        costumer::{PayloadCostumer, PayloadDocument},
+        publisher::PayloadPublisher,
+        rental::{PayloadCheckoutRental, PayloadRental, PayloadReturnRental, Rental},
+        reservation::ReservationStatus,
+    };
+
+    const DEFAULT_NAME: &str = "Name";
+    const DEFAULT_EDITOR: &str = "Editor";
+    const DEFAULT_SHELF_LOCATION: &str = "A-01";
+    const DEFAULT_DATE: Result<Date, ComponentRange> =
+        Date::from_calendar_date(2000, Month::January, 1);
+
+    fn random_document() -> PayloadDocument {
+        PayloadDocument {
+            kind: "rg".to_string(),
+            value: format!("{:014}", Uuid::new_v4().as_u128() % 10u128.pow(14)),
+        }
+    }
+
+    async fn server() -> TestServer {
+        let db_url: String = var("DATABASE_URL").unwrap();
+        let db: Database = Database::conn(&db_url).await;
+
+        let app: Router = router(Arc::new(db));
+
+        TestServer::new(app).unwrap()
+    }
+
+    async fn create_book_on_server() -> Uuid {
+        let payload_author: PayloadAuthor = PayloadAuthor {
+            name: DEFAULT_NAME.to_string(),
+            born: DEFAULT_DATE.unwrap(),
+        };
+
+        let author_uuid: Uuid = server()
+            .await
+            .post("/author/create")
+            .json(&json!(payload_author))
+            .await
+            .json();
+
+        let publisher_uuid: Uuid = server()
+            .await
+            .post("/publisher/create")
+            .json(&json!(PayloadPublisher {
+                name: format!("{DEFAULT_EDITOR} {}", Uuid::new_v4().simple()),
+            }))
+            .await
+            .json();
+
+        let payload_book: PayloadBook = PayloadBook {
+            name: DEFAULT_NAME.to_string(),
+            isbn: None,
+            contributors: vec![PayloadContributor {
+                author_uuid,
+                role: "author".to_string(),
+            }],
+            subjects: vec![],
+            publisher_uuid,
+            release: DEFAULT_DATE.unwrap(),
+        };
+
+        server()
+            .await
+            .post("/book/create")
+            .json(&json!(payload_book))
+            .await
+            .json()
+    }
+
+    async fn create_copy_on_server(book_uuid: Uuid) -> Uuid {
+        let payload_copy: PayloadBookCopy = PayloadBookCopy {
+            book_uuid,
+            barcode: Uuid::new_v4().simple().to_string(),
+            condition: "good".to_string(),
+            shelf_location: DEFAULT_SHELF_LOCATION.to_string(),
+            status: "available".to_string(),
+        };
+
+        server()
+            .await
+            .post("/copy/create")
+            .json(&json!(payload_copy))
+            .await
+            .json()
+    }
+
+    async fn create_costumer_on_server() -> Uuid {
+        let payload_costumer: PayloadCostumer = PayloadCostumer {
+            name: DEFAULT_NAME.to_string(),
+            document: random_document(),
+            born: DEFAULT_DATE.unwrap(),
+        };
+
+        server()
+            .await
+            .post("/costumer/create")
+            .json(&json!(payload_costumer))
+            .await
+            .json()
+    }
+
+    async fn checkout_on_server(costumer_uuid: Uuid, copy_uuid: Uuid) -> TestResponse {
+        server()
+            .await
+            .post("/rental/checkout")
+            .json(&json!(PayloadCheckoutRental {
+                costumer_uuid,
+                copy_uuid,
+            }))
+            .await
+    }
+
+    async fn create_reservation_on_server(book_uuid: Uuid, costumer_uuid: Uuid) -> TestResponse {
+        server()
+            .await
+            .post("/reservation/create")
+            .json(&json!(PayloadReservation {
+                book_uuid,
+                costumer_uuid,
+            }))
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_create_reservation_get() {
+        let res: TestResponse = server().await.get("/reservation/create").await;
+
+        res.assert_status(StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[tokio::test]
+    async fn test_create_reservation_post_book_not_found() {
+        let res: TestResponse =
+            create_reservation_on_server(Uuid::new_v4(), create_costumer_on_server().await).await;
+
+        res.assert_status_not_found();
+        assert_eq!(res.json::<ErrorBody>().message, "Book not found");
+    }
+
+    #[tokio::test]
+    async fn test_create_reservation_post_valid() {
+        let book_uuid: Uuid = create_book_on_server().await;
+        let costumer_uuid: Uuid = create_costumer_on_server().await;
+
+        let res: TestResponse = create_reservation_on_server(book_uuid, costumer_uuid).await;
+
+        res.assert_status(StatusCode::CREATED);
+        let res_json: Reservation = res.json();
+        assert_eq!(res_json.status, ReservationStatus::Waiting);
+
+        let res: TestResponse = create_reservation_on_server(book_uuid, costumer_uuid).await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "already_reserved");
+    }
+
+    #[tokio::test]
+    async fn test_get_reservation_get_not_found() {
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/reservation/get/{}", Uuid::new_v4()))
+            .await;
+
+        res.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_reservation_queue_on_return() {
+        let book_uuid: Uuid = create_book_on_server().await;
+        let copy_uuid: Uuid = create_copy_on_server(book_uuid).await;
+        let first_costumer_uuid: Uuid = create_costumer_on_server().await;
+        let second_costumer_uuid: Uuid = create_costumer_on_server().await;
+
+        let rental: Rental = checkout_on_server(create_costumer_on_server().await, copy_uuid)
+            .await
+            .json();
+
+        let reservation: Reservation = create_reservation_on_server(book_uuid, first_costumer_uuid)
+            .await
+            .json();
+        create_reservation_on_server(book_uuid, second_costumer_uuid).await;
+
+        let res: TestResponse = server()
+            .await
+            .post("/rental/renew")
+            .json(&json!({ "id": rental.id }))
+            .await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "rental_reserved");
+
+        server()
+            .await
+            .post("/rental/return")
+            .json(&json!(PayloadReturnRental { id: rental.id }))
+            .await
+            .assert_status_ok();
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/book/reservations/{book_uuid}"))
+            .await;
+
+        res.assert_status_ok();
+        let res_json: Vec<Reservation> = res.json();
+        assert_eq!(res_json.len(), 2);
+        assert_eq!(res_json[0].id, reservation.id);
+        assert_eq!(res_json[0].status, ReservationStatus::Ready);
+
+        let res: TestResponse = checkout_on_server(second_costumer_uuid, copy_uuid).await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "copy_reserved");
+
+        checkout_on_server(first_costumer_uuid, copy_uuid)
+            .await
+            .assert_status(StatusCode::CREATED);
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/reservation/get/{}", reservation.id))
+            .await;
+
+        res.assert_status_ok();
+        assert_eq!(
+            res.json::<Reservation>().status,
+            ReservationStatus::Fulfilled
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_rental_post_reserved() {
+        let book_uuid: Uuid = create_book_on_server().await;
+        let copy_uuid: Uuid = create_copy_on_server(book_uuid).await;
+        let costumer_uuid: Uuid = create_costumer_on_server().await;
+
+        let reservation: Reservation = create_reservation_on_server(book_uuid, costumer_uuid)
+            .await
+            .json();
+
+        let res: TestResponse = server()
+            .await
+            .post("/rental/create")
+            .json(&json!(PayloadRental {
+                costumer_uuid: create_costumer_on_server().await,
+                copy_uuid,
+                borrowed_at: DEFAULT_DATE.unwrap(),
+                due_date: DEFAULT_DATE.unwrap(),
+            }))
+            .await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "copy_reserved");
+
+        server()
+            .await
+            .post("/rental/create")
+            .json(&json!(PayloadRental {
+                costumer_uuid,
+                copy_uuid,
+                borrowed_at: DEFAULT_DATE.unwrap(),
+                due_date: DEFAULT_DATE.unwrap(),
+            }))
+            .await
+            .assert_status(StatusCode::CREATED);
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/reservation/get/{}", reservation.id))
+            .await;
+
+        res.assert_status_ok();
+        assert_eq!(
+            res.json::<Reservation>().status,
+            ReservationStatus::Fulfilled
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_book_reservations_get_not_found() {
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/book/reservations/{}", Uuid::new_v4()))
+            .await;
+
+        res.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_cancel_reservation_post_not_found() {
+        let res: TestResponse = server()
+            .await
+            .post("/reservation/cancel")
+            .json(&json!(PayloadCancelReservation { id: Uuid::new_v4() }))
+            .await;
+
+        res.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_cancel_reservation_post_valid() {
+        let reservation: Reservation = create_reservation_on_server(
+            create_book_on_server().await,
+            create_costumer_on_server().await,
+        )
+        .await
+        .json();
+
+        let res: TestResponse = server()
+            .await
+            .post("/reservation/cancel")
+            .json(&json!(PayloadCancelReservation { id: reservation.id }))
+            .await;
+
+        res.assert_status_ok();
+        assert_eq!(
+            res.json::<Reservation>().status,
+            ReservationStatus::Cancelled
+        );
+
+        let res: TestResponse = server()
+            .await
+            .post("/reservation/cancel")
+            .json(&json!(PayloadCancelReservation { id: reservation.id }))
+            .await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "reservation_inactive");
+    }
+
+    #[tokio::test]
+    async fn test_count_reservations_get() {
+        let res: TestResponse = server().await.get("/reservation/count").await;
+
+        res.assert_status_ok();
+        assert!(res.json::<i64>() >= 0);
+    }
+}
@@ -0,0 +1,377 @@
+use axum::{extract::State, http::StatusCode};
+use uuid::Uuid;
+
+use crate::database::{subject::SUBJECT_ORDER_COLUMNS, Pagination};
+use crate::extract::{Json, Path, Query};
+use shared::structs::{
+    page::Page,
+    subject::{PayloadSubject, PayloadUpdateSubject, Subject},
+};
+
+use super::{ApiError, DeletingStruct, QueryURL, ResultStatus, DB};
+
+pub async fn create_subject(
+    State(db): State<DB>,
+    Json(incoming_subject): Json<PayloadSubject>,
+) -> ResultStatus<Uuid> {
+    let subject: Subject = Subject::create(incoming_subject)?;
+    let subject_uuid: Uuid = db.create_subject(subject).await?;
+
+    Ok((StatusCode::CREATED, Json(subject_uuid)))
+}
+
+pub async fn get_subject(
+    State(db): State<DB>,
+    Path(subject_uuid): Path<Uuid>,
+) -> ResultStatus<Subject> {
+    let subject: Subject = db
+        .get_subject(subject_uuid)
+        .await?
+        .ok_or(ApiError::NotFound("Subject"))?;
+
+    Ok((StatusCode::OK, Json(subject)))
+}
+
+pub async fn list_subject_children(
+    State(db): State<DB>,
+    Path(subject_uuid): Path<Uuid>,
+) -> ResultStatus<Vec<Subject>> {
+    db.get_subject_id(subject_uuid)
+        .await?
+        .ok_or(ApiError::NotFound("Subject"))?;
+
+    let children: Vec<Subject> = db.list_subject_children(subject_uuid).await?;
+
+    Ok((StatusCode::OK, Json(children)))
+}
+
+pub async fn search_subjects(
+    State(db): State<DB>,
+    Query(t): Query<QueryURL>,
+) -> ResultStatus<Page<Subject>> {
+    let pagination: Pagination = t.pagination(SUBJECT_ORDER_COLUMNS)?;
+    let page: Page<Subject> = db.search_subjects(t.token, pagination).await?;
+
+    Ok((StatusCode::OK, Json(page)))
+}
+
+pub async fn update_subject(
+    State(db): State<DB>,
+    Json(payload_update_subject): Json<PayloadUpdateSubject>,
+) -> ResultStatus<Uuid> {
+    db.get_subject_id(payload_update_subject.id)
+        .await?
+        .ok_or(ApiError::NotFound("Subject"))?;
+
+    let updated_subject: Subject = Subject::parse(payload_update_subject)?;
+
+    if let Some(parent_uuid) = updated_subject.parent_uuid {
+        if db
+            .subject_descends_from(parent_uuid, updated_subject.id)
+            .await?
+        {
+            return Err(ApiError::Conflict(
+                "subject_cycle",
+                "Subject cannot be nested under its own descendant",
+            ));
+        }
+    }
+
+    let subject_uuid: Uuid = db.update_subject(updated_subject).await?;
+
+    Ok((StatusCode::ACCEPTED, Json(subject_uuid)))
+}
+
+pub async fn delete_subject(
+    State(db): State<DB>,
+    Json(incoming_struct): Json<DeletingStruct>,
+) -> ResultStatus<String> {
+    let subject_uuid: Uuid = db
+        .get_subject_id(incoming_struct.id)
+        .await?
+        .ok_or(ApiError::NotFound("Subject"))?;
+    let subject_uuid: Uuid = db.delete_subject(subject_uuid).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        Json(format!("Subject {subject_uuid} deleted")),
+    ))
+}
+
+pub async fn count_subjects(State(db): State<DB>) -> ResultStatus<i64> {
+    let num: i64 = db.count_subjects().await?;
+
+    Ok((StatusCode::OK, Json(num)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{env::var, sync::Arc};
+
+    use axum::Router;
+    use axum_test::{TestResponse, TestServer};
+    use serde_json::json;
+
+    use crate::database::conn::Database;
+    use crate::error::ErrorBody;
+    use crate::router::router;
+
+    fn create_payload_subject(parent_uuid: Option<Uuid>) -> PayloadSubject {
+        PayloadSubject {
+            name: format!("Subject {}", Uuid::new_v4().simple()),
+            parent_uuid,
+        }
+    }
+
+    async fn server() -> TestServer {
+        let db_url: String = var("DATABASE_URL").unwrap();
+        let db: Database = Database::conn(&db_url).await;
+
+        let app: Router = router(Arc::new(db));
+
+        TestServer::new(app).unwrap()
+    }
+
+    async fn create_subject_on_server(payload_subject: &PayloadSubject) -> TestResponse {
+        server()
+            .await
+            .post("/subject/create")
+            .json(&json!(payload_subject))
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_create_subject_get() {
+        let res: TestResponse = server().await.get("/subject/create").await;
+
+        res.assert_status(StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[tokio::test]
+    async fn test_create_subject_post_invalid_name() {
+        let res: TestResponse = server()
+            .await
+            .post("/subject/create")
+            .json(&json!({ "name": "  " }))
+            .await;
+
+        res.assert_status_unprocessable_entity();
+        assert_eq!(res.json::<ErrorBody>().field, Some("name".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_create_subject_post_valid() {
+        let res: TestResponse = create_subject_on_server(&create_payload_subject(None)).await;
+
+        res.assert_status(StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_create_subject_post_unknown_parent() {
+        let res: TestResponse =
+            create_subject_on_server(&create_payload_subject(Some(Uuid::new_v4()))).await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "foreign_key_violation");
+    }
+
+    #[tokio::test]
+    async fn test_create_subject_post_duplicated_name() {
+        let payload_subject: PayloadSubject = create_payload_subject(None);
+
+        create_subject_on_server(&payload_subject).await;
+
+        let res: TestResponse = create_subject_on_server(&PayloadSubject {
+            name: payload_subject.name.to_lowercase(),
+            parent_uuid: None,
+        })
+        .await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "subject_exists");
+    }
+
+    #[tokio::test]
+    async fn test_get_subject_get_not_found() {
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/subject/get/{}", Uuid::new_v4()))
+            .await;
+
+        res.assert_status_not_found();
+        assert_eq!(res.json::<ErrorBody>().message, "Subject not found");
+    }
+
+    #[tokio::test]
+    async fn test_get_subject_get_found() {
+        let subject_uuid: Uuid = create_subject_on_server(&create_payload_subject(None))
+            .await
+            .json();
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/subject/get/{subject_uuid}"))
+            .await;
+
+        res.assert_status_ok();
+        assert_eq!(res.json::<Subject>().id, subject_uuid);
+    }
+
+    #[tokio::test]
+    async fn test_list_subject_children_get() {
+        let parent_uuid: Uuid = create_subject_on_server(&create_payload_subject(None))
+            .await
+            .json();
+        let child_uuid: Uuid = create_subject_on_server(&create_payload_subject(Some(parent_uuid)))
+            .await
+            .json();
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/subject/children/{parent_uuid}"))
+            .await;
+
+        res.assert_status_ok();
+        let res_json: Vec<Subject> = res.json();
+        assert_eq!(res_json.len(), 1);
+        assert_eq!(res_json[0].id, child_uuid);
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!("/subject/children/{}", Uuid::new_v4()))
+            .await;
+        res.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_search_subjects_get_found() {
+        let payload_subject: PayloadSubject = create_payload_subject(None);
+        let subject_uuid: Uuid = create_subject_on_server(&payload_subject).await.json();
+
+        let res: TestResponse = server()
+            .await
+            .get(&format!(
+                "/subject/search?token={}",
+                payload_subject.name.to_lowercase().replace(' ', "%20")
+            ))
+            .await;
+
+        res.assert_status_ok();
+        let res_json: Page<Subject> = res.json();
+        assert_eq!(res_json.total, 1);
+        assert_eq!(res_json.items[0].id, subject_uuid);
+
+        let res: TestResponse = server().await.get("/subject/search").await;
+        res.assert_status_bad_request();
+    }
+
+    #[tokio::test]
+    async fn test_update_subject_post_valid() {
+        let subject_uuid: Uuid = create_subject_on_server(&create_payload_subject(None))
+            .await
+            .json();
+
+        let payload_update_subject: PayloadUpdateSubject = PayloadUpdateSubject {
+            id: subject_uuid,
+            name: create_payload_subject(None).name,
+            parent_uuid: None,
+        };
+
+        let res: TestResponse = server()
+            .await
+            .post("/subject/update")
+            .json(&json!(payload_update_subject))
+            .await;
+
+        res.assert_status(StatusCode::ACCEPTED);
+    }
+
+    #[tokio::test]
+    async fn test_update_subject_post_cycle() {
+        let parent_uuid: Uuid = create_subject_on_server(&create_payload_subject(None))
+            .await
+            .json();
+        let child_uuid: Uuid = create_subject_on_server(&create_payload_subject(Some(parent_uuid)))
+            .await
+            .json();
+
+        let payload_update_subject: PayloadUpdateSubject = PayloadUpdateSubject {
+            id: parent_uuid,
+            name: create_payload_subject(None).name,
+            parent_uuid: Some(child_uuid),
+        };
+
+        let res: TestResponse = server()
+            .await
+            .post("/subject/update")
+            .json(&json!(payload_update_subject))
+            .await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "subject_cycle");
+    }
+
+    #[tokio::test]
+    async fn test_update_subject_post_own_parent() {
+        let subject_uuid: Uuid = create_subject_on_server(&create_payload_subject(None))
+            .await
+            .json();
+
+        let payload_update_subject: PayloadUpdateSubject = PayloadUpdateSubject {
+            id: subject_uuid,
+            name: create_payload_subject(None).name,
+            parent_uuid: Some(subject_uuid),
+        };
+
+        let res: TestResponse = server()
+            .await
+            .post("/subject/update")
+            .json(&json!(payload_update_subject))
+            .await;
+
+        res.assert_status(StatusCode::CONFLICT);
+        assert_eq!(res.json::<ErrorBody>().code, "subject_cycle");
+    }
+
+    #[tokio::test]
+    async fn test_update_subject_post_not_found() {
+        let payload_update_subject: PayloadUpdateSubject = PayloadUpdateSubject {
+            id: Uuid::new_v4(),
+            name: create_payload_subject(None).name,
+            parent_uuid: None,
+        };
+
+        let res: TestResponse = server()
+            .await
+            .post("/subject/update")
+            .json(&json!(payload_update_subject))
+            .await;
+
+        res.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_delete_subject_post_valid() {
+        let subject_uuid: Uuid = create_subject_on_server(&create_payload_subject(None))
+            .await
+            .json();
+
+        let res: TestResponse = server()
+            .await
+            .post("/subject/delete")
+            .json(&json!({ "id": subject_uuid }))
+            .await;
+
+        res.assert_status(StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_count_subjects_get() {
+        let res: TestResponse = server().await.get("/subject/count").await;
+
+        res.assert_status_ok();
+        assert!(res.json::<i64>() >= 0);
+    }
+}
@@ -5,7 +5,10 @@ use axum::Router;
 use tokio::net::TcpListener;
 
 mod database;
+mod error;
+mod extract;
 mod handlers;
+mod policy;
 mod router;
 
 #[tokio::main]
@@ -0,0 +1,18 @@
+DO $$
+BEGIN
+  IF EXISTS (
+    SELECT 1
+    FROM tbl_rentals
+    WHERE returned_at IS NULL
+    GROUP BY book_uuid
+    HAVING count(*) > 1
+  ) THEN
+    RAISE EXCEPTION 'tbl_rentals has books with more than one open rental'
+    USING HINT = 'List them with: SELECT book_uuid, array_agg(id) FROM tbl_rentals WHERE returned_at IS NULL GROUP BY book_uuid HAVING count(*) > 1; then set returned_at on the loans that were actually returned and run the migrations again.';
+  END IF;
+END
+$$;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_rentals_open_book
+ON tbl_rentals (book_uuid)
+WHERE returned_at IS NULL;
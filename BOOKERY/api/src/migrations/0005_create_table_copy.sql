@@ -0,0 +1,28 @@
+CREATE TABLE IF NOT EXISTS tbl_copies (
+  id UUID PRIMARY KEY NOT NULL,
+  book_uuid UUID NOT NULL REFERENCES tbl_books(id),
+  barcode VARCHAR(32) NOT NULL UNIQUE,
+  condition VARCHAR(16) NOT NULL,
+  shelf_location VARCHAR(32) NOT NULL,
+  status VARCHAR(16) NOT NULL
+);
+
+INSERT INTO tbl_copies (id, book_uuid, barcode, condition, shelf_location, status)
+SELECT id, id, replace(id::text, '-', ''), 'good', '', 'available'
+FROM tbl_books
+ON CONFLICT DO NOTHING;
+
+ALTER TABLE tbl_rentals
+ADD COLUMN copy_uuid UUID REFERENCES tbl_copies(id);
+
+UPDATE tbl_rentals
+SET copy_uuid = book_uuid;
+
+DROP INDEX IF EXISTS idx_rentals_open_book;
+
+ALTER TABLE tbl_rentals
+DROP COLUMN book_uuid;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_rentals_open_copy
+ON tbl_rentals (copy_uuid)
+WHERE returned_at IS NULL;
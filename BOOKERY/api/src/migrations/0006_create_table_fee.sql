@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS tbl_fees (
+  id UUID PRIMARY KEY NOT NULL,
+  costumer_uuid UUID NOT NULL REFERENCES tbl_costumers(id),
+  rental_uuid UUID NOT NULL UNIQUE REFERENCES tbl_rentals(id),
+  amount BIGINT NOT NULL CHECK (amount > 0),
+  days_overdue INTEGER NOT NULL,
+  charged_at DATE NOT NULL DEFAULT CURRENT_DATE,
+  paid_at DATE DEFAULT NULL
+)
@@ -0,0 +1,2 @@
+ALTER TABLE tbl_rentals
+ADD COLUMN renewals INTEGER NOT NULL DEFAULT 0;
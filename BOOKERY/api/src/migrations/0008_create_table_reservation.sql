@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS tbl_reservations (
+  id UUID PRIMARY KEY NOT NULL,
+  queue_order BIGINT GENERATED ALWAYS AS IDENTITY,
+  book_uuid UUID NOT NULL REFERENCES tbl_books(id),
+  costumer_uuid UUID NOT NULL REFERENCES tbl_costumers(id),
+  status VARCHAR(16) NOT NULL DEFAULT 'waiting',
+  reserved_at DATE NOT NULL DEFAULT CURRENT_DATE,
+  expires_at DATE DEFAULT NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_reservations_active
+ON tbl_reservations (book_uuid, costumer_uuid)
+WHERE status IN ('waiting', 'ready');
+
+CREATE INDEX IF NOT EXISTS idx_reservations_queue
+ON tbl_reservations (book_uuid, queue_order);
@@ -0,0 +1,3 @@
+ALTER TABLE tbl_costumers
+ADD COLUMN blocked_reason VARCHAR(255) DEFAULT NULL,
+ADD COLUMN blocked_until DATE DEFAULT NULL;
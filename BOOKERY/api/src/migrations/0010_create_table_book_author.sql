@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS tbl_book_authors (
+  book_uuid UUID NOT NULL REFERENCES tbl_books(id) ON DELETE CASCADE,
+  author_uuid UUID NOT NULL REFERENCES tbl_authors(id),
+  role VARCHAR(16) NOT NULL,
+  position INTEGER NOT NULL,
+  PRIMARY KEY (book_uuid, author_uuid, role)
+);
+
+CREATE INDEX IF NOT EXISTS idx_book_authors_author
+ON tbl_book_authors (author_uuid);
+
+INSERT INTO tbl_book_authors (book_uuid, author_uuid, role, position)
+SELECT id, author_uuid, 'author', 0
+FROM tbl_books
+WHERE author_uuid IS NOT NULL;
+
+ALTER TABLE tbl_books
+DROP COLUMN author_uuid;
@@ -0,0 +1,29 @@
+CREATE TABLE IF NOT EXISTS tbl_publishers (
+  id UUID PRIMARY KEY NOT NULL,
+  name VARCHAR(64) NOT NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_publishers_name
+ON tbl_publishers (lower(regexp_replace(btrim(name), '\s+', ' ', 'g')));
+
+INSERT INTO tbl_publishers (id, name)
+SELECT gen_random_uuid(), min(regexp_replace(btrim(editor), '\s+', ' ', 'g'))
+FROM tbl_books
+GROUP BY lower(regexp_replace(btrim(editor), '\s+', ' ', 'g'));
+
+ALTER TABLE tbl_books
+ADD COLUMN publisher_uuid UUID REFERENCES tbl_publishers(id);
+
+UPDATE tbl_books b
+SET publisher_uuid = p.id
+FROM tbl_publishers p
+WHERE lower(regexp_replace(btrim(p.name), '\s+', ' ', 'g')) = lower(regexp_replace(btrim(b.editor), '\s+', ' ', 'g'));
+
+ALTER TABLE tbl_books
+ALTER COLUMN publisher_uuid SET NOT NULL;
+
+ALTER TABLE tbl_books
+DROP COLUMN editor;
+
+CREATE INDEX IF NOT EXISTS idx_books_publisher
+ON tbl_books (publisher_uuid);
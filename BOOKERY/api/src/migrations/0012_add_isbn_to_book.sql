@@ -0,0 +1,5 @@
+ALTER TABLE tbl_books
+ADD COLUMN isbn VARCHAR(13);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_books_isbn
+ON tbl_books (isbn);
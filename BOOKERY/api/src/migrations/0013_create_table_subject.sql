@@ -0,0 +1,20 @@
+CREATE TABLE IF NOT EXISTS tbl_subjects (
+  id UUID PRIMARY KEY NOT NULL,
+  name VARCHAR(64) NOT NULL,
+  parent_uuid UUID REFERENCES tbl_subjects(id)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_subjects_name
+ON tbl_subjects (lower(name));
+
+CREATE INDEX IF NOT EXISTS idx_subjects_parent
+ON tbl_subjects (parent_uuid);
+
+CREATE TABLE IF NOT EXISTS tbl_book_subjects (
+  book_uuid UUID NOT NULL REFERENCES tbl_books(id) ON DELETE CASCADE,
+  subject_uuid UUID NOT NULL REFERENCES tbl_subjects(id) ON DELETE CASCADE,
+  PRIMARY KEY (book_uuid, subject_uuid)
+);
+
+CREATE INDEX IF NOT EXISTS idx_book_subjects_subject
+ON tbl_book_subjects (subject_uuid);
@@ -0,0 +1,89 @@
+CREATE EXTENSION IF NOT EXISTS unaccent;
+
+CREATE OR REPLACE FUNCTION immutable_unaccent(TEXT)
+RETURNS TEXT
+LANGUAGE sql IMMUTABLE PARALLEL SAFE STRICT
+AS $$
+  SELECT public.unaccent('public.unaccent', $1)
+$$;
+
+ALTER TABLE tbl_books
+ADD COLUMN search_vector TSVECTOR NOT NULL DEFAULT ''::TSVECTOR;
+
+CREATE OR REPLACE FUNCTION book_search_vector(book UUID)
+RETURNS TSVECTOR
+LANGUAGE sql STABLE
+AS $$
+  SELECT setweight(to_tsvector('simple', immutable_unaccent(b.name)), 'A')
+    || setweight(to_tsvector('simple', immutable_unaccent(coalesce((
+      SELECT string_agg(a.name, ' ')
+      FROM tbl_book_authors ba
+      JOIN tbl_authors a
+      ON a.id = ba.author_uuid
+      WHERE ba.book_uuid = b.id
+    ), ''))), 'B')
+    || setweight(to_tsvector('simple', immutable_unaccent(p.name)), 'C')
+  FROM tbl_books b
+  JOIN tbl_publishers p
+  ON p.id = b.publisher_uuid
+  WHERE b.id = book
+$$;
+
+CREATE OR REPLACE FUNCTION refresh_book_search_vector()
+RETURNS TRIGGER
+LANGUAGE plpgsql
+AS $$
+BEGIN
+  IF TG_TABLE_NAME = 'tbl_books' THEN
+    UPDATE tbl_books
+    SET search_vector = book_search_vector(id)
+    WHERE id = NEW.id;
+  ELSIF TG_TABLE_NAME = 'tbl_book_authors' AND TG_OP = 'DELETE' THEN
+    UPDATE tbl_books
+    SET search_vector = book_search_vector(id)
+    WHERE id = OLD.book_uuid;
+  ELSIF TG_TABLE_NAME = 'tbl_book_authors' THEN
+    UPDATE tbl_books
+    SET search_vector = book_search_vector(id)
+    WHERE id = NEW.book_uuid
+    OR (TG_OP = 'UPDATE' AND id = OLD.book_uuid);
+  ELSIF TG_TABLE_NAME = 'tbl_authors' THEN
+    UPDATE tbl_books
+    SET search_vector = book_search_vector(id)
+    WHERE id IN (
+      SELECT book_uuid
+      FROM tbl_book_authors
+      WHERE author_uuid = NEW.id
+    );
+  ELSIF TG_TABLE_NAME = 'tbl_publishers' THEN
+    UPDATE tbl_books
+    SET search_vector = book_search_vector(id)
+    WHERE publisher_uuid = NEW.id;
+  END IF;
+
+  RETURN NULL;
+END;
+$$;
+
+CREATE TRIGGER trg_books_search_vector
+AFTER INSERT OR UPDATE OF name, publisher_uuid ON tbl_books
+FOR EACH ROW EXECUTE FUNCTION refresh_book_search_vector();
+
+CREATE TRIGGER trg_book_authors_search_vector
+AFTER INSERT OR UPDATE OR DELETE ON tbl_book_authors
+FOR EACH ROW EXECUTE FUNCTION refresh_book_search_vector();
+
+CREATE TRIGGER trg_authors_search_vector
+AFTER UPDATE OF name ON tbl_authors
+FOR EACH ROW EXECUTE FUNCTION refresh_book_search_vector();
+
+CREATE TRIGGER trg_publishers_search_vector
+AFTER UPDATE OF name ON tbl_publishers
+FOR EACH ROW EXECUTE FUNCTION refresh_book_search_vector();
+
+UPDATE tbl_books
+SET search_vector = book_search_vector(id);
+
+CREATE INDEX IF NOT EXISTS idx_books_search_vector
+ON tbl_books
+USING GIN (search_vector);
@@ -0,0 +1,13 @@
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE INDEX IF NOT EXISTS idx_authors_name_trgm
+ON tbl_authors
+USING GIN (name gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_costumers_name_trgm
+ON tbl_costumers
+USING GIN (name gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_books_name_trgm
+ON tbl_books
+USING GIN (name gin_trgm_ops);
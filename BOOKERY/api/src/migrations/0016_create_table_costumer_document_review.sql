@@ -0,0 +1,44 @@
+CREATE OR REPLACE FUNCTION cpf_is_valid(TEXT)
+RETURNS BOOLEAN
+LANGUAGE plpgsql IMMUTABLE PARALLEL SAFE STRICT
+AS $$
+DECLARE
+  digits TEXT := regexp_replace($1, '[.-]', '', 'g');
+  total INT;
+  check_digit INT;
+BEGIN
+  IF digits !~ '^[0-9]{11}$' OR digits ~ '^(.)\1*$' THEN
+    RETURN FALSE;
+  END IF;
+
+  FOR position IN 10..11 LOOP
+    total := 0;
+
+    FOR i IN 1..position - 1 LOOP
+      total := total + substr(digits, i, 1)::INT * (position + 1 - i);
+    END LOOP;
+
+    check_digit := CASE WHEN total % 11 < 2 THEN 0 ELSE 11 - total % 11 END;
+
+    IF substr(digits, position, 1)::INT <> check_digit THEN
+      RETURN FALSE;
+    END IF;
+  END LOOP;
+
+  RETURN TRUE;
+END
+$$;
+
+CREATE TABLE IF NOT EXISTS tbl_costumer_document_reviews (
+  costumer_uuid UUID NOT NULL REFERENCES tbl_costumers (id) ON DELETE CASCADE,
+  document VARCHAR(14) NOT NULL,
+  reason VARCHAR(32) NOT NULL,
+  flagged_at DATE NOT NULL DEFAULT CURRENT_DATE,
+  PRIMARY KEY (costumer_uuid, reason)
+);
+
+INSERT INTO tbl_costumer_document_reviews (costumer_uuid, document, reason)
+SELECT id, document, 'invalid_checksum'
+FROM tbl_costumers
+WHERE NOT cpf_is_valid(document)
+ON CONFLICT DO NOTHING;
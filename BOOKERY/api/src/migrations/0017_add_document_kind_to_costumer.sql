@@ -0,0 +1,10 @@
+ALTER TABLE tbl_costumers
+ADD COLUMN document_kind VARCHAR(16) NOT NULL DEFAULT 'cpf';
+
+UPDATE tbl_costumers
+SET document_kind = 'legacy'
+WHERE NOT cpf_is_valid(document);
+
+ALTER TABLE tbl_costumers
+ALTER COLUMN document_kind DROP DEFAULT,
+ALTER COLUMN document TYPE VARCHAR(14);
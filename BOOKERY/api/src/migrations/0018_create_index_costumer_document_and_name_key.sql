@@ -0,0 +1,37 @@
+INSERT INTO tbl_costumer_document_reviews (costumer_uuid, document, reason)
+SELECT id, document, 'duplicate_document'
+FROM (
+  SELECT
+    id,
+    document,
+    row_number() OVER (PARTITION BY document_kind, document ORDER BY id) AS position
+  FROM tbl_costumers
+  WHERE document_kind <> 'legacy'
+) AS ranked
+WHERE position > 1
+ON CONFLICT DO NOTHING;
+
+UPDATE tbl_costumers
+SET document_kind = 'legacy'
+WHERE id IN (
+  SELECT costumer_uuid
+  FROM tbl_costumer_document_reviews
+  WHERE reason = 'duplicate_document'
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_costumers_document
+ON tbl_costumers (document_kind, document)
+WHERE document_kind <> 'legacy';
+
+CREATE OR REPLACE FUNCTION person_name_key(TEXT)
+RETURNS TEXT
+LANGUAGE sql IMMUTABLE PARALLEL SAFE STRICT
+AS $$
+  SELECT regexp_replace(lower(immutable_unaccent($1)), '[^[:alnum:]]', '', 'g')
+$$;
+
+CREATE INDEX IF NOT EXISTS idx_authors_name_key
+ON tbl_authors (person_name_key(name), born);
+
+CREATE INDEX IF NOT EXISTS idx_costumers_name_key
+ON tbl_costumers (person_name_key(name), born);
@@ -0,0 +1,272 @@
+use std::env;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct FeePolicy {
+    pub per_day: i64,
+    pub grace_days: i64,
+    pub cap: Option<i64>,
+}
+
+impl Default for FeePolicy {
+    fn default() -> Self {
+        Self {
+            per_day: 100,
+            grace_days: 0,
+            cap: None,
+        }
+    }
+}
+
+impl FeePolicy {
+    pub fn from_env() -> Self {
+        let default: FeePolicy = FeePolicy::default();
+
+        Self {
+            per_day: env_var("FEE_PER_DAY").unwrap_or(default.per_day),
+            grace_days: env_var("FEE_GRACE_DAYS").unwrap_or(default.grace_days),
+            cap: env_var("FEE_CAP").or(default.cap),
+        }
+    }
+
+    pub fn fee(&self, days_overdue: i64) -> i64 {
+        let charged_days: i64 = days_overdue - self.grace_days;
+
+        if charged_days <= 0 {
+            return 0;
+        }
+
+        let fee: i64 = charged_days.saturating_mul(self.per_day);
+
+        match self.cap {
+            Some(cap) => fee.min(cap),
+            None => fee,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct RenewalPolicy {
+    pub period_days: i64,
+    pub max_renewals: i64,
+}
+
+impl Default for RenewalPolicy {
+    fn default() -> Self {
+        Self {
+            period_days: 14,
+            max_renewals: 2,
+        }
+    }
+}
+
+impl RenewalPolicy {
+    pub fn from_env() -> Self {
+        let default: RenewalPolicy = RenewalPolicy::default();
+
+        Self {
+            period_days: env_var("RENEWAL_PERIOD_DAYS").unwrap_or(default.period_days),
+            max_renewals: env_var("RENEWAL_MAX").unwrap_or(default.max_renewals),
+        }
+    }
+
+    pub fn can_renew(&self, renewals: i64) -> bool {
+        renewals < self.max_renewals
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ReservationPolicy {
+    pub hold_days: i64,
+}
+
+impl Default for ReservationPolicy {
+    fn default() -> Self {
+        Self { hold_days: 3 }
+    }
+}
+
+impl ReservationPolicy {
+    pub fn from_env() -> Self {
+        let default: ReservationPolicy = ReservationPolicy::default();
+
+        Self {
+            hold_days: env_var("RESERVATION_HOLD_DAYS").unwrap_or(default.hold_days),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct BorrowingStanding {
+    pub blocked: bool,
+    pub open_rentals: i64,
+    pub outstanding_fees: i64,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum BorrowingRefusal {
+    Blocked,
+    RentalLimit,
+    OutstandingFees,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct BorrowingPolicy {
+    pub max_rentals: i64,
+    pub max_fees: i64,
+}
+
+impl Default for BorrowingPolicy {
+    fn default() -> Self {
+        Self {
+            max_rentals: 5,
+            max_fees: 0,
+        }
+    }
+}
+
+impl BorrowingPolicy {
+    pub fn from_env() -> Self {
+        let default: BorrowingPolicy = BorrowingPolicy::default();
+
+        Self {
+            max_rentals: env_var("BORROW_MAX_RENTALS").unwrap_or(default.max_rentals),
+            max_fees: env_var("BORROW_MAX_FEES").unwrap_or(default.max_fees),
+        }
+    }
+
+    pub fn check(&self, standing: &BorrowingStanding) -> Result<(), BorrowingRefusal> {
+        if standing.blocked {
+            return Err(BorrowingRefusal::Blocked);
+        } else if standing.open_rentals >= self.max_rentals {
+            return Err(BorrowingRefusal::RentalLimit);
+        } else if standing.outstanding_fees > self.max_fees {
+            return Err(BorrowingRefusal::OutstandingFees);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Policy {
+    pub fee: FeePolicy,
+    pub renewal: RenewalPolicy,
+    pub reservation: ReservationPolicy,
+    pub borrowing: BorrowingPolicy,
+}
+
+impl Policy {
+    pub fn from_env() -> Self {
+        Self {
+            fee: FeePolicy::from_env(),
+            renewal: RenewalPolicy::from_env(),
+            reservation: ReservationPolicy::from_env(),
+            borrowing: BorrowingPolicy::from_env(),
+        }
+    }
+}
+
+fn env_var(name: &str) -> Option<i64> {
+    env::var(name).ok().map(|value: String| {
+        value
+            .parse::<i64>()
+            .unwrap_or_else(|_| panic!("{name} must be an integer"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fee_not_overdue() {
+        let policy: FeePolicy = FeePolicy::default();
+
+        assert_eq!(policy.fee(0), 0);
+        assert_eq!(policy.fee(-3), 0);
+    }
+
+    #[test]
+    fn test_fee_per_day() {
+        let policy: FeePolicy = FeePolicy {
+            per_day: 150,
+            grace_days: 0,
+            cap: None,
+        };
+
+        assert_eq!(policy.fee(1), 150);
+        assert_eq!(policy.fee(10), 1500);
+    }
+
+    #[test]
+    fn test_fee_grace_days() {
+        let policy: FeePolicy = FeePolicy {
+            per_day: 100,
+            grace_days: 2,
+            cap: None,
+        };
+
+        assert_eq!(policy.fee(2), 0);
+        assert_eq!(policy.fee(5), 300);
+    }
+
+    #[test]
+    fn test_fee_cap() {
+        let policy: FeePolicy = FeePolicy {
+            per_day: 100,
+            grace_days: 0,
+            cap: Some(1000),
+        };
+
+        assert_eq!(policy.fee(9), 900);
+        assert_eq!(policy.fee(90), 1000);
+    }
+
+    #[test]
+    fn test_renewal_limit() {
+        let policy: RenewalPolicy = RenewalPolicy {
+            period_days: 7,
+            max_renewals: 2,
+        };
+
+        assert!(policy.can_renew(0));
+        assert!(policy.can_renew(1));
+        assert!(!policy.can_renew(2));
+    }
+
+    #[test]
+    fn test_borrowing_check() {
+        let policy: BorrowingPolicy = BorrowingPolicy {
+            max_rentals: 2,
+            max_fees: 500,
+        };
+        let standing: BorrowingStanding = BorrowingStanding {
+            blocked: false,
+            open_rentals: 1,
+            outstanding_fees: 500,
+        };
+
+        assert_eq!(policy.check(&standing), Ok(()));
+        assert_eq!(
+            policy.check(&BorrowingStanding {
+                open_rentals: 2,
+                ..standing
+            }),
+            Err(BorrowingRefusal::RentalLimit)
+        );
+        assert_eq!(
+            policy.check(&BorrowingStanding {
+                outstanding_fees: 501,
+                ..standing
+            }),
+            Err(BorrowingRefusal::OutstandingFees)
+        );
+        assert_eq!(
+            policy.check(&BorrowingStanding {
+                blocked: true,
+                ..standing
+            }),
+            Err(BorrowingRefusal::Blocked)
+        );
+    }
+}
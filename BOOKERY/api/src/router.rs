@@ -11,19 +11,36 @@ use crate::{
     database::conn::Database,
     handlers::{
         author::{
-            count_authors, create_author, delete_author, get_author, search_authors, update_author,
+            count_authors, create_author, delete_author, get_author, list_author_duplicates,
+            merge_authors, search_authors, update_author,
         },
         book::{
-            count_books, create_book, delete_book, get_book, get_book_raw, search_books,
-            search_books_raw, update_book,
+            book_rentals, count_books, create_book, delete_book, get_book, get_book_by_isbn,
+            get_book_raw, list_book_copies, list_book_reservations, search_books,
+            search_books_ranked, search_books_raw, update_book,
         },
+        copy::{count_copies, create_copy, delete_copy, get_copy, update_copy},
         costumer::{
-            count_costumers, create_costumer, delete_costumer, get_costumer, search_costumers,
+            block_costumer, costumer_loans, costumer_rentals, costumer_summary, count_costumers,
+            create_costumer, delete_costumer, get_costumer, get_costumer_block,
+            list_costumer_duplicates, merge_costumers, search_costumers, unblock_costumer,
             update_costumer,
         },
+        publisher::{
+            count_publishers, create_publisher, delete_publisher, get_publisher, search_publishers,
+            update_publisher,
+        },
         rental::{
-            count_rentals, create_rental, delete_rental, get_rental, get_rental_raw,
-            search_rentals, search_rentals_raw, update_rental,
+            checkout_rental, count_rentals, create_rental, delete_rental, get_rental,
+            get_rental_raw, overdue_rentals, renew_rental, return_rental, search_rentals,
+            search_rentals_raw, update_rental,
+        },
+        reservation::{
+            cancel_reservation, count_reservations, create_reservation, get_reservation,
+        },
+        subject::{
+            count_subjects, create_subject, delete_subject, get_subject, list_subject_children,
+            search_subjects, update_subject,
         },
     },
 };
@@ -34,25 +51,61 @@ pub fn router(db: Arc<Database>) -> Router {
         .route("/author/create", post(create_author))
         .route("/author/get/:id", get(get_author))
         .route("/author/search", get(search_authors))
+        .route("/author/duplicates", get(list_author_duplicates))
         .route("/author/update", post(update_author))
+        .route("/author/merge", post(merge_authors))
         .route("/author/delete", post(delete_author))
         .route("/author/count", get(count_authors))
+        // Publishers
+        .route("/publisher/create", post(create_publisher))
+        .route("/publisher/get/:id", get(get_publisher))
+        .route("/publisher/search", get(search_publishers))
+        .route("/publisher/update", post(update_publisher))
+        .route("/publisher/delete", post(delete_publisher))
+        .route("/publisher/count", get(count_publishers))
+        // Subjects
+        .route("/subject/create", post(create_subject))
+        .route("/subject/get/:id", get(get_subject))
+        .route("/subject/children/:id", get(list_subject_children))
+        .route("/subject/search", get(search_subjects))
+        .route("/subject/update", post(update_subject))
+        .route("/subject/delete", post(delete_subject))
+        .route("/subject/count", get(count_subjects))
         // Books
         .route("/book/create", post(create_book))
         .route("/book/get/:id", get(get_book))
         .route("/book/get-raw/:id", get(get_book_raw))
+        .route("/book/isbn/:isbn", get(get_book_by_isbn))
         .route("/book/search", get(search_books))
         .route("/book/search-raw", get(search_books_raw))
+        .route("/book/search-ranked", get(search_books_ranked))
         .route("/book/update", post(update_book))
         .route("/book/delete", post(delete_book))
         .route("/book/count", get(count_books))
+        .route("/book/copies/:id", get(list_book_copies))
+        .route("/book/reservations/:id", get(list_book_reservations))
+        .route("/book/rentals/:id", get(book_rentals))
+        // Copies
+        .route("/copy/create", post(create_copy))
+        .route("/copy/get/:id", get(get_copy))
+        .route("/copy/update", post(update_copy))
+        .route("/copy/delete", post(delete_copy))
+        .route("/copy/count", get(count_copies))
         // Costumers
         .route("/costumer/create", post(create_costumer))
         .route("/costumer/get/:id", get(get_costumer))
         .route("/costumer/search", get(search_costumers))
+        .route("/costumer/duplicates", get(list_costumer_duplicates))
         .route("/costumer/update", post(update_costumer))
+        .route("/costumer/merge", post(merge_costumers))
         .route("/costumer/delete", post(delete_costumer))
         .route("/costumer/count", get(count_costumers))
+        .route("/costumer/block", post(block_costumer))
+        .route("/costumer/unblock", post(unblock_costumer))
+        .route("/costumer/block/:id", get(get_costumer_block))
+        .route("/costumer/:id/loans", get(costumer_loans))
+        .route("/costumer/:id/rentals", get(costumer_rentals))
+        .route("/costumer/:id/summary", get(costumer_summary))
         // Rentals
         .route("/rental/create", post(create_rental))
         .route("/rental/get/:id", get(get_rental))
@@ -62,6 +115,15 @@ pub fn router(db: Arc<Database>) -> Router {
         .route("/rental/update", post(update_rental))
         .route("/rental/delete", post(delete_rental))
         .route("/rental/count", get(count_rentals))
+        .route("/rental/checkout", post(checkout_rental))
+        .route("/rental/return", post(return_rental))
+        .route("/rental/renew", post(renew_rental))
+        .route("/rental/overdue", get(overdue_rentals))
+        // Reservations
+        .route("/reservation/create", post(create_reservation))
+        .route("/reservation/get/:id", get(get_reservation))
+        .route("/reservation/cancel", post(cancel_reservation))
+        .route("/reservation/count", get(count_reservations))
         // CORS
         .layer(CorsLayer::permissive())
         // Database Sync
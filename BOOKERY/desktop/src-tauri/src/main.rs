@@ -3,11 +3,16 @@
 
 use std::env;
 
-use reqwest::get;
-use serde::Deserialize;
+use reqwest::Client;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use time::{Date, Month};
 
 use shared::structs::{
-    author::Author, book::BookWithAuthor, costumer::Costumer, rental::RentalWithCostumerAndBook,
+    author::{Author, AuthorFilter},
+    book::{BookContributorName, BookFilter, BookWithAuthor, ContributorRole},
+    costumer::{Costumer, CostumerFilter},
+    page::Page,
+    rental::{RentalFilter, RentalWithCostumerAndBook},
 };
 
 static API_URL: &str = env!("API_URL");
@@ -20,6 +25,94 @@ enum Module {
     Rental,
 }
 
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AuthorForm {
+    name: String,
+    born_from: String,
+    born_until: String,
+}
+
+impl AuthorForm {
+    fn filter(&self) -> Result<AuthorFilter, &'static str> {
+        Ok(AuthorFilter {
+            born_from: month_start(&self.born_from)?,
+            born_until: month_end(&self.born_until)?,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BookForm {
+    name: String,
+    author: String,
+    editor: String,
+    subject: String,
+    release_from: String,
+    release_until: String,
+}
+
+impl BookForm {
+    fn filter(&self) -> Result<BookFilter, &'static str> {
+        Ok(BookFilter {
+            name: text_filter(&self.name),
+            author: text_filter(&self.author),
+            editor: text_filter(&self.editor),
+            subject: text_filter(&self.subject),
+            release_from: month_start(&self.release_from)?,
+            release_until: month_end(&self.release_until)?,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CostumerForm {
+    name: String,
+    born_from: String,
+    born_until: String,
+}
+
+impl CostumerForm {
+    fn filter(&self) -> Result<CostumerFilter, &'static str> {
+        Ok(CostumerFilter {
+            document: None,
+            document_kind: None,
+            born_from: month_start(&self.born_from)?,
+            born_until: month_end(&self.born_until)?,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RentalForm {
+    costumer: String,
+    book: String,
+    borrowed_at_from: String,
+    borrowed_at_until: String,
+    due_date_from: String,
+    due_date_until: String,
+    returned_at_from: String,
+    returned_at_until: String,
+}
+
+impl RentalForm {
+    fn filter(&self) -> Result<RentalFilter, &'static str> {
+        Ok(RentalFilter {
+            costumer: text_filter(&self.costumer),
+            book: text_filter(&self.book),
+            borrowed_at_from: month_start(&self.borrowed_at_from)?,
+            borrowed_at_until: month_end(&self.borrowed_at_until)?,
+            due_date_from: month_start(&self.due_date_from)?,
+            due_date_until: month_end(&self.due_date_until)?,
+            returned_at_from: month_start(&self.returned_at_from)?,
+            returned_at_until: month_end(&self.returned_at_until)?,
+        })
+    }
+}
+
 fn main() {
     tauri::Builder::default()
         .invoke_handler(tauri::generate_handler![
@@ -33,36 +126,107 @@ fn main() {
         .expect("error while running tauri application");
 }
 
+fn text_filter(value: &str) -> Option<String> {
+    let value: &str = value.trim();
+
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
+
+fn parse_month(value: &str) -> Result<Option<(i32, Month)>, &'static str> {
+    if value.trim().is_empty() {
+        return Ok(None);
+    }
+
+    let (year, month): (&str, &str) = match value.trim().split_once('-') {
+        Some(parts) => parts,
+        None => return Err("Invalid date: expected a year and month."),
+    };
+
+    let year: i32 = match year.parse::<i32>() {
+        Ok(year) => year,
+        Err(_) => return Err("Invalid date: expected a year and month."),
+    };
+
+    let month: Month = match month.parse::<u8>().map(Month::try_from) {
+        Ok(Ok(month)) => month,
+        _ => return Err("Invalid date: expected a year and month."),
+    };
+
+    Ok(Some((year, month)))
+}
+
+fn month_start(value: &str) -> Result<Option<Date>, &'static str> {
+    match parse_month(value)? {
+        Some((year, month)) => match Date::from_calendar_date(year, month, 1) {
+            Ok(date) => Ok(Some(date)),
+            Err(_) => Err("Invalid date: out of range."),
+        },
+        None => Ok(None),
+    }
+}
+
+fn month_end(value: &str) -> Result<Option<Date>, &'static str> {
+    match parse_month(value)? {
+        Some((year, month)) => match Date::from_calendar_date(year, month, month.length(year)) {
+            Ok(date) => Ok(Some(date)),
+            Err(_) => Err("Invalid date: out of range."),
+        },
+        None => Ok(None),
+    }
+}
+
+async fn fetch_page<T: DeserializeOwned, F: Serialize>(
+    module: &str,
+    token: &str,
+    filter: &F,
+) -> Result<Page<T>, &'static str> {
+    let endpoint: String = format!("{API_URL}/{module}/search");
+
+    let response: reqwest::Response = match Client::new()
+        .get(endpoint)
+        .query(&[("token", token)])
+        .query(filter)
+        .send()
+        .await
+    {
+        Ok(res) => res,
+        Err(_) => return Err("Failed to fetch data: API is out."),
+    };
+
+    if !response.status().is_success() {
+        return Err("Failed to fetch data: API rejected the filters.");
+    }
+
+    match response.json::<Page<T>>().await {
+        Ok(data) => Ok(data),
+        Err(_) => Err("Failed to parse data: object response different from expected."),
+    }
+}
+
 #[tauri::command]
 fn create_table_head(module: Module) -> &'static str {
     match module {
         Module::Author => {"<tr><th>Name</th><th>Born</th></tr>"}
-        Module::Book => {"<tr><th>Name</th><th>Author</th><th>Editor</th><th>Release</th></tr>"}
+        Module::Book => {"<tr><th>Name</th><th>Author</th><th>Editor</th><th>Release</th><th>Circulation</th><th>Last Borrowed</th></tr>"}
         Module::Costumer => {"<tr><th>Name</th><th>Document</th><th>Born</th></tr>"}
         Module::Rental => {"<tr><th>Costumer</th><th>Book</th><th>Borrowed at</th><th>Due Date</th><th>Returned at</th></tr>"}
     }
 }
 
 #[tauri::command]
-async fn create_table_body_search_author() -> Result<(String, i64), &'static str> {
+async fn create_table_body_search_author(form: AuthorForm) -> Result<(String, i64), &'static str> {
     let mut table_body_vec: Vec<String> = vec![];
-    let endpoint: String = format!("{API_URL}/author/search?token=");
+    let data: Page<Author> = fetch_page("author", form.name.trim(), &form.filter()?).await?;
 
-    let response: reqwest::Response = match get(endpoint).await {
-        Ok(res) => res,
-        Err(_) => return Err("Failed to fetch data: API is out."),
-    };
-
-    let data: Vec<Author> = match response.json::<Vec<Author>>().await {
-        Ok(data) => data,
-        Err(_) => return Err("Failed to parse data: object response different from expected."),
-    };
-
-    if data.is_empty() {
+    if data.items.is_empty() {
         return Ok(("<tr><td>-</td><td>-</td></tr>".to_string(), 0));
     }
 
-    for author in &data {
+    for author in &data.items {
         table_body_vec.push(format!(
             "<tr><td>{}</td><td>{}</td></tr>",
             author.name.as_str(),
@@ -70,93 +234,83 @@ async fn create_table_body_search_author() -> Result<(String, i64), &'static str
         ));
     }
 
-    Ok((table_body_vec.join(""), data.len().try_into().unwrap()))
+    Ok((table_body_vec.join(""), data.total))
 }
 
 #[tauri::command]
-async fn create_table_body_search_book() -> Result<(String, i64), &'static str> {
+async fn create_table_body_search_book(form: BookForm) -> Result<(String, i64), &'static str> {
     let mut table_body_vec: Vec<String> = vec![];
-    let endpoint: String = format!("{API_URL}/book/search?token=");
+    let data: Page<BookWithAuthor> = fetch_page("book", "", &form.filter()?).await?;
 
-    let response: reqwest::Response = match get(endpoint).await {
-        Ok(res) => res,
-        Err(_) => return Err("Failed to fetch data: API is out."),
-    };
+    if data.items.is_empty() {
+        return Ok(("<tr><td>-</td><td>-</td><td>-</td><td>-</td><td>-</td><td>-</td></tr>".to_string(), 0));
+    }
 
-    let data: Vec<BookWithAuthor> = match response.json::<Vec<BookWithAuthor>>().await {
-        Ok(data) => data,
-        Err(_) => return Err("Failed to parse data: object response different from expected."),
-    };
+    for book in &data.items {
+        let book_last_borrowed_at: String = match book.last_borrowed_at {
+            Some(last_borrowed_at) => format!("{}", last_borrowed_at),
+            None => "-".to_string(),
+        };
 
-    if data.is_empty() {
-        return Ok(("<tr><td>-</td><td>-</td><td>-</td><td>-</td></tr>".to_string(), 0));
-    }
+        let book_contributors: String = book
+            .contributors
+            .iter()
+            .map(|contributor: &BookContributorName| match contributor.role {
+                ContributorRole::Author => contributor.name.as_str().to_string(),
+                role => format!("{} ({})", contributor.name.as_str(), role.as_str()),
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
 
-    for book in &data {
         table_body_vec.push(format!(
-            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
             book.name.as_str(),
-            book.author_name.as_str(),
+            book_contributors,
             book.editor.as_str(),
-            book.release
+            book.release,
+            book.circulation_count,
+            book_last_borrowed_at
         ));
     }
 
-    Ok((table_body_vec.join(""), data.len().try_into().unwrap()))
+    Ok((table_body_vec.join(""), data.total))
 }
 
 #[tauri::command]
-async fn create_table_body_search_costumer() -> Result<(String, i64), &'static str> {
+async fn create_table_body_search_costumer(form: CostumerForm) -> Result<(String, i64), &'static str> {
     let mut table_body_vec: Vec<String> = vec![];
-    let endpoint: String = format!("{API_URL}/costumer/search?token=");
-
-    let response: reqwest::Response = match get(endpoint).await {
-        Ok(res) => res,
-        Err(_) => return Err("Failed to fetch data: API is out."),
-    };
-
-    let data: Vec<Costumer> = match response.json::<Vec<Costumer>>().await {
-        Ok(data) => data,
-        Err(_) => return Err("Failed to parse data: object response different from expected."),
-    };
+    let data: Page<Costumer> = fetch_page("costumer", form.name.trim(), &form.filter()?).await?;
 
-    if data.is_empty() {
+    if data.items.is_empty() {
         return Ok(("<tr><td>-</td><td>-</td><td>-</td></tr>".to_string(), 0));
     }
 
-    for costumer in &data {
+    for costumer in &data.items {
         table_body_vec.push(format!(
             "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
             costumer.name.as_str(),
-            costumer.document.as_str(),
+            format!(
+                "{} {}",
+                costumer.document.kind().label(),
+                costumer.document.formatted()
+            ),
             costumer.born,
         ));
     }
 
-    Ok((table_body_vec.join(""), data.iter().count().try_into().unwrap()))
+    Ok((table_body_vec.join(""), data.total))
 }
 
 #[tauri::command]
-async fn create_table_body_search_rental() -> Result<(String, i64), &'static str> {
+async fn create_table_body_search_rental(form: RentalForm) -> Result<(String, i64), &'static str> {
     let mut table_body_vec: Vec<String> = vec![];
-    let endpoint: String = format!("{API_URL}/rental/search?token=");
-
-    let response: reqwest::Response = match get(endpoint).await {
-        Ok(res) => res,
-        Err(_) => return Err("Failed to fetch data: API is out."),
-    };
-
-    let data: Vec<RentalWithCostumerAndBook> =
-        match response.json::<Vec<RentalWithCostumerAndBook>>().await {
-            Ok(data) => data,
-            Err(_) => return Err("Failed to parse data: object response different from expected."),
-        };
+    let data: Page<RentalWithCostumerAndBook> = fetch_page("rental", "", &form.filter()?).await?;
 
-    if data.is_empty() {
+    if data.items.is_empty() {
         return Ok(("<tr><td>-</td><td>-</td><td>-</td><td>-</td><td>-</td></tr>".to_string(), 0));
     }
 
-    for rental in &data {
+    for rental in &data.items {
         let mut rental_returned_at: String = String::new();
 
         if rental.returned_at.is_none() {
@@ -175,5 +329,5 @@ async fn create_table_body_search_rental() -> Result<(String, i64), &'static str
         ));
     }
 
-    Ok((table_body_vec.join(""), data.len().try_into().unwrap()))
+    Ok((table_body_vec.join(""), data.total))
 }
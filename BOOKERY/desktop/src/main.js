@@ -9,6 +9,40 @@ window.addEventListener("DOMContentLoaded", () => {
     Costumer: "create_table_body_search_costumer",
     Rental: "create_table_body_search_rental",
   };
+  const dispatchSearchForm = {
+    Author: () => ({
+      name: inputValue("author-form-name"),
+      bornFrom: inputValue("author-form-born-from"),
+      bornUntil: inputValue("author-form-born-until"),
+    }),
+    Book: () => ({
+      name: inputValue("book-form-book-name"),
+      author: inputValue("book-form-author-name"),
+      editor: inputValue("book-form-editor-name"),
+      subject: inputValue("book-form-subject-name"),
+      releaseFrom: inputValue("book-form-release-from"),
+      releaseUntil: inputValue("book-form-release-until"),
+    }),
+    Costumer: () => ({
+      name: inputValue("costumer-form-name"),
+      bornFrom: inputValue("costumer-form-born-from"),
+      bornUntil: inputValue("costumer-form-born-until"),
+    }),
+    Rental: () => ({
+      costumer: inputValue("rental-form-costumer-name"),
+      book: inputValue("rental-form-book-name"),
+      borrowedAtFrom: inputValue("rental-form-borrow-from"),
+      borrowedAtUntil: inputValue("rental-form-borrow-until"),
+      dueDateFrom: inputValue("rental-form-due-date-from"),
+      dueDateUntil: inputValue("rental-form-due-date-until"),
+      returnedAtFrom: inputValue("rental-form-returned-from"),
+      returnedAtUntil: inputValue("rental-form-returned-until"),
+    }),
+  };
+
+  function inputValue(id) {
+    return document.getElementById(id).value;
+  }
 
   function hideModules() {
     document.querySelectorAll("form").forEach((form) => {
@@ -32,10 +66,13 @@ window.addEventListener("DOMContentLoaded", () => {
     const functionToCall = dispatchSearchURL[module];
 
     try {
-      const data = await invoke(functionToCall, {});
+      const data = await invoke(functionToCall, {
+        form: dispatchSearchForm[module](),
+      });
       tableBody.innerHTML = data[0];
       tableTotalSpan.innerHTML = data[1];
     } catch (err) {
+      tableTotalSpan.innerHTML = err;
       console.log(err);
     }
   }
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use time::Date;
 use uuid::Uuid;
 
-use crate::structs::{ConversionError, PersonName};
+use crate::structs::{ConversionError, FieldError, PersonName};
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct Author {
@@ -27,17 +27,27 @@ pub struct PayloadUpdateAuthor {
     pub born: Date,
 }
 
+#[derive(Deserialize, Serialize, Default, Debug, PartialEq, Clone)]
+pub struct AuthorFilter {
+    #[serde(default, with = "super::option_date_format")]
+    pub born_from: Option<Date>,
+    #[serde(default, with = "super::option_date_format")]
+    pub born_until: Option<Date>,
+}
+
 impl Author {
-    pub fn create(payload_author: PayloadAuthor) -> Result<Self, ConversionError> {
-        let name: PersonName = PersonName::try_from(payload_author.name)?;
+    pub fn create(payload_author: PayloadAuthor) -> Result<Self, Vec<FieldError>> {
+        let name: PersonName = PersonName::try_from(payload_author.name)
+            .map_err(|e: ConversionError| vec![e.on("name")])?;
         let born: Date = payload_author.born;
         let id: Uuid = Uuid::new_v4();
 
         Ok(Self { id, name, born })
     }
 
-    pub fn parse(author: PayloadUpdateAuthor) -> Result<Self, ConversionError> {
-        let name: PersonName = PersonName::try_from(author.name)?;
+    pub fn parse(author: PayloadUpdateAuthor) -> Result<Self, Vec<FieldError>> {
+        let name: PersonName =
+            PersonName::try_from(author.name).map_err(|e: ConversionError| vec![e.on("name")])?;
         let born: Date = author.born;
 
         Ok(Self {
@@ -54,7 +64,7 @@ mod tests {
 
     use time::{error::ComponentRange, Month};
 
-    const DEFAULT_NAME: &'static str = "Name";
+    const DEFAULT_NAME: &str = "Name";
     const DEFAULT_BORN: Result<Date, ComponentRange> =
         Date::from_calendar_date(2000, Month::January, 1);
 
@@ -85,7 +95,7 @@ mod tests {
             born: DEFAULT_BORN.unwrap(),
         };
 
-        let author_uuid: Uuid = payload_update_author.id.clone();
+        let author_uuid: Uuid = payload_update_author.id;
 
         let author: Author = Author::parse(payload_update_author).unwrap();
 
@@ -2,14 +2,64 @@ use serde::{Deserialize, Serialize};
 use time::Date;
 use uuid::Uuid;
 
-use crate::structs::{BookName, ConversionError, EditorName, PersonName};
+use crate::structs::{
+    field_errors, BookName, ConversionError, EditorName, FieldError, Isbn, PersonName, SubjectName,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ContributorRole {
+    Author,
+    Editor,
+    Translator,
+    Illustrator,
+}
+
+impl ContributorRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContributorRole::Author => "author",
+            ContributorRole::Editor => "editor",
+            ContributorRole::Translator => "translator",
+            ContributorRole::Illustrator => "illustrator",
+        }
+    }
+}
+
+impl TryFrom<String> for ContributorRole {
+    type Error = ConversionError;
+
+    fn try_from(token: String) -> Result<Self, Self::Error> {
+        match token.as_str() {
+            "author" => Ok(ContributorRole::Author),
+            "editor" => Ok(ContributorRole::Editor),
+            "translator" => Ok(ContributorRole::Translator),
+            "illustrator" => Ok(ContributorRole::Illustrator),
+            _ => Err(ConversionError::InvalidType),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct BookContributor {
+    pub author_uuid: Uuid,
+    pub role: ContributorRole,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct BookContributorName {
+    pub name: PersonName,
+    pub role: ContributorRole,
+}
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct Book {
     pub id: Uuid,
     pub name: BookName,
-    pub author_uuid: Uuid,
-    pub editor: EditorName,
+    pub isbn: Option<Isbn>,
+    pub contributors: Vec<BookContributor>,
+    pub subjects: Vec<Uuid>,
+    pub publisher_uuid: Uuid,
     #[serde(with = "super::date_format")]
     pub release: Date,
 }
@@ -18,17 +68,32 @@ pub struct Book {
 pub struct BookWithAuthor {
     pub id: Uuid,
     pub name: BookName,
-    pub author_name: PersonName,
+    pub isbn: Option<Isbn>,
+    pub contributors: Vec<BookContributorName>,
+    pub subjects: Vec<SubjectName>,
     pub editor: EditorName,
     #[serde(with = "super::date_format")]
     pub release: Date,
+    pub circulation_count: i64,
+    #[serde(with = "super::option_date_format")]
+    pub last_borrowed_at: Option<Date>,
+}
+
+#[derive(Deserialize, Serialize, Clone)]
+pub struct PayloadContributor {
+    pub author_uuid: Uuid,
+    pub role: String,
 }
 
 #[derive(Deserialize, Serialize)]
 pub struct PayloadBook {
     pub name: String,
-    pub author_uuid: Uuid,
-    pub editor: String,
+    #[serde(default)]
+    pub isbn: Option<String>,
+    pub contributors: Vec<PayloadContributor>,
+    #[serde(default)]
+    pub subjects: Vec<Uuid>,
+    pub publisher_uuid: Uuid,
     #[serde(with = "super::date_format")]
     pub release: Date,
 }
@@ -37,42 +102,122 @@ pub struct PayloadBook {
 pub struct PayloadUpdateBook {
     pub id: Uuid,
     pub name: String,
-    pub author_uuid: Uuid,
-    pub editor: String,
+    #[serde(default)]
+    pub isbn: Option<String>,
+    pub contributors: Vec<PayloadContributor>,
+    #[serde(default)]
+    pub subjects: Vec<Uuid>,
+    pub publisher_uuid: Uuid,
     #[serde(with = "super::date_format")]
     pub release: Date,
 }
 
+#[derive(Deserialize, Serialize, Default, Debug, PartialEq, Clone)]
+pub struct BookFilter {
+    pub name: Option<String>,
+    pub author: Option<String>,
+    pub editor: Option<String>,
+    pub subject: Option<String>,
+    #[serde(default, with = "super::option_date_format")]
+    pub release_from: Option<Date>,
+    #[serde(default, with = "super::option_date_format")]
+    pub release_until: Option<Date>,
+}
+
+impl BookContributor {
+    fn list(contributors: Vec<PayloadContributor>) -> Result<Vec<Self>, ConversionError> {
+        if contributors.is_empty() {
+            return Err(ConversionError::TokenIncompatibleSize);
+        }
+
+        let mut list: Vec<Self> = Vec::with_capacity(contributors.len());
+
+        for contributor in contributors {
+            let contributor: Self = Self {
+                author_uuid: contributor.author_uuid,
+                role: ContributorRole::try_from(contributor.role)?,
+            };
+
+            if list.contains(&contributor) {
+                return Err(ConversionError::InvalidType);
+            }
+
+            list.push(contributor);
+        }
+
+        Ok(list)
+    }
+}
+
+fn subject_list(mut subjects: Vec<Uuid>) -> Result<Vec<Uuid>, ConversionError> {
+    subjects.sort();
+
+    if subjects.windows(2).any(|pair: &[Uuid]| pair[0] == pair[1]) {
+        return Err(ConversionError::InvalidType);
+    }
+
+    Ok(subjects)
+}
+
 impl Book {
-    pub fn create(new_book: PayloadBook) -> Result<Self, ConversionError> {
-        let name: BookName = BookName::try_from(new_book.name)?;
-        let editor: EditorName = EditorName::try_from(new_book.editor)?;
-        let release: Date = new_book.release;
-        let author_uuid: Uuid = new_book.author_uuid;
+    pub fn create(new_book: PayloadBook) -> Result<Self, Vec<FieldError>> {
         let id: Uuid = Uuid::new_v4();
 
-        Ok(Self {
+        Self::validate(
             id,
-            name,
-            author_uuid,
-            editor,
-            release,
-        })
+            new_book.name,
+            new_book.isbn,
+            new_book.contributors,
+            new_book.subjects,
+            new_book.publisher_uuid,
+            new_book.release,
+        )
     }
 
-    pub fn parse(book: PayloadUpdateBook) -> Result<Self, ConversionError> {
-        let name: BookName = BookName::try_from(book.name)?;
-        let editor: EditorName = EditorName::try_from(book.editor)?;
-        let release: Date = book.release;
-        let author_uuid: Uuid = book.author_uuid;
-
-        Ok(Self {
-            id: book.id,
-            name,
-            author_uuid,
-            editor,
-            release,
-        })
+    pub fn parse(book: PayloadUpdateBook) -> Result<Self, Vec<FieldError>> {
+        Self::validate(
+            book.id,
+            book.name,
+            book.isbn,
+            book.contributors,
+            book.subjects,
+            book.publisher_uuid,
+            book.release,
+        )
+    }
+
+    fn validate(
+        id: Uuid,
+        name: String,
+        isbn: Option<String>,
+        contributors: Vec<PayloadContributor>,
+        subjects: Vec<Uuid>,
+        publisher_uuid: Uuid,
+        release: Date,
+    ) -> Result<Self, Vec<FieldError>> {
+        let name: Result<BookName, ConversionError> = BookName::try_from(name);
+        let isbn: Result<Option<Isbn>, ConversionError> = isbn.map(Isbn::try_from).transpose();
+        let contributors: Result<Vec<BookContributor>, ConversionError> =
+            BookContributor::list(contributors);
+        let subjects: Result<Vec<Uuid>, ConversionError> = subject_list(subjects);
+
+        match (name, isbn, contributors, subjects) {
+            (Ok(name), Ok(isbn), Ok(contributors), Ok(subjects)) => Ok(Self {
+                id,
+                name,
+                isbn,
+                contributors,
+                subjects,
+                publisher_uuid,
+                release,
+            }),
+            (name, isbn, contributors, subjects) => Err(field_errors([
+                ("name", name.err()),
+                ("isbn", isbn.err()),
+                ("contributors", contributors.err()),
+                ("subjects", subjects.err()),
+            ])),
+        }
     }
 }
 
@@ -82,20 +227,35 @@ mod tests {
 
     use time::{error::ComponentRange, Month};
 
-    const DEFAULT_NAME: &'static str = "Name";
-    const DEFAULT_EDITOR: &'static str = "Editor";
+    const DEFAULT_NAME: &str = "Name";
     const DEFAULT_RELEASE: Result<Date, ComponentRange> =
         Date::from_calendar_date(2000, Month::January, 1);
 
+    fn create_payload_contributor(role: &str) -> PayloadContributor {
+        PayloadContributor {
+            author_uuid: Uuid::new_v4(),
+            role: role.to_string(),
+        }
+    }
+
     #[test]
     fn test_create_book() {
         let payload_book: PayloadBook = PayloadBook {
             name: DEFAULT_NAME.to_string(),
-            author_uuid: Uuid::new_v4(),
-            editor: DEFAULT_EDITOR.to_string(),
+            isbn: Some("0-306-40615-2".to_string()),
+            contributors: vec![
+                create_payload_contributor("author"),
+                create_payload_contributor("translator"),
+            ],
+            subjects: vec![Uuid::from_u128(2), Uuid::from_u128(1)],
+            publisher_uuid: Uuid::new_v4(),
             release: DEFAULT_RELEASE.unwrap(),
         };
 
+        let author_uuid: Uuid = payload_book.contributors[0].author_uuid;
+        let translator_uuid: Uuid = payload_book.contributors[1].author_uuid;
+        let publisher_uuid: Uuid = payload_book.publisher_uuid;
+
         let book: Book = Book::create(payload_book).unwrap();
 
         assert_eq!(
@@ -103,8 +263,19 @@ mod tests {
             Book {
                 id: book.id,
                 name: BookName::try_from(DEFAULT_NAME.to_string()).unwrap(),
-                author_uuid: book.author_uuid,
-                editor: EditorName::try_from(DEFAULT_EDITOR.to_string()).unwrap(),
+                isbn: Some(Isbn::try_from("9780306406157".to_string()).unwrap()),
+                contributors: vec![
+                    BookContributor {
+                        author_uuid,
+                        role: ContributorRole::Author,
+                    },
+                    BookContributor {
+                        author_uuid: translator_uuid,
+                        role: ContributorRole::Translator,
+                    },
+                ],
+                subjects: vec![Uuid::from_u128(1), Uuid::from_u128(2)],
+                publisher_uuid,
                 release: DEFAULT_RELEASE.unwrap(),
             }
         );
@@ -115,13 +286,16 @@ mod tests {
         let payload_update_book: PayloadUpdateBook = PayloadUpdateBook {
             id: Uuid::new_v4(),
             name: DEFAULT_NAME.to_string(),
-            author_uuid: Uuid::new_v4(),
-            editor: DEFAULT_EDITOR.to_string(),
+            isbn: None,
+            contributors: vec![create_payload_contributor("editor")],
+            subjects: vec![],
+            publisher_uuid: Uuid::new_v4(),
             release: DEFAULT_RELEASE.unwrap(),
         };
 
-        let book_uuid: Uuid = payload_update_book.id.clone();
-        let book_author_uuid: Uuid = payload_update_book.author_uuid.clone();
+        let book_uuid: Uuid = payload_update_book.id;
+        let book_author_uuid: Uuid = payload_update_book.contributors[0].author_uuid;
+        let book_publisher_uuid: Uuid = payload_update_book.publisher_uuid;
 
         let book: Book = Book::parse(payload_update_book).unwrap();
 
@@ -130,10 +304,85 @@ mod tests {
             Book {
                 id: book_uuid,
                 name: BookName::try_from(DEFAULT_NAME.to_string()).unwrap(),
-                author_uuid: book_author_uuid,
-                editor: EditorName::try_from(DEFAULT_EDITOR.to_string()).unwrap(),
+                isbn: None,
+                contributors: vec![BookContributor {
+                    author_uuid: book_author_uuid,
+                    role: ContributorRole::Editor,
+                }],
+                subjects: vec![],
+                publisher_uuid: book_publisher_uuid,
                 release: DEFAULT_RELEASE.unwrap(),
             }
         );
     }
+
+    #[test]
+    fn test_create_book_collects_every_field_error() {
+        let payload_book: PayloadBook = PayloadBook {
+            name: "x".repeat(65),
+            isbn: Some("0-306-40615".to_string()),
+            contributors: vec![],
+            subjects: vec![Uuid::nil(), Uuid::nil()],
+            publisher_uuid: Uuid::new_v4(),
+            release: DEFAULT_RELEASE.unwrap(),
+        };
+
+        let errors: Vec<FieldError> = Book::create(payload_book).unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![
+                ConversionError::TokenTooLong.on("name"),
+                ConversionError::TokenIncompatibleSize.on("isbn"),
+                ConversionError::TokenIncompatibleSize.on("contributors"),
+                ConversionError::InvalidType.on("subjects"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_create_book_invalid_contributors() {
+        let contributor: PayloadContributor = create_payload_contributor("author");
+
+        for (contributors, error) in [
+            (vec![], ConversionError::TokenIncompatibleSize),
+            (
+                vec![create_payload_contributor("narrator")],
+                ConversionError::InvalidType,
+            ),
+            (
+                vec![contributor.clone(), contributor],
+                ConversionError::InvalidType,
+            ),
+        ] {
+            let payload_book: PayloadBook = PayloadBook {
+                name: DEFAULT_NAME.to_string(),
+                isbn: None,
+                contributors,
+                subjects: vec![],
+                publisher_uuid: Uuid::new_v4(),
+                release: DEFAULT_RELEASE.unwrap(),
+            };
+
+            assert_eq!(
+                Book::create(payload_book).unwrap_err(),
+                vec![error.on("contributors")]
+            );
+        }
+    }
+
+    #[test]
+    fn test_contributor_role_round_trip() {
+        for role in [
+            ContributorRole::Author,
+            ContributorRole::Editor,
+            ContributorRole::Translator,
+            ContributorRole::Illustrator,
+        ] {
+            assert_eq!(
+                ContributorRole::try_from(role.as_str().to_string()),
+                Ok(role)
+            );
+        }
+    }
 }
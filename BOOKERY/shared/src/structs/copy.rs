@@ -0,0 +1,314 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::structs::{field_errors, Barcode, ConversionError, FieldError, ShelfLocation};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum CopyCondition {
+    New,
+    Good,
+    Worn,
+    Damaged,
+}
+
+impl CopyCondition {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CopyCondition::New => "new",
+            CopyCondition::Good => "good",
+            CopyCondition::Worn => "worn",
+            CopyCondition::Damaged => "damaged",
+        }
+    }
+}
+
+impl TryFrom<String> for CopyCondition {
+    type Error = ConversionError;
+
+    fn try_from(token: String) -> Result<Self, Self::Error> {
+        match token.as_str() {
+            "new" => Ok(CopyCondition::New),
+            "good" => Ok(CopyCondition::Good),
+            "worn" => Ok(CopyCondition::Worn),
+            "damaged" => Ok(CopyCondition::Damaged),
+            _ => Err(ConversionError::InvalidType),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum CopyStatus {
+    Available,
+    Maintenance,
+    Lost,
+    Withdrawn,
+}
+
+impl CopyStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CopyStatus::Available => "available",
+            CopyStatus::Maintenance => "maintenance",
+            CopyStatus::Lost => "lost",
+            CopyStatus::Withdrawn => "withdrawn",
+        }
+    }
+}
+
+impl TryFrom<String> for CopyStatus {
+    type Error = ConversionError;
+
+    fn try_from(token: String) -> Result<Self, Self::Error> {
+        match token.as_str() {
+            "available" => Ok(CopyStatus::Available),
+            "maintenance" => Ok(CopyStatus::Maintenance),
+            "lost" => Ok(CopyStatus::Lost),
+            "withdrawn" => Ok(CopyStatus::Withdrawn),
+            _ => Err(ConversionError::InvalidType),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct BookCopy {
+    pub id: Uuid,
+    pub book_uuid: Uuid,
+    pub barcode: Barcode,
+    pub condition: CopyCondition,
+    pub shelf_location: ShelfLocation,
+    pub status: CopyStatus,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct BookCopyWithLoan {
+    pub id: Uuid,
+    pub barcode: Barcode,
+    pub condition: CopyCondition,
+    pub shelf_location: ShelfLocation,
+    pub status: CopyStatus,
+    pub on_loan: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct BookCopies {
+    pub book_uuid: Uuid,
+    pub total: i64,
+    pub available: i64,
+    pub copies: Vec<BookCopyWithLoan>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PayloadBookCopy {
+    pub book_uuid: Uuid,
+    pub barcode: String,
+    pub condition: String,
+    pub shelf_location: String,
+    pub status: String,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PayloadUpdateBookCopy {
+    pub id: Uuid,
+    pub book_uuid: Uuid,
+    pub barcode: String,
+    pub condition: String,
+    pub shelf_location: String,
+    pub status: String,
+}
+
+impl BookCopyWithLoan {
+    pub fn is_available(&self) -> bool {
+        self.status == CopyStatus::Available && !self.on_loan
+    }
+}
+
+impl BookCopies {
+    pub fn new(book_uuid: Uuid, copies: Vec<BookCopyWithLoan>) -> Self {
+        let total: i64 = copies.len() as i64;
+        let available: i64 = copies
+            .iter()
+            .filter(|copy: &&BookCopyWithLoan| copy.is_available())
+            .count() as i64;
+
+        Self {
+            book_uuid,
+            total,
+            available,
+            copies,
+        }
+    }
+}
+
+impl BookCopy {
+    pub fn create(new_copy: PayloadBookCopy) -> Result<Self, Vec<FieldError>> {
+        let id: Uuid = Uuid::new_v4();
+
+        Self::validate(
+            id,
+            new_copy.book_uuid,
+            new_copy.barcode,
+            new_copy.condition,
+            new_copy.shelf_location,
+            new_copy.status,
+        )
+    }
+
+    pub fn parse(copy: PayloadUpdateBookCopy) -> Result<Self, Vec<FieldError>> {
+        Self::validate(
+            copy.id,
+            copy.book_uuid,
+            copy.barcode,
+            copy.condition,
+            copy.shelf_location,
+            copy.status,
+        )
+    }
+
+    fn validate(
+        id: Uuid,
+        book_uuid: Uuid,
+        barcode: String,
+        condition: String,
+        shelf_location: String,
+        status: String,
+    ) -> Result<Self, Vec<FieldError>> {
+        let barcode: Result<Barcode, ConversionError> = Barcode::try_from(barcode);
+        let condition: Result<CopyCondition, ConversionError> = CopyCondition::try_from(condition);
+        let shelf_location: Result<ShelfLocation, ConversionError> =
+            ShelfLocation::try_from(shelf_location);
+        let status: Result<CopyStatus, ConversionError> = CopyStatus::try_from(status);
+
+        match (barcode, condition, shelf_location, status) {
+            (Ok(barcode), Ok(condition), Ok(shelf_location), Ok(status)) => Ok(Self {
+                id,
+                book_uuid,
+                barcode,
+                condition,
+                shelf_location,
+                status,
+            }),
+            (barcode, condition, shelf_location, status) => Err(field_errors([
+                ("barcode", barcode.err()),
+                ("condition", condition.err()),
+                ("shelf_location", shelf_location.err()),
+                ("status", status.err()),
+            ])),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEFAULT_BARCODE: &str = "BK-000001";
+    const DEFAULT_SHELF_LOCATION: &str = "A-01";
+
+    #[test]
+    fn test_create_copy() {
+        let payload_copy: PayloadBookCopy = PayloadBookCopy {
+            book_uuid: Uuid::new_v4(),
+            barcode: DEFAULT_BARCODE.to_string(),
+            condition: "good".to_string(),
+            shelf_location: DEFAULT_SHELF_LOCATION.to_string(),
+            status: "available".to_string(),
+        };
+
+        let copy: BookCopy = BookCopy::create(payload_copy).unwrap();
+
+        assert_eq!(
+            copy,
+            BookCopy {
+                id: copy.id,
+                book_uuid: copy.book_uuid,
+                barcode: Barcode::try_from(DEFAULT_BARCODE.to_string()).unwrap(),
+                condition: CopyCondition::Good,
+                shelf_location: ShelfLocation::try_from(DEFAULT_SHELF_LOCATION.to_string())
+                    .unwrap(),
+                status: CopyStatus::Available,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_copy_collects_every_field_error() {
+        let payload_update_copy: PayloadUpdateBookCopy = PayloadUpdateBookCopy {
+            id: Uuid::new_v4(),
+            book_uuid: Uuid::new_v4(),
+            barcode: "BK 1".to_string(),
+            condition: "shiny".to_string(),
+            shelf_location: DEFAULT_SHELF_LOCATION.to_string(),
+            status: "borrowed".to_string(),
+        };
+
+        let errors: Vec<FieldError> = BookCopy::parse(payload_update_copy).unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![
+                ConversionError::InvalidType.on("barcode"),
+                ConversionError::InvalidType.on("condition"),
+                ConversionError::InvalidType.on("status"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_copy_enums_round_trip() {
+        for condition in [
+            CopyCondition::New,
+            CopyCondition::Good,
+            CopyCondition::Worn,
+            CopyCondition::Damaged,
+        ] {
+            assert_eq!(
+                CopyCondition::try_from(condition.as_str().to_string()),
+                Ok(condition)
+            );
+        }
+
+        for status in [
+            CopyStatus::Available,
+            CopyStatus::Maintenance,
+            CopyStatus::Lost,
+            CopyStatus::Withdrawn,
+        ] {
+            assert_eq!(
+                CopyStatus::try_from(status.as_str().to_string()),
+                Ok(status)
+            );
+        }
+    }
+
+    #[test]
+    fn test_book_copies_counts_available() {
+        let copy: BookCopyWithLoan = BookCopyWithLoan {
+            id: Uuid::new_v4(),
+            barcode: Barcode::try_from(DEFAULT_BARCODE.to_string()).unwrap(),
+            condition: CopyCondition::Good,
+            shelf_location: ShelfLocation::try_from(DEFAULT_SHELF_LOCATION.to_string()).unwrap(),
+            status: CopyStatus::Available,
+            on_loan: false,
+        };
+
+        let book_copies: BookCopies = BookCopies::new(
+            Uuid::new_v4(),
+            vec![
+                copy.clone(),
+                BookCopyWithLoan {
+                    on_loan: true,
+                    ..copy.clone()
+                },
+                BookCopyWithLoan {
+                    status: CopyStatus::Lost,
+                    ..copy
+                },
+            ],
+        );
+
+        assert_eq!(book_copies.total, 3);
+        assert_eq!(book_copies.available, 1);
+    }
+}
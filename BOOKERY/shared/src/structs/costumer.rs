@@ -2,13 +2,173 @@ use serde::{Deserialize, Serialize};
 use time::Date;
 use uuid::Uuid;
 
-use crate::structs::{ConversionError, PersonDocument, PersonName};
+use crate::structs::{
+    field_errors, BlockReason, CompanyDocument, ConversionError, FieldError, PersonDocument,
+    PersonName,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum DocumentKind {
+    Cpf,
+    Rg,
+    Passport,
+    Cnpj,
+    Legacy,
+}
+
+impl DocumentKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DocumentKind::Cpf => "cpf",
+            DocumentKind::Rg => "rg",
+            DocumentKind::Passport => "passport",
+            DocumentKind::Cnpj => "cnpj",
+            DocumentKind::Legacy => "legacy",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DocumentKind::Cpf => "CPF",
+            DocumentKind::Rg => "RG",
+            DocumentKind::Passport => "Passport",
+            DocumentKind::Cnpj => "CNPJ",
+            DocumentKind::Legacy => "Legacy",
+        }
+    }
+}
+
+impl TryFrom<String> for DocumentKind {
+    type Error = ConversionError;
+
+    fn try_from(token: String) -> Result<Self, Self::Error> {
+        match token.as_str() {
+            "cpf" => Ok(DocumentKind::Cpf),
+            "rg" => Ok(DocumentKind::Rg),
+            "passport" => Ok(DocumentKind::Passport),
+            "cnpj" => Ok(DocumentKind::Cnpj),
+            "legacy" => Ok(DocumentKind::Legacy),
+            _ => Err(ConversionError::InvalidType),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(try_from = "PayloadDocument")]
+pub struct Document {
+    kind: DocumentKind,
+    value: String,
+}
+
+impl Document {
+    pub fn parse(kind: String, value: String) -> Result<Self, ConversionError> {
+        let kind: DocumentKind = DocumentKind::try_from(kind)?;
+
+        let value: String = match kind {
+            DocumentKind::Cpf => PersonDocument::try_from(value)?.as_str(),
+            DocumentKind::Cnpj => CompanyDocument::try_from(value)?.as_str(),
+            DocumentKind::Rg => Self::parse_rg(Self::normalize(&value))?,
+            DocumentKind::Passport => Self::parse_passport(Self::normalize(&value))?,
+            DocumentKind::Legacy => Self::parse_legacy(value)?,
+        };
+
+        Ok(Self { kind, value })
+    }
+
+    pub fn parse_payload(document: PayloadDocument) -> Result<Self, ConversionError> {
+        match Self::parse(document.kind, document.value)? {
+            Document {
+                kind: DocumentKind::Legacy,
+                ..
+            } => Err(ConversionError::InvalidType),
+            document => Ok(document),
+        }
+    }
+
+    pub fn normalize(token: &str) -> String {
+        token
+            .chars()
+            .filter(|c: &char| !matches!(c, '.' | '-' | '/' | ' '))
+            .collect::<String>()
+            .to_uppercase()
+    }
+
+    fn parse_rg(token: String) -> Result<String, ConversionError> {
+        if !(5..=14).contains(&token.len()) {
+            Err(ConversionError::TokenIncompatibleSize)
+        } else if !token
+            .char_indices()
+            .all(|(i, c): (usize, char)| c.is_ascii_digit() || (c == 'X' && i == token.len() - 1))
+        {
+            Err(ConversionError::InvalidType)
+        } else {
+            Ok(token)
+        }
+    }
+
+    fn parse_passport(token: String) -> Result<String, ConversionError> {
+        if !(6..=9).contains(&token.len()) {
+            Err(ConversionError::TokenIncompatibleSize)
+        } else if !token.chars().all(|c: char| c.is_ascii_alphanumeric())
+            || !token.chars().any(|c: char| c.is_ascii_digit())
+        {
+            Err(ConversionError::InvalidType)
+        } else {
+            Ok(token)
+        }
+    }
+
+    fn parse_legacy(token: String) -> Result<String, ConversionError> {
+        if token.len() != 11 {
+            Err(ConversionError::TokenIncompatibleSize)
+        } else if !token.chars().all(|c: char| c.is_ascii_digit()) {
+            Err(ConversionError::InvalidType)
+        } else {
+            Ok(token)
+        }
+    }
+
+    pub fn kind(&self) -> DocumentKind {
+        self.kind
+    }
+
+    pub fn as_str(&self) -> String {
+        String::from(&self.value)
+    }
+
+    pub fn formatted(&self) -> String {
+        match self.kind {
+            DocumentKind::Cpf => PersonDocument::try_from(self.as_str())
+                .map(|document: PersonDocument| document.formatted())
+                .unwrap_or_else(|_| self.as_str()),
+            DocumentKind::Cnpj => CompanyDocument::try_from(self.as_str())
+                .map(|document: CompanyDocument| document.formatted())
+                .unwrap_or_else(|_| self.as_str()),
+            DocumentKind::Rg | DocumentKind::Passport | DocumentKind::Legacy => self.as_str(),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct PayloadDocument {
+    pub kind: String,
+    pub value: String,
+}
+
+impl TryFrom<PayloadDocument> for Document {
+    type Error = ConversionError;
+
+    fn try_from(document: PayloadDocument) -> Result<Self, Self::Error> {
+        Document::parse(document.kind, document.value)
+    }
+}
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct Costumer {
     pub id: Uuid,
     pub name: PersonName,
-    pub document: PersonDocument,
+    pub document: Document,
     #[serde(with = "super::date_format")]
     pub born: Date,
 }
@@ -16,7 +176,7 @@ pub struct Costumer {
 #[derive(Deserialize, Serialize)]
 pub struct PayloadCostumer {
     pub name: String,
-    pub document: String,
+    pub document: PayloadDocument,
     #[serde(with = "super::date_format")]
     pub born: Date,
 }
@@ -25,15 +185,66 @@ pub struct PayloadCostumer {
 pub struct PayloadUpdateCostumer {
     pub id: Uuid,
     pub name: String,
-    pub document: String,
+    pub document: PayloadDocument,
     #[serde(with = "super::date_format")]
     pub born: Date,
 }
 
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct CostumerBlock {
+    pub id: Uuid,
+    pub reason: BlockReason,
+    #[serde(with = "super::option_date_format")]
+    pub until: Option<Date>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct CostumerSummary {
+    pub id: Uuid,
+    pub total_borrowed: i64,
+    pub current_loans: i64,
+    pub overdue_count: i64,
+    pub fees_owed: i64,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PayloadBlockCostumer {
+    pub id: Uuid,
+    pub reason: String,
+    #[serde(default, with = "super::option_date_format")]
+    pub until: Option<Date>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PayloadUnblockCostumer {
+    pub id: Uuid,
+}
+
+#[derive(Deserialize, Serialize, Default, Debug, PartialEq, Clone)]
+pub struct CostumerFilter {
+    pub document: Option<String>,
+    pub document_kind: Option<DocumentKind>,
+    #[serde(default, with = "super::option_date_format")]
+    pub born_from: Option<Date>,
+    #[serde(default, with = "super::option_date_format")]
+    pub born_until: Option<Date>,
+}
+
 impl Costumer {
-    pub fn create(new_costumer: PayloadCostumer) -> Result<Self, ConversionError> {
-        let name: PersonName = PersonName::try_from(new_costumer.name)?;
-        let document: PersonDocument = PersonDocument::try_from(new_costumer.document)?;
+    pub fn create(new_costumer: PayloadCostumer) -> Result<Self, Vec<FieldError>> {
+        let name: Result<PersonName, ConversionError> = PersonName::try_from(new_costumer.name);
+        let document: Result<Document, ConversionError> =
+            Document::parse_payload(new_costumer.document);
+
+        let (name, document): (PersonName, Document) = match (name, document) {
+            (Ok(name), Ok(document)) => (name, document),
+            (name, document) => {
+                return Err(field_errors([
+                    ("name", name.err()),
+                    ("document", document.err()),
+                ]))
+            }
+        };
         let born: Date = new_costumer.born;
         let id: Uuid = Uuid::new_v4();
 
@@ -45,9 +256,20 @@ impl Costumer {
         })
     }
 
-    pub fn parse(costumer: PayloadUpdateCostumer) -> Result<Self, ConversionError> {
-        let name: PersonName = PersonName::try_from(costumer.name)?;
-        let document: PersonDocument = PersonDocument::try_from(costumer.document)?;
+    pub fn parse(costumer: PayloadUpdateCostumer) -> Result<Self, Vec<FieldError>> {
+        let name: Result<PersonName, ConversionError> = PersonName::try_from(costumer.name);
+        let document: Result<Document, ConversionError> =
+            Document::parse_payload(costumer.document);
+
+        let (name, document): (PersonName, Document) = match (name, document) {
+            (Ok(name), Ok(document)) => (name, document),
+            (name, document) => {
+                return Err(field_errors([
+                    ("name", name.err()),
+                    ("document", document.err()),
+                ]))
+            }
+        };
         let born: Date = costumer.born;
 
         Ok(Self {
@@ -59,22 +281,42 @@ impl Costumer {
     }
 }
 
+impl CostumerBlock {
+    pub fn create(block: PayloadBlockCostumer) -> Result<Self, Vec<FieldError>> {
+        let reason: BlockReason = BlockReason::try_from(block.reason)
+            .map_err(|e: ConversionError| vec![e.on("reason")])?;
+
+        Ok(Self {
+            id: block.id,
+            reason,
+            until: block.until,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use time::{error::ComponentRange, Month};
 
-    const DEFAULT_NAME: &'static str = "Name";
-    const DEFAULT_DOCUMENT: &'static str = "12345678901";
+    const DEFAULT_NAME: &str = "Name";
+    const DEFAULT_DOCUMENT: &str = "12345678909";
     const DEFAULT_BORN: Result<Date, ComponentRange> =
         Date::from_calendar_date(2000, Month::January, 1);
 
+    fn default_payload_document() -> PayloadDocument {
+        PayloadDocument {
+            kind: "cpf".to_string(),
+            value: DEFAULT_DOCUMENT.to_string(),
+        }
+    }
+
     #[test]
     fn test_create_costumer() {
         let payload_costumer: PayloadCostumer = PayloadCostumer {
             name: DEFAULT_NAME.to_string(),
-            document: DEFAULT_DOCUMENT.to_string(),
+            document: default_payload_document(),
             born: DEFAULT_BORN.unwrap(),
         };
 
@@ -85,7 +327,7 @@ mod tests {
             Costumer {
                 id: costumer.id,
                 name: PersonName::try_from(DEFAULT_NAME.to_string()).unwrap(),
-                document: PersonDocument::try_from(DEFAULT_DOCUMENT.to_string()).unwrap(),
+                document: Document::parse("cpf".to_string(), DEFAULT_DOCUMENT.to_string()).unwrap(),
                 born: DEFAULT_BORN.unwrap(),
             }
         );
@@ -96,11 +338,11 @@ mod tests {
         let payload_update_costumer: PayloadUpdateCostumer = PayloadUpdateCostumer {
             id: Uuid::new_v4(),
             name: DEFAULT_NAME.to_string(),
-            document: DEFAULT_DOCUMENT.to_string(),
+            document: default_payload_document(),
             born: DEFAULT_BORN.unwrap(),
         };
 
-        let costumer_uuid: Uuid = payload_update_costumer.id.clone();
+        let costumer_uuid: Uuid = payload_update_costumer.id;
 
         let costumer: Costumer = Costumer::parse(payload_update_costumer).unwrap();
 
@@ -109,9 +351,170 @@ mod tests {
             Costumer {
                 id: costumer_uuid,
                 name: PersonName::try_from(DEFAULT_NAME.to_string()).unwrap(),
-                document: PersonDocument::try_from(DEFAULT_DOCUMENT.to_string()).unwrap(),
+                document: Document::parse("cpf".to_string(), DEFAULT_DOCUMENT.to_string()).unwrap(),
                 born: DEFAULT_BORN.unwrap(),
             }
         );
     }
+
+    #[test]
+    fn test_create_costumer_collects_every_field_error() {
+        let payload_costumer: PayloadCostumer = PayloadCostumer {
+            name: "Name 0".to_string(),
+            document: PayloadDocument {
+                kind: "cpf".to_string(),
+                value: "123".to_string(),
+            },
+            born: DEFAULT_BORN.unwrap(),
+        };
+
+        let errors: Vec<FieldError> = Costumer::create(payload_costumer).unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![
+                ConversionError::InvalidType.on("name"),
+                ConversionError::TokenIncompatibleSize.on("document"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_costumer_collects_single_field_error() {
+        let payload_update_costumer: PayloadUpdateCostumer = PayloadUpdateCostumer {
+            id: Uuid::new_v4(),
+            name: DEFAULT_NAME.to_string(),
+            document: PayloadDocument {
+                kind: "cpf".to_string(),
+                value: "a0000000000".to_string(),
+            },
+            born: DEFAULT_BORN.unwrap(),
+        };
+
+        let errors: Vec<FieldError> = Costumer::parse(payload_update_costumer).unwrap_err();
+
+        assert_eq!(errors, vec![ConversionError::InvalidType.on("document")]);
+    }
+
+    #[test]
+    fn test_parse_document_per_kind() {
+        let cases: [(&str, &str, &str); 5] = [
+            ("cpf", "123.456.789-09", "12345678909"),
+            ("rg", "12.345.678-x", "12345678X"),
+            ("passport", "ab 123456", "AB123456"),
+            ("cnpj", "11.222.333/0001-81", "11222333000181"),
+            ("legacy", "00000000000", "00000000000"),
+        ];
+
+        for (kind, value, expected) in cases {
+            let document: Document = Document::parse(kind.to_string(), value.to_string()).unwrap();
+
+            assert_eq!(document.kind().as_str(), kind);
+            assert_eq!(document.as_str(), expected);
+        }
+    }
+
+    #[test]
+    fn test_fail_document_per_kind() {
+        let cases: [(&str, &str, ConversionError); 7] = [
+            ("ssn", "12345678909", ConversionError::InvalidType),
+            ("cpf", "11111111111", ConversionError::InvalidChecksum),
+            ("rg", "1234", ConversionError::TokenIncompatibleSize),
+            ("rg", "12X45678", ConversionError::InvalidType),
+            ("passport", "ABCDEFG", ConversionError::InvalidType),
+            ("cnpj", "11222333000180", ConversionError::InvalidChecksum),
+            ("legacy", "0000000000a", ConversionError::InvalidType),
+        ];
+
+        for (kind, value, error) in cases {
+            assert_eq!(
+                Document::parse(kind.to_string(), value.to_string()),
+                Err(error)
+            );
+        }
+    }
+
+    #[test]
+    fn test_create_costumer_legacy_document() {
+        let payload_costumer: PayloadCostumer = PayloadCostumer {
+            name: DEFAULT_NAME.to_string(),
+            document: PayloadDocument {
+                kind: "legacy".to_string(),
+                value: "00000000000".to_string(),
+            },
+            born: DEFAULT_BORN.unwrap(),
+        };
+
+        let errors: Vec<FieldError> = Costumer::create(payload_costumer).unwrap_err();
+
+        assert_eq!(errors, vec![ConversionError::InvalidType.on("document")]);
+    }
+
+    #[test]
+    fn test_format_document() {
+        let cpf: Document =
+            Document::parse("cpf".to_string(), DEFAULT_DOCUMENT.to_string()).unwrap();
+        let cnpj: Document =
+            Document::parse("cnpj".to_string(), "11222333000181".to_string()).unwrap();
+        let passport: Document =
+            Document::parse("passport".to_string(), "AB123456".to_string()).unwrap();
+
+        assert_eq!(cpf.formatted(), "123.456.789-09");
+        assert_eq!(cnpj.formatted(), "11.222.333/0001-81");
+        assert_eq!(passport.formatted(), "AB123456");
+    }
+
+    #[test]
+    fn test_deserialize_costumer_validates_document() {
+        let costumer: Costumer = Costumer::create(PayloadCostumer {
+            name: DEFAULT_NAME.to_string(),
+            document: default_payload_document(),
+            born: DEFAULT_BORN.unwrap(),
+        })
+        .unwrap();
+
+        let json: String = serde_json::to_string(&costumer).unwrap();
+
+        assert_eq!(serde_json::from_str::<Costumer>(&json).unwrap(), costumer);
+        assert!(
+            serde_json::from_str::<Costumer>(&json.replace(DEFAULT_DOCUMENT, "12345678901"))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_create_costumer_block() {
+        let payload_block: PayloadBlockCostumer = PayloadBlockCostumer {
+            id: Uuid::new_v4(),
+            reason: "Damaged a book".to_string(),
+            until: Some(DEFAULT_BORN.unwrap()),
+        };
+
+        let block: CostumerBlock = CostumerBlock::create(payload_block).unwrap();
+
+        assert_eq!(
+            block,
+            CostumerBlock {
+                id: block.id,
+                reason: BlockReason::try_from("Damaged a book".to_string()).unwrap(),
+                until: Some(DEFAULT_BORN.unwrap()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_create_costumer_block_empty_reason() {
+        let payload_block: PayloadBlockCostumer = PayloadBlockCostumer {
+            id: Uuid::new_v4(),
+            reason: String::new(),
+            until: None,
+        };
+
+        let errors: Vec<FieldError> = CostumerBlock::create(payload_block).unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![ConversionError::TokenIncompatibleSize.on("reason")]
+        );
+    }
 }
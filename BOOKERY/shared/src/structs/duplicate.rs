@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct DuplicateGroup<T> {
+    pub key: String,
+    #[serde(with = "super::date_format")]
+    pub born: Date,
+    pub items: Vec<T>,
+}
+
+impl<T> DuplicateGroup<T> {
+    pub fn group(rows: Vec<(String, Date, T)>) -> Vec<Self> {
+        let mut groups: Vec<Self> = vec![];
+
+        for (key, born, item) in rows {
+            match groups.last_mut() {
+                Some(group) if group.key == key && group.born == born => group.items.push(item),
+                _ => groups.push(Self {
+                    key,
+                    born,
+                    items: vec![item],
+                }),
+            }
+        }
+
+        groups
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PayloadMerge {
+    pub survivor_uuid: Uuid,
+    pub loser_uuid: Uuid,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+pub struct Merge {
+    pub survivor_uuid: Uuid,
+    pub loser_uuid: Uuid,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct AuthorMergeSummary {
+    pub survivor_uuid: Uuid,
+    pub loser_uuid: Uuid,
+    pub books: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct CostumerMergeSummary {
+    pub survivor_uuid: Uuid,
+    pub loser_uuid: Uuid,
+    pub rentals: i64,
+    pub fees: i64,
+    pub reservations: i64,
+}
+
+impl Merge {
+    pub fn parse(merge: PayloadMerge) -> Option<Self> {
+        if merge.survivor_uuid == merge.loser_uuid {
+            return None;
+        }
+
+        Some(Self {
+            survivor_uuid: merge.survivor_uuid,
+            loser_uuid: merge.loser_uuid,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use time::Month;
+
+    #[test]
+    fn test_parse_merge() {
+        let payload_merge: PayloadMerge = PayloadMerge {
+            survivor_uuid: Uuid::new_v4(),
+            loser_uuid: Uuid::new_v4(),
+        };
+
+        let survivor_uuid: Uuid = payload_merge.survivor_uuid;
+        let loser_uuid: Uuid = payload_merge.loser_uuid;
+
+        assert_eq!(
+            Merge::parse(payload_merge).unwrap(),
+            Merge {
+                survivor_uuid,
+                loser_uuid,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_merge_into_itself() {
+        let uuid: Uuid = Uuid::new_v4();
+
+        let payload_merge: PayloadMerge = PayloadMerge {
+            survivor_uuid: uuid,
+            loser_uuid: uuid,
+        };
+
+        assert_eq!(Merge::parse(payload_merge), None);
+    }
+
+    #[test]
+    fn test_group_consecutive_rows() {
+        let born: Date = Date::from_calendar_date(2000, Month::January, 1).unwrap();
+        let other_born: Date = Date::from_calendar_date(2001, Month::January, 1).unwrap();
+
+        let groups: Vec<DuplicateGroup<u8>> = DuplicateGroup::group(vec![
+            ("josesilva".to_string(), born, 1),
+            ("josesilva".to_string(), born, 2),
+            ("josesilva".to_string(), other_born, 3),
+            ("mariasouza".to_string(), other_born, 4),
+        ]);
+
+        assert_eq!(
+            groups,
+            vec![
+                DuplicateGroup {
+                    key: "josesilva".to_string(),
+                    born,
+                    items: vec![1, 2],
+                },
+                DuplicateGroup {
+                    key: "josesilva".to_string(),
+                    born: other_born,
+                    items: vec![3],
+                },
+                DuplicateGroup {
+                    key: "mariasouza".to_string(),
+                    born: other_born,
+                    items: vec![4],
+                },
+            ]
+        );
+    }
+}
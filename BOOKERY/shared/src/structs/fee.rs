@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Fee {
+    pub id: Uuid,
+    pub costumer_uuid: Uuid,
+    pub rental_uuid: Uuid,
+    pub amount: i64,
+    pub days_overdue: i64,
+    #[serde(with = "super::date_format")]
+    pub charged_at: Date,
+    #[serde(with = "super::option_date_format")]
+    pub paid_at: Option<Date>,
+}
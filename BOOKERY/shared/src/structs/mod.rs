@@ -1,14 +1,65 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum ConversionError {
     TokenTooLong,
     TokenIncompatibleSize,
     InvalidType,
+    InvalidChecksum,
+}
+
+impl ConversionError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ConversionError::TokenTooLong => "token_too_long",
+            ConversionError::TokenIncompatibleSize => "token_incompatible_size",
+            ConversionError::InvalidType => "invalid_type",
+            ConversionError::InvalidChecksum => "invalid_checksum",
+        }
+    }
+
+    pub fn message(&self) -> &'static str {
+        match self {
+            ConversionError::TokenTooLong => "Value is longer than allowed",
+            ConversionError::TokenIncompatibleSize => "Value does not have the expected size",
+            ConversionError::InvalidType => "Value contains characters that are not allowed",
+            ConversionError::InvalidChecksum => "Value does not match its check digits",
+        }
+    }
+
+    pub fn on(self, field: &'static str) -> FieldError {
+        FieldError { field, error: self }
+    }
+}
+
+impl std::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct FieldError {
+    pub field: &'static str,
+    pub error: ConversionError,
+}
+
+pub fn field_errors<const N: usize>(
+    fields: [(&'static str, Option<ConversionError>); N],
+) -> Vec<FieldError> {
+    fields
+        .into_iter()
+        .filter_map(|(field, error): (&'static str, Option<ConversionError>)| {
+            error.map(|e: ConversionError| e.on(field))
+        })
+        .collect()
 }
 
 mod person_name {
     #[derive(super::Serialize, super::Deserialize, Debug, PartialEq, Clone)] // TODO compare bin with and without this params
+    #[serde(try_from = "String")]
     pub struct PersonName(String);
 
     impl PersonName {
@@ -27,11 +78,17 @@ mod person_name {
         type Error = super::ConversionError;
 
         fn try_from(token: String) -> Result<Self, Self::Error> {
-            if token.len() > 128 {
+            let token: String = token.split_whitespace().collect::<Vec<&str>>().join(" ");
+
+            if token.is_empty() {
+                return Err(super::ConversionError::TokenIncompatibleSize);
+            } else if token.chars().count() > 120 {
                 return Err(super::ConversionError::TokenTooLong);
-            } else if !token
-                .chars()
-                .all(|c: char| char::is_ascii_alphabetic(&c) || c == ' ')
+            } else if !token.chars().any(char::is_alphabetic)
+                || !token.chars().all(|c: char| {
+                    c.is_alphabetic()
+                        || matches!(c, ' ' | '\'' | '’' | '-' | '.' | '\u{0300}'..='\u{036F}')
+                })
             {
                 return Err(super::ConversionError::InvalidType);
             }
@@ -42,8 +99,6 @@ mod person_name {
 
     #[cfg(test)]
     mod tests {
-        use std::iter::repeat;
-
         use super::*;
 
         #[test]
@@ -59,7 +114,7 @@ mod person_name {
 
         #[test]
         fn test_pass_person_name_limit() {
-            let name: String = repeat("x").take(128).collect();
+            let name: String = "á".repeat(120);
 
             PersonName::try_from(name).unwrap();
         }
@@ -67,16 +122,44 @@ mod person_name {
         #[test]
         #[should_panic]
         fn test_fail_person_name_limit() {
-            let name: String = repeat("x").take(129).collect();
+            let name: String = "x".repeat(121);
 
             PersonName::try_from(name).unwrap();
         }
 
         #[test]
         fn test_pass_person_name_charset() {
-            let name: String = "abcxyz ABCXYZ".to_string();
+            for name in [
+                "abcxyz ABCXYZ",
+                "José",
+                "Zoë",
+                "O'Brien",
+                "Jean-Luc",
+                "J. R. R. Tolkien",
+                "Conceição Evaristo",
+            ] {
+                PersonName::try_from(name.to_string()).unwrap();
+            }
+        }
 
-            PersonName::try_from(name).unwrap();
+        #[test]
+        fn test_normalize_person_name_whitespace() {
+            let person_name: PersonName =
+                PersonName::try_from("  Machado \t de   Assis ".to_string()).unwrap();
+
+            assert_eq!(person_name, PersonName("Machado de Assis".to_string()));
+        }
+
+        #[test]
+        fn test_fail_person_name_without_letters() {
+            assert_eq!(
+                PersonName::try_from("   ".to_string()),
+                Err(crate::structs::ConversionError::TokenIncompatibleSize)
+            );
+            assert_eq!(
+                PersonName::try_from("-.'".to_string()),
+                Err(crate::structs::ConversionError::InvalidType)
+            );
         }
 
         #[test]
@@ -86,11 +169,27 @@ mod person_name {
 
             PersonName::try_from(name).unwrap();
         }
+
+        #[test]
+        fn test_deserialize_person_name() {
+            let person_name: PersonName =
+                serde_json::from_str(r#""  Machado   de Assis ""#).unwrap();
+
+            assert_eq!(person_name, PersonName("Machado de Assis".to_string()));
+
+            let error: serde_json::Error =
+                serde_json::from_str::<PersonName>(r#""Machado 2""#).unwrap_err();
+
+            assert!(error
+                .to_string()
+                .contains(crate::structs::ConversionError::InvalidType.message()));
+        }
     }
 }
 
 mod book_name {
     #[derive(super::Serialize, super::Deserialize, Debug, PartialEq, Clone)]
+    #[serde(try_from = "String")]
     pub struct BookName(String);
 
     impl BookName {
@@ -118,8 +217,6 @@ mod book_name {
 
     #[cfg(test)]
     mod tests {
-        use std::iter::repeat;
-
         use super::*;
 
         #[test]
@@ -135,7 +232,7 @@ mod book_name {
 
         #[test]
         fn test_pass_book_name_limit() {
-            let name: String = repeat("x").take(64).collect();
+            let name: String = "x".repeat(64);
 
             BookName::try_from(name).unwrap();
         }
@@ -143,7 +240,7 @@ mod book_name {
         #[test]
         #[should_panic]
         fn test_fail_book_name_limit() {
-            let name: String = repeat("x").take(65).collect();
+            let name: String = "x".repeat(65);
 
             BookName::try_from(name).unwrap();
         }
@@ -154,11 +251,26 @@ mod book_name {
 
             BookName::try_from(name).unwrap();
         }
+
+        #[test]
+        fn test_deserialize_book_name() {
+            let book_name: BookName = serde_json::from_str(r#""Name""#).unwrap();
+
+            assert_eq!(book_name, BookName("Name".to_string()));
+
+            let error: serde_json::Error =
+                serde_json::from_str::<BookName>(&format!("\"{}\"", "x".repeat(65))).unwrap_err();
+
+            assert!(error
+                .to_string()
+                .contains(crate::structs::ConversionError::TokenTooLong.message()));
+        }
     }
 }
 
 mod editor_name {
     #[derive(super::Serialize, super::Deserialize, Debug, PartialEq, Clone)]
+    #[serde(try_from = "String")]
     pub struct EditorName(String);
 
     impl EditorName {
@@ -179,7 +291,7 @@ mod editor_name {
         fn try_from(token: String) -> Result<Self, Self::Error> {
             if token.len() > 64 {
                 return Err(super::ConversionError::TokenTooLong);
-            } else if !token.chars().all(|c: char| char::is_ascii(&c)) {
+            } else if !token.is_ascii() {
                 return Err(super::ConversionError::InvalidType);
             }
 
@@ -189,8 +301,6 @@ mod editor_name {
 
     #[cfg(test)]
     mod tests {
-        use std::iter::repeat;
-
         use super::*;
 
         #[test]
@@ -206,7 +316,7 @@ mod editor_name {
 
         #[test]
         fn test_pass_editor_name_limit() {
-            let name: String = repeat("x").take(64).collect();
+            let name: String = "x".repeat(64);
 
             EditorName::try_from(name).unwrap();
         }
@@ -214,7 +324,7 @@ mod editor_name {
         #[test]
         #[should_panic]
         fn test_fail_editor_name_limit() {
-            let name: String = repeat("x").take(65).collect();
+            let name: String = "x".repeat(65);
 
             EditorName::try_from(name).unwrap();
         }
@@ -233,17 +343,300 @@ mod editor_name {
 
             EditorName::try_from(name).unwrap();
         }
+
+        #[test]
+        fn test_deserialize_editor_name() {
+            let editor_name: EditorName = serde_json::from_str(r#""Name""#).unwrap();
+
+            assert_eq!(editor_name, EditorName("Name".to_string()));
+
+            let error: serde_json::Error =
+                serde_json::from_str::<EditorName>(&format!("\"{}\"", "x".repeat(65))).unwrap_err();
+
+            assert!(error
+                .to_string()
+                .contains(crate::structs::ConversionError::TokenTooLong.message()));
+        }
+    }
+}
+
+mod subject_name {
+    #[derive(super::Serialize, super::Deserialize, Debug, PartialEq, Clone)]
+    #[serde(try_from = "String")]
+    pub struct SubjectName(String);
+
+    impl SubjectName {
+        pub fn as_str(&self) -> String {
+            String::from(&self.0)
+        }
+    }
+
+    impl From<SubjectName> for String {
+        fn from(value: SubjectName) -> String {
+            value.0
+        }
+    }
+
+    impl TryFrom<String> for SubjectName {
+        type Error = super::ConversionError;
+
+        fn try_from(token: String) -> Result<Self, Self::Error> {
+            let token: &str = token.trim();
+
+            if token.is_empty() {
+                return Err(super::ConversionError::TokenIncompatibleSize);
+            } else if token.chars().count() > 64 {
+                return Err(super::ConversionError::TokenTooLong);
+            } else if token.chars().any(char::is_control) {
+                return Err(super::ConversionError::InvalidType);
+            }
+
+            Ok(SubjectName(token.to_string()))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_create_subject_name() {
+            let subject_name: SubjectName =
+                SubjectName::try_from("  Ficção  ".to_string()).unwrap();
+
+            assert_eq!(subject_name, SubjectName("Ficção".to_string()));
+        }
+
+        #[test]
+        fn test_pass_subject_name_limit() {
+            let name: String = "ç".repeat(64);
+
+            SubjectName::try_from(name).unwrap();
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_fail_subject_name_limit() {
+            let name: String = "x".repeat(65);
+
+            SubjectName::try_from(name).unwrap();
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_fail_subject_name_empty() {
+            SubjectName::try_from("  ".to_string()).unwrap();
+        }
+
+        #[test]
+        fn test_deserialize_subject_name() {
+            let subject_name: SubjectName = serde_json::from_str(r#""  Ficção  ""#).unwrap();
+
+            assert_eq!(subject_name, SubjectName("Ficção".to_string()));
+
+            let error: serde_json::Error =
+                serde_json::from_str::<SubjectName>(&format!("\"{}\"", "x".repeat(65)))
+                    .unwrap_err();
+
+            assert!(error
+                .to_string()
+                .contains(crate::structs::ConversionError::TokenTooLong.message()));
+        }
+    }
+}
+
+mod isbn {
+    #[derive(super::Serialize, super::Deserialize, Debug, PartialEq, Clone)]
+    #[serde(try_from = "String")]
+    pub struct Isbn(String);
+
+    impl Isbn {
+        pub fn as_str(&self) -> String {
+            String::from(&self.0)
+        }
+
+        fn isbn_10_is_valid(digits: &[u32]) -> bool {
+            let sum: u32 = digits
+                .iter()
+                .zip((1..=10).rev())
+                .map(|(digit, weight): (&u32, u32)| digit * weight)
+                .sum();
+
+            sum.is_multiple_of(11)
+        }
+
+        fn isbn_13_check_digit(digits: &[u32]) -> u32 {
+            let sum: u32 = digits
+                .iter()
+                .take(12)
+                .enumerate()
+                .map(|(i, digit): (usize, &u32)| if i % 2 == 0 { *digit } else { digit * 3 })
+                .sum();
+
+            (10 - sum % 10) % 10
+        }
+    }
+
+    impl From<Isbn> for String {
+        fn from(value: Isbn) -> String {
+            value.0
+        }
+    }
+
+    impl TryFrom<String> for Isbn {
+        type Error = super::ConversionError;
+
+        fn try_from(token: String) -> Result<Self, Self::Error> {
+            let token: String = token
+                .chars()
+                .filter(|c: &char| *c != '-' && *c != ' ')
+                .collect();
+
+            if token.len() != 10 && token.len() != 13 {
+                return Err(super::ConversionError::TokenIncompatibleSize);
+            }
+
+            let mut digits: Vec<u32> = Vec::with_capacity(13);
+
+            for (i, c) in token.chars().enumerate() {
+                match c.to_digit(10) {
+                    Some(digit) => digits.push(digit),
+                    None if token.len() == 10 && i == 9 && (c == 'X' || c == 'x') => {
+                        digits.push(10)
+                    }
+                    None => return Err(super::ConversionError::InvalidType),
+                }
+            }
+
+            if digits.len() == 10 {
+                if !Self::isbn_10_is_valid(&digits) {
+                    return Err(super::ConversionError::InvalidChecksum);
+                }
+
+                digits = [9, 7, 8]
+                    .into_iter()
+                    .chain(digits.into_iter().take(9))
+                    .collect();
+                digits.push(Self::isbn_13_check_digit(&digits));
+            } else if digits[12] != Self::isbn_13_check_digit(&digits) {
+                return Err(super::ConversionError::InvalidChecksum);
+            }
+
+            Ok(Isbn(
+                digits
+                    .iter()
+                    .map(|digit: &u32| char::from_digit(*digit, 10).unwrap())
+                    .collect(),
+            ))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_create_isbn_13() {
+            let isbn: Isbn = Isbn::try_from("978-0-306-40615-7".to_string()).unwrap();
+
+            assert_eq!(isbn, Isbn("9780306406157".to_string()));
+        }
+
+        #[test]
+        fn test_convert_isbn_10_to_13() {
+            let isbn: Isbn = Isbn::try_from("0-306-40615-2".to_string()).unwrap();
+
+            assert_eq!(isbn, Isbn("9780306406157".to_string()));
+        }
+
+        #[test]
+        fn test_convert_isbn_10_with_check_x() {
+            let isbn: Isbn = Isbn::try_from("0 8044 2957 x".to_string()).unwrap();
+
+            assert_eq!(isbn, Isbn("9780804429573".to_string()));
+        }
+
+        #[test]
+        fn test_fail_isbn_size() {
+            assert_eq!(
+                Isbn::try_from("978030640615".to_string()),
+                Err(crate::structs::ConversionError::TokenIncompatibleSize)
+            );
+        }
+
+        #[test]
+        fn test_fail_isbn_charset() {
+            assert_eq!(
+                Isbn::try_from("97803064061X7".to_string()),
+                Err(crate::structs::ConversionError::InvalidType)
+            );
+            assert_eq!(
+                Isbn::try_from("X306406152".to_string()),
+                Err(crate::structs::ConversionError::InvalidType)
+            );
+        }
+
+        #[test]
+        fn test_fail_isbn_check_digit() {
+            assert_eq!(
+                Isbn::try_from("9780306406158".to_string()),
+                Err(crate::structs::ConversionError::InvalidChecksum)
+            );
+            assert_eq!(
+                Isbn::try_from("0306406153".to_string()),
+                Err(crate::structs::ConversionError::InvalidChecksum)
+            );
+        }
+
+        #[test]
+        fn test_deserialize_isbn() {
+            let isbn: Isbn = serde_json::from_str(r#""978-0-306-40615-7""#).unwrap();
+
+            assert_eq!(isbn, Isbn("9780306406157".to_string()));
+
+            let error: serde_json::Error =
+                serde_json::from_str::<Isbn>(r#""9780306406158""#).unwrap_err();
+
+            assert!(error
+                .to_string()
+                .contains(crate::structs::ConversionError::InvalidChecksum.message()));
+        }
     }
 }
 
 mod person_document {
     #[derive(super::Serialize, super::Deserialize, Debug, PartialEq, Clone)]
+    #[serde(try_from = "String")]
     pub struct PersonDocument(String);
 
     impl PersonDocument {
         pub fn as_str(&self) -> String {
             String::from(&self.0)
         }
+
+        pub fn formatted(&self) -> String {
+            format!(
+                "{}.{}.{}-{}",
+                &self.0[..3],
+                &self.0[3..6],
+                &self.0[6..9],
+                &self.0[9..]
+            )
+        }
+
+        fn check_digit(digits: &[u32]) -> u32 {
+            let weight: u32 = digits.len() as u32 + 1;
+            let sum: u32 = digits
+                .iter()
+                .enumerate()
+                .map(|(i, digit): (usize, &u32)| digit * (weight - i as u32))
+                .sum();
+
+            match sum % 11 {
+                0 | 1 => 0,
+                rest => 11 - rest,
+            }
+        }
     }
 
     impl From<PersonDocument> for String {
@@ -256,25 +649,40 @@ mod person_document {
         type Error = super::ConversionError;
 
         fn try_from(token: String) -> Result<Self, Self::Error> {
+            let token: String = token
+                .chars()
+                .filter(|c: &char| !matches!(c, '.' | '-'))
+                .collect();
+
             if token.len() != 11 {
                 return Err(super::ConversionError::TokenIncompatibleSize);
             } else if !token.chars().all(|c: char| char::is_ascii_digit(&c)) {
                 return Err(super::ConversionError::InvalidType);
             }
 
+            let digits: Vec<u32> = token
+                .chars()
+                .map(|c: char| c.to_digit(10).unwrap())
+                .collect();
+
+            if digits.iter().all(|digit: &u32| *digit == digits[0])
+                || PersonDocument::check_digit(&digits[..9]) != digits[9]
+                || PersonDocument::check_digit(&digits[..10]) != digits[10]
+            {
+                return Err(super::ConversionError::InvalidChecksum);
+            }
+
             Ok(PersonDocument(token))
         }
     }
 
     #[cfg(test)]
     mod tests {
-        use std::iter::repeat;
-
         use super::*;
 
         #[test]
         fn test_create_person_document() {
-            let document: String = "00000000000".to_string();
+            let document: String = "12345678909".to_string();
 
             let person_document_document: String = document.clone();
 
@@ -284,16 +692,18 @@ mod person_document {
         }
 
         #[test]
-        fn test_pass_person_document_limit() {
-            let document: String = repeat("0").take(11).collect();
+        fn test_normalize_formatted_person_document() {
+            let person_document: PersonDocument =
+                PersonDocument::try_from("529.982.247-25".to_string()).unwrap();
 
-            PersonDocument::try_from(document).unwrap();
+            assert_eq!(person_document, PersonDocument("52998224725".to_string()));
+            assert_eq!(person_document.formatted(), "529.982.247-25");
         }
 
         #[test]
         #[should_panic]
         fn test_fail_person_document_limit_below_expected() {
-            let document: String = repeat("0").take(10).collect();
+            let document: String = "0".repeat(10);
 
             PersonDocument::try_from(document).unwrap();
         }
@@ -301,24 +711,432 @@ mod person_document {
         #[test]
         #[should_panic]
         fn test_fail_person_document_limit_above_expected() {
-            let document: String = repeat("0").take(12).collect();
+            let document: String = "0".repeat(12);
 
             PersonDocument::try_from(document).unwrap();
         }
 
         #[test]
-        fn test_pass_person_document_charset() {
-            let document: String = "12345678901".to_string();
+        #[should_panic]
+        fn test_fail_person_document_charset() {
+            let document: String = "a2345678909".to_string();
 
             PersonDocument::try_from(document).unwrap();
         }
 
+        #[test]
+        fn test_fail_person_document_checksum() {
+            for document in [
+                "12345678901",
+                "12345678919",
+                "00000000000",
+                "999.999.999-99",
+            ] {
+                assert_eq!(
+                    PersonDocument::try_from(document.to_string()),
+                    Err(crate::structs::ConversionError::InvalidChecksum)
+                );
+            }
+        }
+
+        #[test]
+        fn test_deserialize_person_document() {
+            let person_document: PersonDocument =
+                serde_json::from_str(r#""529.982.247-25""#).unwrap();
+
+            assert_eq!(person_document, PersonDocument("52998224725".to_string()));
+
+            let error: serde_json::Error =
+                serde_json::from_str::<PersonDocument>(r#""12345678901""#).unwrap_err();
+
+            assert!(error
+                .to_string()
+                .contains(crate::structs::ConversionError::InvalidChecksum.message()));
+        }
+    }
+}
+
+mod company_document {
+    #[derive(super::Serialize, super::Deserialize, Debug, PartialEq, Clone)]
+    #[serde(try_from = "String")]
+    pub struct CompanyDocument(String);
+
+    impl CompanyDocument {
+        pub fn as_str(&self) -> String {
+            String::from(&self.0)
+        }
+
+        pub fn formatted(&self) -> String {
+            format!(
+                "{}.{}.{}/{}-{}",
+                &self.0[..2],
+                &self.0[2..5],
+                &self.0[5..8],
+                &self.0[8..12],
+                &self.0[12..]
+            )
+        }
+
+        fn check_digit(digits: &[u32]) -> u32 {
+            let sum: u32 = digits
+                .iter()
+                .rev()
+                .enumerate()
+                .map(|(i, digit): (usize, &u32)| digit * (i as u32 % 8 + 2))
+                .sum();
+
+            match sum % 11 {
+                0 | 1 => 0,
+                rest => 11 - rest,
+            }
+        }
+    }
+
+    impl From<CompanyDocument> for String {
+        fn from(value: CompanyDocument) -> String {
+            value.0
+        }
+    }
+
+    impl TryFrom<String> for CompanyDocument {
+        type Error = super::ConversionError;
+
+        fn try_from(token: String) -> Result<Self, Self::Error> {
+            let token: String = token
+                .chars()
+                .filter(|c: &char| !matches!(c, '.' | '-' | '/'))
+                .collect();
+
+            if token.len() != 14 {
+                return Err(super::ConversionError::TokenIncompatibleSize);
+            } else if !token.chars().all(|c: char| char::is_ascii_digit(&c)) {
+                return Err(super::ConversionError::InvalidType);
+            }
+
+            let digits: Vec<u32> = token
+                .chars()
+                .map(|c: char| c.to_digit(10).unwrap())
+                .collect();
+
+            if digits.iter().all(|digit: &u32| *digit == digits[0])
+                || CompanyDocument::check_digit(&digits[..12]) != digits[12]
+                || CompanyDocument::check_digit(&digits[..13]) != digits[13]
+            {
+                return Err(super::ConversionError::InvalidChecksum);
+            }
+
+            Ok(CompanyDocument(token))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_create_company_document() {
+            let company_document: CompanyDocument =
+                CompanyDocument::try_from("11222333000181".to_string()).unwrap();
+
+            assert_eq!(
+                company_document,
+                CompanyDocument("11222333000181".to_string())
+            );
+        }
+
+        #[test]
+        fn test_normalize_formatted_company_document() {
+            let company_document: CompanyDocument =
+                CompanyDocument::try_from("11.222.333/0001-81".to_string()).unwrap();
+
+            assert_eq!(
+                company_document,
+                CompanyDocument("11222333000181".to_string())
+            );
+            assert_eq!(company_document.formatted(), "11.222.333/0001-81");
+        }
+
         #[test]
         #[should_panic]
-        fn test_fail_person_document_charset() {
-            let document: String = "a0000000000".to_string();
+        fn test_fail_company_document_limit_below_expected() {
+            CompanyDocument::try_from("1122233300018".to_string()).unwrap();
+        }
 
-            PersonDocument::try_from(document).unwrap();
+        #[test]
+        #[should_panic]
+        fn test_fail_company_document_charset() {
+            CompanyDocument::try_from("a1222333000181".to_string()).unwrap();
+        }
+
+        #[test]
+        fn test_fail_company_document_checksum() {
+            for document in ["11222333000180", "11222333000191", "00000000000000"] {
+                assert_eq!(
+                    CompanyDocument::try_from(document.to_string()),
+                    Err(crate::structs::ConversionError::InvalidChecksum)
+                );
+            }
+        }
+
+        #[test]
+        fn test_deserialize_company_document() {
+            let company_document: CompanyDocument =
+                serde_json::from_str(r#""11.222.333/0001-81""#).unwrap();
+
+            assert_eq!(
+                company_document,
+                CompanyDocument("11222333000181".to_string())
+            );
+
+            let error: serde_json::Error =
+                serde_json::from_str::<CompanyDocument>(r#""11222333000180""#).unwrap_err();
+
+            assert!(error
+                .to_string()
+                .contains(crate::structs::ConversionError::InvalidChecksum.message()));
+        }
+    }
+}
+
+mod barcode {
+    #[derive(super::Serialize, super::Deserialize, Debug, PartialEq, Clone)]
+    #[serde(try_from = "String")]
+    pub struct Barcode(String);
+
+    impl Barcode {
+        pub fn as_str(&self) -> String {
+            String::from(&self.0)
+        }
+    }
+
+    impl From<Barcode> for String {
+        fn from(value: Barcode) -> String {
+            value.0
+        }
+    }
+
+    impl TryFrom<String> for Barcode {
+        type Error = super::ConversionError;
+
+        fn try_from(token: String) -> Result<Self, Self::Error> {
+            if token.is_empty() || token.len() > 32 {
+                return Err(super::ConversionError::TokenIncompatibleSize);
+            } else if !token
+                .chars()
+                .all(|c: char| char::is_ascii_alphanumeric(&c) || c == '-')
+            {
+                return Err(super::ConversionError::InvalidType);
+            }
+
+            Ok(Barcode(token))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_create_barcode() {
+            let code: String = "BK-000123".to_string();
+
+            let barcode_code: String = code.clone();
+
+            let barcode: Barcode = Barcode::try_from(code).unwrap();
+
+            assert_eq!(barcode, Barcode(barcode_code));
+        }
+
+        #[test]
+        fn test_pass_barcode_limit() {
+            let code: String = "0".repeat(32);
+
+            Barcode::try_from(code).unwrap();
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_fail_barcode_limit() {
+            let code: String = "0".repeat(33);
+
+            Barcode::try_from(code).unwrap();
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_fail_barcode_empty() {
+            Barcode::try_from(String::new()).unwrap();
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_fail_barcode_charset() {
+            let code: String = "BK 000123".to_string();
+
+            Barcode::try_from(code).unwrap();
+        }
+
+        #[test]
+        fn test_deserialize_barcode() {
+            let barcode: Barcode = serde_json::from_str(r#""BK-000123""#).unwrap();
+
+            assert_eq!(barcode, Barcode("BK-000123".to_string()));
+
+            let error: serde_json::Error =
+                serde_json::from_str::<Barcode>(r#""BK 000123""#).unwrap_err();
+
+            assert!(error
+                .to_string()
+                .contains(crate::structs::ConversionError::InvalidType.message()));
+        }
+    }
+}
+
+mod shelf_location {
+    #[derive(super::Serialize, super::Deserialize, Debug, PartialEq, Clone)]
+    #[serde(try_from = "String")]
+    pub struct ShelfLocation(String);
+
+    impl ShelfLocation {
+        pub fn as_str(&self) -> String {
+            String::from(&self.0)
+        }
+    }
+
+    impl From<ShelfLocation> for String {
+        fn from(value: ShelfLocation) -> String {
+            value.0
+        }
+    }
+
+    impl TryFrom<String> for ShelfLocation {
+        type Error = super::ConversionError;
+
+        fn try_from(token: String) -> Result<Self, Self::Error> {
+            if token.chars().count() > 32 {
+                return Err(super::ConversionError::TokenTooLong);
+            }
+
+            Ok(ShelfLocation(token))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_pass_shelf_location_limit() {
+            let location: String = "x".repeat(32);
+
+            ShelfLocation::try_from(location).unwrap();
+        }
+
+        #[test]
+        fn test_pass_shelf_location_limit_multibyte() {
+            let location: String = "é".repeat(32);
+
+            ShelfLocation::try_from(location).unwrap();
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_fail_shelf_location_limit() {
+            let location: String = "x".repeat(33);
+
+            ShelfLocation::try_from(location).unwrap();
+        }
+
+        #[test]
+        fn test_deserialize_shelf_location() {
+            let shelf_location: ShelfLocation = serde_json::from_str(r#""A-01""#).unwrap();
+
+            assert_eq!(shelf_location, ShelfLocation("A-01".to_string()));
+
+            let error: serde_json::Error =
+                serde_json::from_str::<ShelfLocation>(&format!("\"{}\"", "x".repeat(33)))
+                    .unwrap_err();
+
+            assert!(error
+                .to_string()
+                .contains(crate::structs::ConversionError::TokenTooLong.message()));
+        }
+    }
+}
+
+mod block_reason {
+    #[derive(super::Serialize, super::Deserialize, Debug, PartialEq, Clone)]
+    #[serde(try_from = "String")]
+    pub struct BlockReason(String);
+
+    impl BlockReason {
+        pub fn as_str(&self) -> String {
+            String::from(&self.0)
+        }
+    }
+
+    impl From<BlockReason> for String {
+        fn from(value: BlockReason) -> String {
+            value.0
+        }
+    }
+
+    impl TryFrom<String> for BlockReason {
+        type Error = super::ConversionError;
+
+        fn try_from(token: String) -> Result<Self, Self::Error> {
+            if token.trim().is_empty() || token.chars().count() > 255 {
+                return Err(super::ConversionError::TokenIncompatibleSize);
+            }
+
+            Ok(BlockReason(token))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_pass_block_reason_limit() {
+            let reason: String = "x".repeat(255);
+
+            BlockReason::try_from(reason).unwrap();
+        }
+
+        #[test]
+        fn test_pass_block_reason_limit_multibyte() {
+            let reason: String = "ã".repeat(255);
+
+            BlockReason::try_from(reason).unwrap();
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_fail_block_reason_limit() {
+            let reason: String = "x".repeat(256);
+
+            BlockReason::try_from(reason).unwrap();
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_fail_block_reason_empty() {
+            BlockReason::try_from("  ".to_string()).unwrap();
+        }
+
+        #[test]
+        fn test_deserialize_block_reason() {
+            let block_reason: BlockReason = serde_json::from_str(r#""Damaged a book""#).unwrap();
+
+            assert_eq!(block_reason, BlockReason("Damaged a book".to_string()));
+
+            let error: serde_json::Error =
+                serde_json::from_str::<BlockReason>(r#""  ""#).unwrap_err();
+
+            assert!(error
+                .to_string()
+                .contains(crate::structs::ConversionError::TokenIncompatibleSize.message()));
         }
     }
 }
@@ -358,10 +1176,23 @@ mod option_date_format {
 
 pub mod author;
 pub mod book;
+pub mod copy;
 pub mod costumer;
+pub mod duplicate;
+pub mod fee;
+pub mod page;
+pub mod publisher;
 pub mod rental;
+pub mod reservation;
+pub mod subject;
 
+pub use barcode::Barcode;
+pub use block_reason::BlockReason;
 pub use book_name::BookName;
+pub use company_document::CompanyDocument;
 pub use editor_name::EditorName;
+pub use isbn::Isbn;
 pub use person_document::PersonDocument;
 pub use person_name::PersonName;
+pub use shelf_location::ShelfLocation;
+pub use subject_name::SubjectName;
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: i64,
+    pub limit: Option<i64>,
+    pub offset: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_round_trip() {
+        let page: Page<String> = Page {
+            items: vec!["Name".to_string()],
+            total: 10,
+            limit: Some(1),
+            offset: 3,
+        };
+
+        let json: String = serde_json::to_string(&page).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"items":["Name"],"total":10,"limit":1,"offset":3}"#
+        );
+        assert_eq!(serde_json::from_str::<Page<String>>(&json).unwrap(), page);
+    }
+}
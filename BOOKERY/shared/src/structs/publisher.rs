@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::structs::{ConversionError, EditorName, FieldError};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Publisher {
+    pub id: Uuid,
+    pub name: EditorName,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PayloadPublisher {
+    pub name: String,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PayloadUpdatePublisher {
+    pub id: Uuid,
+    pub name: String,
+}
+
+impl Publisher {
+    pub fn create(payload_publisher: PayloadPublisher) -> Result<Self, Vec<FieldError>> {
+        let name: EditorName = EditorName::try_from(payload_publisher.name)
+            .map_err(|e: ConversionError| vec![e.on("name")])?;
+        let id: Uuid = Uuid::new_v4();
+
+        Ok(Self { id, name })
+    }
+
+    pub fn parse(publisher: PayloadUpdatePublisher) -> Result<Self, Vec<FieldError>> {
+        let name: EditorName = EditorName::try_from(publisher.name)
+            .map_err(|e: ConversionError| vec![e.on("name")])?;
+
+        Ok(Self {
+            id: publisher.id,
+            name,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEFAULT_NAME: &str = "Publisher";
+
+    #[test]
+    fn test_create_publisher() {
+        let payload_publisher: PayloadPublisher = PayloadPublisher {
+            name: DEFAULT_NAME.to_string(),
+        };
+
+        let publisher: Publisher = Publisher::create(payload_publisher).unwrap();
+
+        assert_eq!(
+            publisher,
+            Publisher {
+                id: publisher.id,
+                name: EditorName::try_from(DEFAULT_NAME.to_string()).unwrap(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_publisher() {
+        let payload_update_publisher: PayloadUpdatePublisher = PayloadUpdatePublisher {
+            id: Uuid::new_v4(),
+            name: DEFAULT_NAME.to_string(),
+        };
+
+        let publisher_uuid: Uuid = payload_update_publisher.id;
+
+        let publisher: Publisher = Publisher::parse(payload_update_publisher).unwrap();
+
+        assert_eq!(
+            publisher,
+            Publisher {
+                id: publisher_uuid,
+                name: EditorName::try_from(DEFAULT_NAME.to_string()).unwrap(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_create_publisher_invalid_name() {
+        let payload_publisher: PayloadPublisher = PayloadPublisher {
+            name: "x".repeat(65),
+        };
+
+        assert_eq!(
+            Publisher::create(payload_publisher).unwrap_err(),
+            vec![ConversionError::TokenTooLong.on("name")]
+        );
+    }
+}
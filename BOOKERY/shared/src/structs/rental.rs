@@ -2,19 +2,20 @@ use serde::{Deserialize, Serialize};
 use time::Date;
 use uuid::Uuid;
 
-use crate::structs::{BookName, ConversionError, PersonName};
+use crate::structs::{fee::Fee, page::Page, BookName, FieldError, PersonName};
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct Rental {
     pub id: Uuid,
     pub costumer_uuid: Uuid,
-    pub book_uuid: Uuid,
+    pub copy_uuid: Uuid,
     #[serde(with = "super::date_format")]
     pub borrowed_at: Date,
     #[serde(with = "super::date_format")]
     pub due_date: Date,
     #[serde(with = "super::option_date_format")]
     pub returned_at: Option<Date>,
+    pub renewals: i64,
 }
 
 #[derive(Serialize, PartialEq, Debug, Deserialize)]
@@ -30,10 +31,37 @@ pub struct RentalWithCostumerAndBook {
     pub returned_at: Option<Date>,
 }
 
+#[derive(Serialize, PartialEq, Debug, Deserialize)]
+pub struct BookRentals {
+    pub current: Vec<RentalWithCostumerAndBook>,
+    pub history: Page<RentalWithCostumerAndBook>,
+}
+
+#[derive(Serialize, PartialEq, Debug, Deserialize)]
+pub struct OverdueRental {
+    pub id: Uuid,
+    pub costumer_uuid: Uuid,
+    pub costumer_name: PersonName,
+    pub copy_uuid: Uuid,
+    pub book_name: BookName,
+    #[serde(with = "super::date_format")]
+    pub borrowed_at: Date,
+    #[serde(with = "super::date_format")]
+    pub due_date: Date,
+    pub days_overdue: i64,
+    pub fee: i64,
+}
+
+#[derive(Serialize, PartialEq, Debug, Deserialize)]
+pub struct ReturnedRental {
+    pub rental: Rental,
+    pub fee: Option<Fee>,
+}
+
 #[derive(Deserialize, Serialize)]
 pub struct PayloadRental {
     pub costumer_uuid: Uuid,
-    pub book_uuid: Uuid,
+    pub copy_uuid: Uuid,
     #[serde(with = "super::date_format")]
     pub borrowed_at: Date,
     #[serde(with = "super::date_format")]
@@ -44,7 +72,7 @@ pub struct PayloadRental {
 pub struct PayloadUpdateRental {
     pub id: Uuid,
     pub costumer_uuid: Uuid,
-    pub book_uuid: Uuid,
+    pub copy_uuid: Uuid,
     #[serde(with = "super::date_format")]
     pub borrowed_at: Date,
     #[serde(with = "super::date_format")]
@@ -53,28 +81,64 @@ pub struct PayloadUpdateRental {
     pub returned_at: Option<Date>,
 }
 
+#[derive(Deserialize, Serialize)]
+pub struct PayloadCheckoutRental {
+    pub costumer_uuid: Uuid,
+    pub copy_uuid: Uuid,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PayloadReturnRental {
+    pub id: Uuid,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PayloadRenewRental {
+    pub id: Uuid,
+}
+
+#[derive(Deserialize, Serialize, Default, Debug, PartialEq, Clone)]
+pub struct RentalFilter {
+    pub costumer: Option<String>,
+    pub book: Option<String>,
+    #[serde(default, with = "super::option_date_format")]
+    pub borrowed_at_from: Option<Date>,
+    #[serde(default, with = "super::option_date_format")]
+    pub borrowed_at_until: Option<Date>,
+    #[serde(default, with = "super::option_date_format")]
+    pub due_date_from: Option<Date>,
+    #[serde(default, with = "super::option_date_format")]
+    pub due_date_until: Option<Date>,
+    #[serde(default, with = "super::option_date_format")]
+    pub returned_at_from: Option<Date>,
+    #[serde(default, with = "super::option_date_format")]
+    pub returned_at_until: Option<Date>,
+}
+
 impl Rental {
-    pub fn create(new_rent: PayloadRental) -> Result<Self, ConversionError> {
+    pub fn create(new_rent: PayloadRental) -> Result<Self, Vec<FieldError>> {
         let id: Uuid = Uuid::new_v4();
 
         Ok(Self {
             id,
             costumer_uuid: new_rent.costumer_uuid,
-            book_uuid: new_rent.book_uuid,
+            copy_uuid: new_rent.copy_uuid,
             borrowed_at: new_rent.borrowed_at,
             due_date: new_rent.due_date,
             returned_at: None,
+            renewals: 0,
         })
     }
 
-    pub fn parse(rent: PayloadUpdateRental) -> Result<Self, ConversionError> {
+    pub fn parse(rent: PayloadUpdateRental) -> Result<Self, Vec<FieldError>> {
         Ok(Self {
             id: rent.id,
             costumer_uuid: rent.costumer_uuid,
-            book_uuid: rent.book_uuid,
+            copy_uuid: rent.copy_uuid,
             borrowed_at: rent.borrowed_at,
             due_date: rent.due_date,
             returned_at: rent.returned_at,
+            renewals: 0,
         })
     }
 }
@@ -95,7 +159,7 @@ mod tests {
     #[test]
     fn test_create_rent() {
         let payload_rent: PayloadRental = PayloadRental {
-            book_uuid: Uuid::new_v4(),
+            copy_uuid: Uuid::new_v4(),
             costumer_uuid: Uuid::new_v4(),
             borrowed_at: DEFAULT_BORROWED_DATE.unwrap(),
             due_date: DEFAULT_DUE_DATE.unwrap(),
@@ -107,11 +171,12 @@ mod tests {
             rent,
             Rental {
                 id: rent.id,
-                book_uuid: rent.book_uuid,
+                copy_uuid: rent.copy_uuid,
                 costumer_uuid: rent.costumer_uuid,
                 borrowed_at: DEFAULT_BORROWED_DATE.unwrap(),
                 due_date: DEFAULT_DUE_DATE.unwrap(),
                 returned_at: None,
+                renewals: 0,
             }
         );
     }
@@ -120,7 +185,7 @@ mod tests {
     fn test_parse_rent() {
         let payload_update_rent: PayloadUpdateRental = PayloadUpdateRental {
             id: Uuid::new_v4(),
-            book_uuid: Uuid::new_v4(),
+            copy_uuid: Uuid::new_v4(),
             costumer_uuid: Uuid::new_v4(),
             borrowed_at: DEFAULT_BORROWED_DATE.unwrap(),
             due_date: DEFAULT_DUE_DATE.unwrap(),
@@ -133,11 +198,12 @@ mod tests {
             rent,
             Rental {
                 id: rent.id,
-                book_uuid: rent.book_uuid,
+                copy_uuid: rent.copy_uuid,
                 costumer_uuid: rent.costumer_uuid,
                 borrowed_at: DEFAULT_BORROWED_DATE.unwrap(),
                 due_date: DEFAULT_DUE_DATE.unwrap(),
                 returned_at: Some(DEFAULT_RETURNED_DATE.unwrap()),
+                renewals: 0,
             }
         );
     }
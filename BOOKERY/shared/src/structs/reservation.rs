@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+
+use crate::structs::ConversionError;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ReservationStatus {
+    Waiting,
+    Ready,
+    Fulfilled,
+    Cancelled,
+    Expired,
+}
+
+impl ReservationStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReservationStatus::Waiting => "waiting",
+            ReservationStatus::Ready => "ready",
+            ReservationStatus::Fulfilled => "fulfilled",
+            ReservationStatus::Cancelled => "cancelled",
+            ReservationStatus::Expired => "expired",
+        }
+    }
+}
+
+impl TryFrom<String> for ReservationStatus {
+    type Error = ConversionError;
+
+    fn try_from(token: String) -> Result<Self, Self::Error> {
+        match token.as_str() {
+            "waiting" => Ok(ReservationStatus::Waiting),
+            "ready" => Ok(ReservationStatus::Ready),
+            "fulfilled" => Ok(ReservationStatus::Fulfilled),
+            "cancelled" => Ok(ReservationStatus::Cancelled),
+            "expired" => Ok(ReservationStatus::Expired),
+            _ => Err(ConversionError::InvalidType),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Reservation {
+    pub id: Uuid,
+    pub book_uuid: Uuid,
+    pub costumer_uuid: Uuid,
+    pub status: ReservationStatus,
+    #[serde(with = "super::date_format")]
+    pub reserved_at: Date,
+    #[serde(with = "super::option_date_format")]
+    pub expires_at: Option<Date>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PayloadReservation {
+    pub book_uuid: Uuid,
+    pub costumer_uuid: Uuid,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PayloadCancelReservation {
+    pub id: Uuid,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reservation_status_round_trip() {
+        for status in [
+            ReservationStatus::Waiting,
+            ReservationStatus::Ready,
+            ReservationStatus::Fulfilled,
+            ReservationStatus::Cancelled,
+            ReservationStatus::Expired,
+        ] {
+            assert_eq!(
+                ReservationStatus::try_from(status.as_str().to_string()),
+                Ok(status)
+            );
+        }
+
+        assert_eq!(
+            ReservationStatus::try_from("lent".to_string()),
+            Err(ConversionError::InvalidType)
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::structs::{ConversionError, FieldError, SubjectName};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Subject {
+    pub id: Uuid,
+    pub name: SubjectName,
+    pub parent_uuid: Option<Uuid>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PayloadSubject {
+    pub name: String,
+    #[serde(default)]
+    pub parent_uuid: Option<Uuid>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PayloadUpdateSubject {
+    pub id: Uuid,
+    pub name: String,
+    #[serde(default)]
+    pub parent_uuid: Option<Uuid>,
+}
+
+impl Subject {
+    pub fn create(new_subject: PayloadSubject) -> Result<Self, Vec<FieldError>> {
+        let id: Uuid = Uuid::new_v4();
+
+        Self::validate(id, new_subject.name, new_subject.parent_uuid)
+    }
+
+    pub fn parse(subject: PayloadUpdateSubject) -> Result<Self, Vec<FieldError>> {
+        Self::validate(subject.id, subject.name, subject.parent_uuid)
+    }
+
+    fn validate(
+        id: Uuid,
+        name: String,
+        parent_uuid: Option<Uuid>,
+    ) -> Result<Self, Vec<FieldError>> {
+        let name: SubjectName =
+            SubjectName::try_from(name).map_err(|e: ConversionError| vec![e.on("name")])?;
+
+        Ok(Self {
+            id,
+            name,
+            parent_uuid,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEFAULT_NAME: &str = "Subject";
+
+    #[test]
+    fn test_create_subject() {
+        let payload_subject: PayloadSubject = PayloadSubject {
+            name: DEFAULT_NAME.to_string(),
+            parent_uuid: None,
+        };
+
+        let subject: Subject = Subject::create(payload_subject).unwrap();
+
+        assert_eq!(
+            subject,
+            Subject {
+                id: subject.id,
+                name: SubjectName::try_from(DEFAULT_NAME.to_string()).unwrap(),
+                parent_uuid: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_subject() {
+        let payload_update_subject: PayloadUpdateSubject = PayloadUpdateSubject {
+            id: Uuid::new_v4(),
+            name: DEFAULT_NAME.to_string(),
+            parent_uuid: Some(Uuid::new_v4()),
+        };
+
+        let subject_uuid: Uuid = payload_update_subject.id;
+        let parent_uuid: Option<Uuid> = payload_update_subject.parent_uuid;
+
+        let subject: Subject = Subject::parse(payload_update_subject).unwrap();
+
+        assert_eq!(
+            subject,
+            Subject {
+                id: subject_uuid,
+                name: SubjectName::try_from(DEFAULT_NAME.to_string()).unwrap(),
+                parent_uuid,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_subject_invalid_name() {
+        let payload_update_subject: PayloadUpdateSubject = PayloadUpdateSubject {
+            id: Uuid::new_v4(),
+            name: "x".repeat(65),
+            parent_uuid: None,
+        };
+
+        assert_eq!(
+            Subject::parse(payload_update_subject).unwrap_err(),
+            vec![ConversionError::TokenTooLong.on("name")]
+        );
+    }
+}